@@ -0,0 +1,26 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::{bail, Result};
+use clap::Args;
+
+use super::print_json;
+use crate::{json, Session};
+
+#[derive(Args)]
+pub struct AccountArgs {
+    /// Address of the account to dump
+    address: Pubkey,
+}
+
+pub fn run(session: &Session, args: AccountArgs) -> Result<()> {
+    let Some(account) = session.rpc.get_account(&args.address)? else {
+        bail!("account {} not found", args.address);
+    };
+    if account.owner != solearn_client::PROGRAM_ID {
+        bail!(
+            "account {} is owned by {}, not the solearn program",
+            args.address,
+            account.owner
+        );
+    }
+    print_json(&json::account_to_json(&account.data)?)
+}
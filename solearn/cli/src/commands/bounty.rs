@@ -0,0 +1,368 @@
+use std::collections::HashSet;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anyhow::{bail, Result};
+use clap::{Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use solana_signer::Signer;
+use solearn::states::{Bounty, Client, Industries, PayoutPolicy, SkillGate};
+use solearn_client::{accounts, instructions, pda, PROGRAM_ID};
+
+use super::{print_json, send, IndustryArg};
+use crate::{json, Session};
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Policy {
+    ClientSelects,
+    GuaranteedToEarliest,
+}
+
+impl From<Policy> for PayoutPolicy {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::ClientSelects => PayoutPolicy::ClientSelects,
+            Policy::GuaranteedToEarliest => PayoutPolicy::GuaranteedToEarliest,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Gate {
+    Open,
+    Any,
+    All,
+    AtLeast,
+}
+
+#[derive(Subcommand)]
+pub enum BountyCommand {
+    /// Post a bounty funded from the keypair
+    Create {
+        #[arg(long)]
+        title: String,
+        #[arg(long, default_value = "")]
+        description: String,
+        /// Reward in whole SOL, or whole tokens with --mint
+        #[arg(long)]
+        reward: u64,
+        /// Unix timestamp after which submissions close
+        #[arg(long)]
+        deadline: u64,
+        /// Comma-separated skills
+        #[arg(long, value_delimiter = ',')]
+        skills: Vec<String>,
+        /// Pay the reward in this SPL token from the keypair's associated account
+        #[arg(long)]
+        mint: Option<Pubkey>,
+        #[arg(
+            long,
+            value_enum,
+            default_value = "client-selects",
+            conflicts_with = "mint"
+        )]
+        payout_policy: Policy,
+        /// Seconds after the deadline before a guaranteed bounty auto-releases
+        #[arg(long, default_value_t = 0, conflicts_with = "mint")]
+        review_period: u64,
+    },
+    /// Replace a bounty's title, description and deadline
+    Update {
+        bounty: Pubkey,
+        #[arg(long)]
+        title: String,
+        #[arg(long, default_value = "")]
+        description: String,
+        #[arg(long)]
+        deadline: u64,
+    },
+    /// Restrict submissions to contributors listing the bounty's required skills
+    SkillGate {
+        bounty: Pubkey,
+        #[arg(value_enum)]
+        gate: Gate,
+        /// Required skills a contributor must list, for `at-least`
+        #[arg(long, required_if_eq("gate", "at-least"))]
+        count: Option<u8>,
+        /// Only count skills attested to the contributor
+        #[arg(long)]
+        attested: bool,
+    },
+    /// Require a minimum reputation from contributors before they can submit
+    MinReputation { bounty: Pubkey, min_reputation: u64 },
+    /// Rate the other side of a completed bounty
+    Review {
+        bounty: Pubkey,
+        /// 1 to 5
+        #[arg(long)]
+        score: u8,
+        #[arg(long, default_value = "")]
+        comment: String,
+        /// Winning contributor to rate, when reviewing as the bounty's client
+        #[arg(long)]
+        contributor: Option<Pubkey>,
+    },
+    /// Upgrade one of the keypair's bounties to the current account layout
+    Migrate { bounty: Pubkey },
+    /// Close a bounty and refund its escrow
+    Delete { bounty: Pubkey },
+    /// Pay a bounty's reward to a contributor's submission
+    SelectWinner { bounty: Pubkey, wallet: Pubkey },
+    /// Print a bounty as JSON
+    Show { bounty: Pubkey },
+    /// List bounties posted by a wallet, or by every client in an industry
+    List {
+        /// Creator wallet; defaults to the keypair
+        #[arg(long)]
+        creator: Option<Pubkey>,
+        #[arg(long, value_enum, conflicts_with = "creator")]
+        industry: Option<IndustryArg>,
+    },
+}
+
+pub fn run(session: &Session, command: BountyCommand) -> Result<()> {
+    match command {
+        BountyCommand::Create {
+            title,
+            description,
+            reward,
+            deadline,
+            skills,
+            mint,
+            payout_policy,
+            review_period,
+        } => {
+            let payer = session.payer()?;
+            let authority = payer.pubkey();
+            let bounty_id = accounts::fetch_client(&session.rpc, &authority)?.next_bounty_id;
+            let instruction = match mint {
+                None => instructions::create_bounty(
+                    authority,
+                    bounty_id,
+                    title,
+                    description,
+                    reward,
+                    deadline,
+                    skills,
+                    payout_policy.into(),
+                    review_period,
+                ),
+                Some(mint) => {
+                    let token_program = token_program_of(session, &mint)?;
+                    instructions::create_token_bounty(
+                        authority,
+                        bounty_id,
+                        mint,
+                        get_associated_token_address_with_program_id(
+                            &authority,
+                            &mint,
+                            &token_program,
+                        ),
+                        token_program,
+                        title,
+                        description,
+                        reward,
+                        deadline,
+                        skills,
+                    )
+                }
+            };
+            send(session, &payer, instruction)?;
+            println!("Bounty: {}", pda::bounty(&authority, bounty_id).0);
+            Ok(())
+        }
+        BountyCommand::Update {
+            bounty,
+            title,
+            description,
+            deadline,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::update_bounty(payer.pubkey(), bounty, title, description, deadline),
+            )
+        }
+        BountyCommand::SkillGate {
+            bounty,
+            gate,
+            count,
+            attested,
+        } => {
+            let skill_gate = match gate {
+                Gate::Open => SkillGate::Open,
+                Gate::Any => SkillGate::Any,
+                Gate::All => SkillGate::All,
+                Gate::AtLeast => SkillGate::AtLeast(count.unwrap_or_default()),
+            };
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::set_bounty_skill_gate(payer.pubkey(), bounty, skill_gate, attested),
+            )
+        }
+        BountyCommand::MinReputation {
+            bounty,
+            min_reputation,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::set_bounty_min_reputation(payer.pubkey(), bounty, min_reputation),
+            )
+        }
+        BountyCommand::Migrate { bounty } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::migrate_bounty_layout(payer.pubkey(), bounty),
+            )
+        }
+        BountyCommand::Review {
+            bounty,
+            score,
+            comment,
+            contributor,
+        } => {
+            let payer = session.payer()?;
+            let instruction = match contributor {
+                Some(contributor) => instructions::review_contributor(
+                    payer.pubkey(),
+                    bounty,
+                    contributor,
+                    score,
+                    comment,
+                ),
+                None => {
+                    let creator = accounts::fetch_bounty(&session.rpc, &bounty)?.creator_wallet_key;
+                    instructions::review_client(payer.pubkey(), bounty, creator, score, comment)
+                }
+            };
+            send(session, &payer, instruction)
+        }
+        BountyCommand::Delete { bounty } => {
+            let payer = session.payer()?;
+            let authority = payer.pubkey();
+            let reward_mint = accounts::fetch_bounty(&session.rpc, &bounty)?.reward_mint;
+            let instruction = if reward_mint == Pubkey::default() {
+                instructions::delete_bounty(authority, bounty)
+            } else {
+                let token_program = token_program_of(session, &reward_mint)?;
+                instructions::delete_token_bounty(
+                    authority,
+                    bounty,
+                    reward_mint,
+                    get_associated_token_address_with_program_id(
+                        &authority,
+                        &reward_mint,
+                        &token_program,
+                    ),
+                    token_program,
+                )
+            };
+            send(session, &payer, instruction)
+        }
+        BountyCommand::SelectWinner { bounty, wallet } => {
+            let payer = session.payer()?;
+            let authority = payer.pubkey();
+            let reward_mint = accounts::fetch_bounty(&session.rpc, &bounty)?.reward_mint;
+            let instruction = if reward_mint == Pubkey::default() {
+                let treasury = accounts::fetch_config(&session.rpc)?.treasury;
+                instructions::select_submission(authority, bounty, wallet, treasury)
+            } else {
+                let token_program = token_program_of(session, &reward_mint)?;
+                let treasury = accounts::fetch_config(&session.rpc)?.treasury;
+                instructions::select_token_submission(
+                    authority,
+                    bounty,
+                    wallet,
+                    reward_mint,
+                    get_associated_token_address_with_program_id(
+                        &wallet,
+                        &reward_mint,
+                        &token_program,
+                    ),
+                    get_associated_token_address_with_program_id(
+                        &treasury,
+                        &reward_mint,
+                        &token_program,
+                    ),
+                    token_program,
+                )
+            };
+            send(session, &payer, instruction)
+        }
+        BountyCommand::Show { bounty } => print_json(&json::bounty(&accounts::fetch_bounty(
+            &session.rpc,
+            &bounty,
+        )?)),
+        BountyCommand::List { creator, industry } => {
+            let mut bounties = match industry {
+                Some(industry) => bounties_in_industry(session, industry.into())?,
+                None => {
+                    let creator = match creator {
+                        Some(creator) => creator,
+                        None => session.payer()?.pubkey(),
+                    };
+                    // creator_wallet_key is the first field after the discriminator
+                    let found = session.rpc.get_program_accounts(
+                        &PROGRAM_ID,
+                        &[(0, Bounty::DISCRIMINATOR), (8, creator.as_ref())],
+                    )?;
+                    found
+                        .into_iter()
+                        .map(|(address, account)| {
+                            Ok((address, accounts::decode::<Bounty>(&account.data)?))
+                        })
+                        .collect::<Result<Vec<_>>>()?
+                }
+            };
+            bounties.sort_by_key(|(_, bounty)| bounty.created_at);
+
+            let listed: Vec<Value> = bounties
+                .iter()
+                .map(|(address, bounty)| json!({ "address": address.to_string(), "data": json::bounty(bounty) }))
+                .collect();
+            print_json(&Value::Array(listed))
+        }
+    }
+}
+
+/// Bounties posted by clients in `industry`. The industry lives on the client
+/// profile at a variable offset, so profiles are matched after decoding.
+fn bounties_in_industry(session: &Session, industry: Industries) -> Result<Vec<(Pubkey, Bounty)>> {
+    let clients: HashSet<Pubkey> = session
+        .rpc
+        .get_program_accounts(&PROGRAM_ID, &[(0, Client::DISCRIMINATOR)])?
+        .into_iter()
+        // Profiles still on a layout without an industry cannot match
+        .filter_map(|(address, account)| {
+            let client = accounts::decode::<Client>(&account.data).ok()?;
+            (client.industry == industry).then_some(address)
+        })
+        .collect();
+    let found = session
+        .rpc
+        .get_program_accounts(&PROGRAM_ID, &[(0, Bounty::DISCRIMINATOR)])?;
+    found
+        .into_iter()
+        .map(|(address, account)| Ok((address, accounts::decode::<Bounty>(&account.data)?)))
+        .filter(|bounty| match bounty {
+            Ok((_, bounty)) => clients.contains(&bounty.client_key),
+            Err(_) => true,
+        })
+        .collect()
+}
+
+/// Token program that owns `mint`, so Token-2022 mints work as well as SPL Token.
+fn token_program_of(session: &Session, mint: &Pubkey) -> Result<Pubkey> {
+    match session.rpc.get_account(mint)? {
+        Some(account) => Ok(account.owner),
+        None => bail!("mint {mint} not found"),
+    }
+}
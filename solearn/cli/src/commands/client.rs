@@ -0,0 +1,141 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::Subcommand;
+use solana_signer::Signer;
+use solearn_client::{accounts, instructions};
+
+use super::{print_json, send, CountryArg, IndustryArg, SocialArgs};
+use crate::{json, Session};
+
+#[derive(Subcommand)]
+pub enum ClientCommand {
+    /// Create the keypair's client profile
+    Create {
+        #[arg(long)]
+        company_name: String,
+        #[arg(long)]
+        company_email: String,
+        #[arg(long, default_value = "")]
+        company_link: String,
+    },
+    /// Replace the keypair's client profile fields
+    Update {
+        #[arg(long)]
+        company_name: String,
+        #[arg(long)]
+        company_email: String,
+        #[arg(long, default_value = "")]
+        company_link: String,
+        #[arg(long, default_value = "")]
+        company_bio: String,
+    },
+    /// Set the keypair's industry, country and social links
+    Details {
+        #[arg(long, value_enum, default_value = "other")]
+        industry: IndustryArg,
+        #[arg(long, value_enum, default_value = "other")]
+        country: CountryArg,
+        #[command(flatten)]
+        socials: SocialArgs,
+    },
+    /// Upgrade the keypair's client profile to the current account layout
+    Migrate,
+    /// Close the keypair's client profile
+    Delete,
+    /// Print a client profile as JSON
+    Show {
+        /// Profile owner; defaults to the keypair
+        wallet: Option<Pubkey>,
+        /// Look the profile up by company handle instead
+        #[arg(long, conflicts_with = "wallet")]
+        company_name: Option<String>,
+    },
+}
+
+pub fn run(session: &Session, command: ClientCommand) -> Result<()> {
+    match command {
+        ClientCommand::Create {
+            company_name,
+            company_email,
+            company_link,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::create_client(
+                    payer.pubkey(),
+                    company_name,
+                    company_email,
+                    company_link,
+                ),
+            )
+        }
+        ClientCommand::Update {
+            company_name,
+            company_email,
+            company_link,
+            company_bio,
+        } => {
+            let payer = session.payer()?;
+            let current = accounts::fetch_client(&session.rpc, &payer.pubkey())?;
+            send(
+                session,
+                &payer,
+                instructions::update_client(
+                    payer.pubkey(),
+                    &current.company_name,
+                    company_name,
+                    company_email,
+                    company_link,
+                    company_bio,
+                ),
+            )
+        }
+        ClientCommand::Details {
+            industry,
+            country,
+            socials,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::set_client_details(
+                    payer.pubkey(),
+                    industry.into(),
+                    country.into(),
+                    socials.into(),
+                ),
+            )
+        }
+        ClientCommand::Migrate => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::migrate_client(payer.pubkey()),
+            )
+        }
+        ClientCommand::Delete => {
+            let payer = session.payer()?;
+            let current = accounts::fetch_client(&session.rpc, &payer.pubkey())?;
+            send(
+                session,
+                &payer,
+                instructions::delete_client(payer.pubkey(), &current.company_name),
+            )
+        }
+        ClientCommand::Show {
+            wallet,
+            company_name,
+        } => {
+            let client = match (wallet, company_name) {
+                (_, Some(name)) => accounts::fetch_client_by_company_name(&session.rpc, &name)?,
+                (Some(wallet), None) => accounts::fetch_client(&session.rpc, &wallet)?,
+                (None, None) => accounts::fetch_client(&session.rpc, &session.payer()?.pubkey())?,
+            };
+            print_json(&json::client(&client))
+        }
+    }
+}
@@ -0,0 +1,101 @@
+pub mod account;
+pub mod bounty;
+pub mod client;
+pub mod submission;
+pub mod user;
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anyhow::Result;
+use clap::{Args, ValueEnum};
+use serde_json::Value;
+use solana_keypair::Keypair;
+use solearn::states::{Country, Industries, Social};
+
+use crate::Session;
+
+/// Sends a single instruction signed by `payer` and reports the signature.
+fn send(session: &Session, payer: &Keypair, instruction: Instruction) -> Result<()> {
+    let signature = session.rpc.send_and_confirm(&[instruction], payer)?;
+    println!("Signature: {signature}");
+    Ok(())
+}
+
+fn print_json(value: &Value) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CountryArg {
+    India,
+    Usa,
+    Uk,
+    Canada,
+    Australia,
+    NewZealand,
+    Other,
+}
+
+impl From<CountryArg> for Country {
+    fn from(country: CountryArg) -> Self {
+        match country {
+            CountryArg::India => Country::India,
+            CountryArg::Usa => Country::USA,
+            CountryArg::Uk => Country::UK,
+            CountryArg::Canada => Country::Canada,
+            CountryArg::Australia => Country::Australia,
+            CountryArg::NewZealand => Country::NewZealand,
+            CountryArg::Other => Country::Other,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum IndustryArg {
+    Dao,
+    Defi,
+    Infrastructure,
+    DePin,
+    WalletAndPayment,
+    Nfts,
+    Other,
+}
+
+impl From<IndustryArg> for Industries {
+    fn from(industry: IndustryArg) -> Self {
+        match industry {
+            IndustryArg::Dao => Industries::DAO,
+            IndustryArg::Defi => Industries::Defi,
+            IndustryArg::Infrastructure => Industries::Infrastructure,
+            IndustryArg::DePin => Industries::DePin,
+            IndustryArg::WalletAndPayment => Industries::WalletAndPayment,
+            IndustryArg::Nfts => Industries::Nfts,
+            IndustryArg::Other => Industries::Other,
+        }
+    }
+}
+
+/// Social handles shared by contributor and client profiles; unset ones are cleared.
+#[derive(Args)]
+pub struct SocialArgs {
+    #[arg(long, default_value = "")]
+    twitter: String,
+    #[arg(long, default_value = "")]
+    facebook: String,
+    #[arg(long, default_value = "")]
+    linkedin: String,
+    /// https link
+    #[arg(long, default_value = "")]
+    website: String,
+}
+
+impl From<SocialArgs> for Social {
+    fn from(args: SocialArgs) -> Self {
+        Social {
+            twitter: args.twitter,
+            facebook: args.facebook,
+            linkedin: args.linkedin,
+            website: args.website,
+        }
+    }
+}
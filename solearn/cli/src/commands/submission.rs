@@ -0,0 +1,141 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Discriminator;
+use anyhow::Result;
+use clap::{Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use solana_signer::Signer;
+use solearn::states::{Submission, SubmissionStatus};
+use solearn_client::{accounts, instructions, PROGRAM_ID};
+
+use super::{print_json, send};
+use crate::{json, Session};
+
+// Discriminator, then user_wallet_key and user_key precede bounty_key
+const BOUNTY_KEY_OFFSET: usize = 8 + 32 + 32;
+
+/// Outcomes a client can give a submission; winners are marked by paying out
+#[derive(Clone, Copy, ValueEnum)]
+pub enum ReviewArg {
+    Shortlist,
+    RequestChanges,
+    Reject,
+}
+
+impl From<ReviewArg> for SubmissionStatus {
+    fn from(review: ReviewArg) -> Self {
+        match review {
+            ReviewArg::Shortlist => SubmissionStatus::Shortlisted,
+            ReviewArg::RequestChanges => SubmissionStatus::ChangesRequested,
+            ReviewArg::Reject => SubmissionStatus::Rejected,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+pub enum SubmissionCommand {
+    /// List every submission to a bounty
+    List { bounty: Pubkey },
+    /// Print one contributor's submission to a bounty
+    Show { bounty: Pubkey, wallet: Pubkey },
+    /// Replace the keypair's submission to a bounty before its deadline
+    Update {
+        bounty: Pubkey,
+        #[arg(long)]
+        description: String,
+        #[arg(long)]
+        work_url: String,
+    },
+    /// Withdraw the keypair's submission to a bounty before its deadline, or any time once rejected
+    Withdraw { bounty: Pubkey },
+    /// Review a contributor's submission to one of the keypair's bounties
+    Review {
+        bounty: Pubkey,
+        wallet: Pubkey,
+        #[arg(value_enum)]
+        outcome: ReviewArg,
+        /// Note shown to the contributor
+        #[arg(long, default_value = "")]
+        feedback: String,
+    },
+    /// Upgrade the keypair's submission to a bounty to the current account layout
+    Migrate { bounty: Pubkey },
+}
+
+pub fn run(session: &Session, command: SubmissionCommand) -> Result<()> {
+    match command {
+        SubmissionCommand::List { bounty } => {
+            let found = session.rpc.get_program_accounts(
+                &PROGRAM_ID,
+                &[
+                    (0, Submission::DISCRIMINATOR),
+                    (BOUNTY_KEY_OFFSET, bounty.as_ref()),
+                ],
+            )?;
+            let mut submissions = found
+                .into_iter()
+                .map(|(address, account)| {
+                    Ok((address, accounts::decode::<Submission>(&account.data)?))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            submissions.sort_by_key(|(_, submission)| submission.submitted_at);
+
+            let listed: Vec<Value> = submissions
+                .iter()
+                .map(|(address, submission)| {
+                    json!({ "address": address.to_string(), "data": json::submission(submission) })
+                })
+                .collect();
+            print_json(&Value::Array(listed))
+        }
+        SubmissionCommand::Show { bounty, wallet } => print_json(&json::submission(
+            &accounts::fetch_submission(&session.rpc, &wallet, &bounty)?,
+        )),
+        SubmissionCommand::Update {
+            bounty,
+            description,
+            work_url,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::update_submission(payer.pubkey(), bounty, description, work_url),
+            )
+        }
+        SubmissionCommand::Withdraw { bounty } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::withdraw_submission(payer.pubkey(), bounty),
+            )
+        }
+        SubmissionCommand::Review {
+            bounty,
+            wallet,
+            outcome,
+            feedback,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::review_submission(
+                    payer.pubkey(),
+                    bounty,
+                    wallet,
+                    outcome.into(),
+                    feedback,
+                ),
+            )
+        }
+        SubmissionCommand::Migrate { bounty } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::migrate_submission(payer.pubkey(), bounty),
+            )
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::Pubkey;
+use anyhow::Result;
+use clap::Subcommand;
+use solana_signer::Signer;
+use solearn_client::{accounts, instructions};
+
+use super::{print_json, send, CountryArg, SocialArgs};
+use crate::{json, Session};
+
+#[derive(Subcommand)]
+pub enum UserCommand {
+    /// Create the keypair's contributor profile
+    Create {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: String,
+        /// Comma-separated skills
+        #[arg(long, value_delimiter = ',')]
+        skills: Vec<String>,
+    },
+    /// Replace the keypair's contributor profile fields
+    Update {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        email: String,
+        #[arg(long, default_value = "")]
+        bio: String,
+        /// Comma-separated skills
+        #[arg(long, value_delimiter = ',')]
+        skills: Vec<String>,
+    },
+    /// Set the keypair's country and social links
+    Details {
+        #[arg(long, value_enum, default_value = "other")]
+        country: CountryArg,
+        #[command(flatten)]
+        socials: SocialArgs,
+    },
+    /// Upgrade the keypair's contributor profile to the current account layout
+    Migrate,
+    /// Close the keypair's contributor profile
+    Delete,
+    /// Print a contributor profile as JSON
+    Show {
+        /// Profile owner; defaults to the keypair
+        wallet: Option<Pubkey>,
+        /// Look the profile up by username instead
+        #[arg(long, conflicts_with = "wallet")]
+        name: Option<String>,
+    },
+}
+
+pub fn run(session: &Session, command: UserCommand) -> Result<()> {
+    match command {
+        UserCommand::Create {
+            name,
+            email,
+            skills,
+        } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::create_user(payer.pubkey(), name, email, skills),
+            )
+        }
+        UserCommand::Update {
+            name,
+            email,
+            bio,
+            skills,
+        } => {
+            let payer = session.payer()?;
+            let current = accounts::fetch_user(&session.rpc, &payer.pubkey())?;
+            send(
+                session,
+                &payer,
+                instructions::update_user(
+                    payer.pubkey(),
+                    &current.name,
+                    &current.email,
+                    name,
+                    email,
+                    bio,
+                    skills,
+                ),
+            )
+        }
+        UserCommand::Details { country, socials } => {
+            let payer = session.payer()?;
+            send(
+                session,
+                &payer,
+                instructions::set_user_details(payer.pubkey(), country.into(), socials.into()),
+            )
+        }
+        UserCommand::Migrate => {
+            let payer = session.payer()?;
+            send(session, &payer, instructions::migrate_user(payer.pubkey()))
+        }
+        UserCommand::Delete => {
+            let payer = session.payer()?;
+            let current = accounts::fetch_user(&session.rpc, &payer.pubkey())?;
+            send(
+                session,
+                &payer,
+                instructions::delete_user(payer.pubkey(), &current.name, &current.email),
+            )
+        }
+        UserCommand::Show { wallet, name } => {
+            let user = match (wallet, name) {
+                (_, Some(name)) => accounts::fetch_user_by_name(&session.rpc, &name)?,
+                (Some(wallet), None) => accounts::fetch_user(&session.rpc, &wallet)?,
+                (None, None) => accounts::fetch_user(&session.rpc, &session.payer()?.pubkey())?,
+            };
+            print_json(&json::user(&user))
+        }
+    }
+}
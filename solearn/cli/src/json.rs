@@ -0,0 +1,648 @@
+//! JSON rendering of program accounts.
+//!
+//! Structs are destructured field by field so a layout change fails to compile here
+//! instead of silently dropping fields from the output.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{bail, Result};
+use serde_json::{json, Value};
+use solearn::states::{
+    Application, ApplicationStatus, Attestation, AttestationSource, Bounty, BountyCloseReason,
+    Client, Country, Dispute, DisputeRuling, DisputeStatus, DisputeVote, HandleRecord, Industries,
+    Milestone, MilestoneStatus, PayoutPolicy, PlatformConfig, PrizeTier, PrizeTierStatus, Project,
+    ProjectStatus, Reputation, Review, ReviewerRole, SkillGate, Social, Submission,
+    SubmissionRevision, SubmissionStatus, TrustedIssuer, User, WorkStatus,
+};
+
+fn key(pubkey: &Pubkey) -> Value {
+    pubkey.to_string().into()
+}
+
+fn keys(pubkeys: &[Pubkey]) -> Value {
+    pubkeys.iter().map(key).collect()
+}
+
+fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    Ok(T::try_deserialize(&mut &data[..])?)
+}
+
+/// Decodes any solearn account by its discriminator, tagging it with its type name.
+pub fn account_to_json(data: &[u8]) -> Result<Value> {
+    let (kind, fields) = if data.starts_with(User::DISCRIMINATOR) {
+        ("User", user(&decode(data)?))
+    } else if data.starts_with(Client::DISCRIMINATOR) {
+        ("Client", client(&decode(data)?))
+    } else if data.starts_with(Bounty::DISCRIMINATOR) {
+        ("Bounty", bounty(&decode(data)?))
+    } else if data.starts_with(Submission::DISCRIMINATOR) {
+        ("Submission", submission(&decode(data)?))
+    } else if data.starts_with(Dispute::DISCRIMINATOR) {
+        ("Dispute", dispute(&decode(data)?))
+    } else if data.starts_with(Project::DISCRIMINATOR) {
+        ("Project", project(&decode(data)?))
+    } else if data.starts_with(Application::DISCRIMINATOR) {
+        ("Application", application(&decode(data)?))
+    } else if data.starts_with(PlatformConfig::DISCRIMINATOR) {
+        ("PlatformConfig", platform_config(&decode(data)?))
+    } else if data.starts_with(HandleRecord::DISCRIMINATOR) {
+        ("HandleRecord", handle_record(&decode(data)?))
+    } else if data.starts_with(Attestation::DISCRIMINATOR) {
+        ("Attestation", attestation(&decode(data)?))
+    } else if data.starts_with(TrustedIssuer::DISCRIMINATOR) {
+        ("TrustedIssuer", trusted_issuer(&decode(data)?))
+    } else if data.starts_with(Review::DISCRIMINATOR) {
+        ("Review", review(&decode(data)?))
+    } else {
+        bail!("not a solearn account (unknown discriminator)");
+    };
+    Ok(json!({ "type": kind, "data": fields }))
+}
+
+pub fn user(user: &User) -> Value {
+    let User {
+        authority,
+        name,
+        email,
+        avatar,
+        bio,
+        skills,
+        joined_at,
+        earned,
+        bounties_submitted,
+        bounties_completed,
+        bump,
+        rating_sum,
+        rating_count,
+        reputation,
+        country,
+        socials,
+        version,
+        deleted_at,
+        reserved: _,
+    } = user;
+    json!({
+        "authority": key(authority),
+        "name": name,
+        "email": email,
+        "avatar": avatar,
+        "bio": bio,
+        "skills": skills,
+        "joined_at": joined_at,
+        "earned": earned,
+        "bounties_submitted": bounties_submitted,
+        "bounties_completed": bounties_completed,
+        "bump": bump,
+        "rating_sum": rating_sum,
+        "rating_count": rating_count,
+        "reputation": reputation_json(reputation),
+        "country": country_name(country),
+        "socials": social_json(socials),
+        "version": version,
+        "deleted_at": deleted_at,
+    })
+}
+
+pub fn client(client: &Client) -> Value {
+    let Client {
+        authority,
+        company_name,
+        company_email,
+        company_avatar,
+        company_link,
+        company_bio,
+        joined_at,
+        rewarded,
+        bounties_posted,
+        bump,
+        next_bounty_id,
+        rating_sum,
+        rating_count,
+        reputation,
+        industry,
+        country,
+        socials,
+        version,
+        live_bounties,
+        live_projects,
+        deleted_at,
+        next_project_id,
+        reserved: _,
+    } = client;
+    json!({
+        "authority": key(authority),
+        "company_name": company_name,
+        "company_email": company_email,
+        "company_avatar": company_avatar,
+        "company_link": company_link,
+        "company_bio": company_bio,
+        "joined_at": joined_at,
+        "rewarded": rewarded,
+        "bounties_posted": bounties_posted,
+        "bump": bump,
+        "next_bounty_id": next_bounty_id,
+        "rating_sum": rating_sum,
+        "rating_count": rating_count,
+        "reputation": reputation_json(reputation),
+        "industry": industry_name(industry),
+        "country": country_name(country),
+        "socials": social_json(socials),
+        "version": version,
+        "live_bounties": live_bounties,
+        "live_projects": live_projects,
+        "deleted_at": deleted_at,
+        "next_project_id": next_project_id,
+    })
+}
+
+pub fn bounty(bounty: &Bounty) -> Value {
+    let Bounty {
+        creator_wallet_key,
+        client_key,
+        title,
+        description,
+        reward,
+        live,
+        close_reason,
+        created_at,
+        deadline,
+        required_skills,
+        no_of_submissions,
+        first_submission,
+        selected_submission,
+        selected_user_wallet_key,
+        escrow_account,
+        reward_mint,
+        bounty_rewarded,
+        payout_policy,
+        review_period,
+        platform_fee_bps,
+        prize_tiers,
+        milestones,
+        arbiters,
+        arbiter_fee_bps,
+        disputed,
+        bump,
+        bounty_id,
+        legacy_title_seed,
+        skill_gate,
+        attested_skills,
+        min_reputation,
+        version,
+        counted_live,
+        payout_held,
+        selected_at,
+        reserved: _,
+    } = bounty;
+    json!({
+        "creator_wallet_key": key(creator_wallet_key),
+        "client_key": key(client_key),
+        "title": title,
+        "description": description,
+        "reward": reward,
+        "live": live,
+        "close_reason": bounty_close_reason(close_reason),
+        "created_at": created_at,
+        "deadline": deadline,
+        "required_skills": required_skills,
+        "no_of_submissions": no_of_submissions,
+        "first_submission": key(first_submission),
+        "selected_submission": key(selected_submission),
+        "selected_user_wallet_key": key(selected_user_wallet_key),
+        "escrow_account": key(escrow_account),
+        "reward_mint": key(reward_mint),
+        "bounty_rewarded": bounty_rewarded,
+        "payout_policy": payout_policy_name(payout_policy),
+        "review_period": review_period,
+        "platform_fee_bps": platform_fee_bps,
+        "prize_tiers": prize_tiers.iter().map(prize_tier).collect::<Vec<_>>(),
+        "milestones": milestones.iter().map(milestone).collect::<Vec<_>>(),
+        "arbiters": keys(arbiters),
+        "arbiter_fee_bps": arbiter_fee_bps,
+        "disputed": disputed,
+        "bump": bump,
+        "bounty_id": bounty_id,
+        "legacy_title_seed": legacy_title_seed,
+        "skill_gate": skill_gate_json(skill_gate),
+        "attested_skills": attested_skills,
+        "min_reputation": min_reputation,
+        "version": version,
+        "counted_live": counted_live,
+        "payout_held": payout_held,
+        "selected_at": selected_at,
+    })
+}
+
+fn prize_tier(tier: &PrizeTier) -> Value {
+    let PrizeTier {
+        reward,
+        status,
+        submission,
+    } = tier;
+    let status = match status {
+        PrizeTierStatus::Open => "Open",
+        PrizeTierStatus::Awarded => "Awarded",
+        PrizeTierStatus::Forfeited => "Forfeited",
+    };
+    json!({ "reward": reward, "status": status, "submission": key(submission) })
+}
+
+fn milestone(milestone: &Milestone) -> Value {
+    let Milestone {
+        reward,
+        deliverable,
+        work_url,
+        status,
+        submitted_at,
+    } = milestone;
+    let status = match status {
+        MilestoneStatus::Pending => "Pending",
+        MilestoneStatus::Submitted => "Submitted",
+        MilestoneStatus::Approved => "Approved",
+        MilestoneStatus::Refunded => "Refunded",
+    };
+    json!({
+        "reward": reward,
+        "deliverable": deliverable,
+        "work_url": work_url,
+        "status": status,
+        "submitted_at": submitted_at,
+    })
+}
+
+fn bounty_close_reason(reason: &BountyCloseReason) -> &'static str {
+    match reason {
+        BountyCloseReason::None => "None",
+        BountyCloseReason::Rewarded => "Rewarded",
+        BountyCloseReason::Cancelled => "Cancelled",
+        BountyCloseReason::Expired => "Expired",
+    }
+}
+
+fn payout_policy_name(policy: &PayoutPolicy) -> &'static str {
+    match policy {
+        PayoutPolicy::ClientSelects => "ClientSelects",
+        PayoutPolicy::GuaranteedToEarliest => "GuaranteedToEarliest",
+    }
+}
+
+fn country_name(country: &Country) -> &'static str {
+    match country {
+        Country::India => "India",
+        Country::USA => "USA",
+        Country::UK => "UK",
+        Country::Canada => "Canada",
+        Country::Australia => "Australia",
+        Country::NewZealand => "NewZealand",
+        Country::Other => "Other",
+    }
+}
+
+fn industry_name(industry: &Industries) -> &'static str {
+    match industry {
+        Industries::DAO => "DAO",
+        Industries::Defi => "Defi",
+        Industries::Infrastructure => "Infrastructure",
+        Industries::DePin => "DePin",
+        Industries::WalletAndPayment => "WalletAndPayment",
+        Industries::Nfts => "Nfts",
+        Industries::Other => "Other",
+    }
+}
+
+fn social_json(socials: &Social) -> Value {
+    let Social {
+        twitter,
+        facebook,
+        linkedin,
+        website,
+    } = socials;
+    json!({
+        "twitter": twitter,
+        "facebook": facebook,
+        "linkedin": linkedin,
+        "website": website,
+    })
+}
+
+fn reputation_json(reputation: &Reputation) -> Value {
+    let Reputation { score, updated_at } = reputation;
+    json!({
+        "score": score,
+        "updated_at": updated_at,
+    })
+}
+
+fn skill_gate_json(gate: &SkillGate) -> Value {
+    match gate {
+        SkillGate::Open => "Open".into(),
+        SkillGate::Any => "Any".into(),
+        SkillGate::All => "All".into(),
+        SkillGate::AtLeast(count) => json!({ "AtLeast": count }),
+    }
+}
+
+pub fn submission(submission: &Submission) -> Value {
+    let Submission {
+        user_wallet_key,
+        user_key,
+        bounty_key,
+        description,
+        work_url,
+        submitted_at,
+        bump,
+        version,
+        status,
+        reviewed_at,
+        reserved: _,
+        revisions,
+        feedback,
+    } = submission;
+    json!({
+        "user_wallet_key": key(user_wallet_key),
+        "user_key": key(user_key),
+        "bounty_key": key(bounty_key),
+        "description": description,
+        "work_url": work_url,
+        "submitted_at": submitted_at,
+        "bump": bump,
+        "version": version,
+        "status": submission_status_name(status),
+        "reviewed_at": reviewed_at,
+        "revisions": revisions.iter().map(revision).collect::<Vec<_>>(),
+        "feedback": feedback,
+    })
+}
+
+fn submission_status_name(status: &SubmissionStatus) -> &'static str {
+    match status {
+        SubmissionStatus::Pending => "Pending",
+        SubmissionStatus::Shortlisted => "Shortlisted",
+        SubmissionStatus::ChangesRequested => "ChangesRequested",
+        SubmissionStatus::Rejected => "Rejected",
+        SubmissionStatus::Winner => "Winner",
+    }
+}
+
+fn revision(revision: &SubmissionRevision) -> Value {
+    let SubmissionRevision {
+        work_url,
+        revised_at,
+    } = revision;
+    json!({ "work_url": work_url, "revised_at": revised_at })
+}
+
+pub fn dispute(dispute: &Dispute) -> Value {
+    let Dispute {
+        bounty,
+        submission,
+        client_wallet,
+        contributor_wallet,
+        opened_by,
+        arbiters,
+        votes,
+        arbiter_fee_bps,
+        client_evidence,
+        contributor_evidence,
+        status,
+        ruling,
+        opened_at,
+        resolved_at,
+        bump,
+    } = dispute;
+    let status = match status {
+        DisputeStatus::Open => "Open",
+        DisputeStatus::Resolved => "Resolved",
+    };
+    json!({
+        "bounty": key(bounty),
+        "submission": key(submission),
+        "client_wallet": key(client_wallet),
+        "contributor_wallet": key(contributor_wallet),
+        "opened_by": key(opened_by),
+        "arbiters": keys(arbiters),
+        "votes": votes.iter().map(dispute_vote).collect::<Vec<_>>(),
+        "arbiter_fee_bps": arbiter_fee_bps,
+        "client_evidence": client_evidence,
+        "contributor_evidence": contributor_evidence,
+        "status": status,
+        "ruling": ruling.as_ref().map(dispute_ruling),
+        "opened_at": opened_at,
+        "resolved_at": resolved_at,
+        "bump": bump,
+    })
+}
+
+fn dispute_vote(vote: &DisputeVote) -> Value {
+    let DisputeVote { arbiter, ruling } = vote;
+    json!({ "arbiter": key(arbiter), "ruling": dispute_ruling(ruling) })
+}
+
+fn dispute_ruling(ruling: &DisputeRuling) -> Value {
+    match ruling {
+        DisputeRuling::PayContributor => json!("PayContributor"),
+        DisputeRuling::Split { contributor_bps } => {
+            json!({ "Split": { "contributor_bps": contributor_bps } })
+        }
+        DisputeRuling::Refund => json!("Refund"),
+    }
+}
+
+pub fn project(project: &Project) -> Value {
+    let Project {
+        creator_wallet_key,
+        client_key,
+        project_id,
+        title,
+        description,
+        reward,
+        status,
+        created_at,
+        deadline,
+        required_skills,
+        no_of_applications,
+        approved_application,
+        approved_user_wallet_key,
+        escrow_account,
+        project_rewarded,
+        platform_fee_bps,
+        bump,
+    } = project;
+    let status = match status {
+        ProjectStatus::Open => "Open",
+        ProjectStatus::Closed => "Closed",
+    };
+    json!({
+        "creator_wallet_key": key(creator_wallet_key),
+        "client_key": key(client_key),
+        "project_id": project_id,
+        "title": title,
+        "description": description,
+        "reward": reward,
+        "status": status,
+        "created_at": created_at,
+        "deadline": deadline,
+        "required_skills": required_skills,
+        "no_of_applications": no_of_applications,
+        "approved_application": key(approved_application),
+        "approved_user_wallet_key": key(approved_user_wallet_key),
+        "escrow_account": key(escrow_account),
+        "project_rewarded": project_rewarded,
+        "platform_fee_bps": platform_fee_bps,
+        "bump": bump,
+    })
+}
+
+pub fn application(application: &Application) -> Value {
+    let Application {
+        user_wallet_key,
+        user_key,
+        project_key,
+        description,
+        link,
+        status,
+        applied_at,
+        work_description,
+        work_link,
+        additional_files_link,
+        work_status,
+        work_submitted_at,
+        bump,
+    } = application;
+    let status = match status {
+        ApplicationStatus::Pending => "Pending",
+        ApplicationStatus::Approved => "Approved",
+        ApplicationStatus::Rejected => "Rejected",
+    };
+    let work_status = match work_status {
+        WorkStatus::NotSubmitted => "NotSubmitted",
+        WorkStatus::Submitted => "Submitted",
+        WorkStatus::Approved => "Approved",
+        WorkStatus::Rejected => "Rejected",
+    };
+    json!({
+        "user_wallet_key": key(user_wallet_key),
+        "user_key": key(user_key),
+        "project_key": key(project_key),
+        "description": description,
+        "link": link,
+        "status": status,
+        "applied_at": applied_at,
+        "work_description": work_description,
+        "work_link": work_link,
+        "additional_files_link": additional_files_link,
+        "work_status": work_status,
+        "work_submitted_at": work_submitted_at,
+        "bump": bump,
+    })
+}
+
+pub fn platform_config(config: &PlatformConfig) -> Value {
+    let PlatformConfig {
+        authority,
+        treasury,
+        fee_bps,
+        min_reward,
+        max_deadline_duration,
+        guardian,
+        paused,
+        deposits_paused,
+        payouts_paused,
+        refunds_paused,
+        bump,
+    } = config;
+    json!({
+        "authority": key(authority),
+        "treasury": key(treasury),
+        "fee_bps": fee_bps,
+        "min_reward": min_reward,
+        "max_deadline_duration": max_deadline_duration,
+        "guardian": key(guardian),
+        "paused": paused,
+        "deposits_paused": deposits_paused,
+        "payouts_paused": payouts_paused,
+        "refunds_paused": refunds_paused,
+        "bump": bump,
+    })
+}
+
+pub fn handle_record(record: &HandleRecord) -> Value {
+    let HandleRecord {
+        authority,
+        profile,
+        bump,
+    } = record;
+    json!({
+        "authority": key(authority),
+        "profile": key(profile),
+        "bump": bump,
+    })
+}
+
+pub fn attestation(attestation: &Attestation) -> Value {
+    let Attestation {
+        user_wallet,
+        issuer,
+        source,
+        skill,
+        issued_at,
+        expires_at,
+        revoked_at,
+        bump,
+    } = attestation;
+    let source = match source {
+        AttestationSource::Platform => "Platform",
+        AttestationSource::Client => "Client",
+        AttestationSource::Bounty => "Bounty",
+    };
+    json!({
+        "user_wallet": key(user_wallet),
+        "issuer": key(issuer),
+        "source": source,
+        "skill": skill,
+        "issued_at": issued_at,
+        "expires_at": expires_at,
+        "revoked_at": revoked_at,
+        "bump": bump,
+    })
+}
+
+pub fn trusted_issuer(issuer: &TrustedIssuer) -> Value {
+    let TrustedIssuer {
+        wallet,
+        approved_at,
+        bump,
+    } = issuer;
+    json!({
+        "wallet": key(wallet),
+        "approved_at": approved_at,
+        "bump": bump,
+    })
+}
+
+pub fn review(review: &Review) -> Value {
+    let Review {
+        bounty,
+        reviewer,
+        reviewee,
+        reviewer_role,
+        score,
+        comment,
+        created_at,
+        bump,
+    } = review;
+    let reviewer_role = match reviewer_role {
+        ReviewerRole::Client => "Client",
+        ReviewerRole::Contributor => "Contributor",
+    };
+    json!({
+        "bounty": key(bounty),
+        "reviewer": key(reviewer),
+        "reviewee": key(reviewee),
+        "reviewer_role": reviewer_role,
+        "score": score,
+        "comment": comment,
+        "created_at": created_at,
+        "bump": bump,
+    })
+}
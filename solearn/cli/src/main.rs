@@ -0,0 +1,91 @@
+//! `solearn` command-line tool for operating profiles and bounties against a cluster.
+
+mod commands;
+mod json;
+mod rpc;
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand};
+use solana_keypair::{read_keypair_file, Keypair};
+
+use crate::commands::{account, bounty, client, submission, user};
+use crate::rpc::RpcClient;
+
+#[derive(Parser)]
+#[command(
+    name = "solearn",
+    version,
+    about = "Operate solearn profiles and bounties"
+)]
+struct Cli {
+    /// JSON-RPC endpoint of the cluster
+    #[arg(
+        short = 'u',
+        long,
+        global = true,
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+    /// Keypair file that signs and pays for transactions
+    #[arg(short = 'k', long, global = true)]
+    keypair: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Manage the contributor profile of the keypair
+    #[command(subcommand)]
+    User(user::UserCommand),
+    /// Manage the client profile of the keypair
+    #[command(subcommand)]
+    Client(client::ClientCommand),
+    /// Post, update, delete and award bounties
+    #[command(subcommand)]
+    Bounty(bounty::BountyCommand),
+    /// List and inspect submissions to a bounty
+    #[command(subcommand)]
+    Submission(submission::SubmissionCommand),
+    /// Print any solearn account as JSON
+    Account(account::AccountArgs),
+}
+
+/// Connection and signer shared by every command.
+pub struct Session {
+    pub rpc: RpcClient,
+    keypair: Option<PathBuf>,
+}
+
+impl Session {
+    /// Loads the signing keypair, defaulting to the Solana CLI's `id.json`.
+    pub fn payer(&self) -> Result<Keypair> {
+        let path = match &self.keypair {
+            Some(path) => path.clone(),
+            None => {
+                let home = std::env::var_os("HOME").context("HOME is not set; pass --keypair")?;
+                PathBuf::from(home).join(".config/solana/id.json")
+            }
+        };
+        read_keypair_file(&path)
+            .map_err(|err| anyhow!("failed to read keypair {}: {err}", path.display()))
+    }
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let session = Session {
+        rpc: RpcClient::new(cli.url),
+        keypair: cli.keypair,
+    };
+
+    match cli.command {
+        Command::User(command) => user::run(&session, command),
+        Command::Client(command) => client::run(&session, command),
+        Command::Bounty(command) => bounty::run(&session, command),
+        Command::Submission(command) => submission::run(&session, command),
+        Command::Account(args) => account::run(&session, args),
+    }
+}
@@ -0,0 +1,215 @@
+//! Minimal JSON-RPC client covering the calls the CLI makes.
+
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+use anchor_lang::prelude::Pubkey;
+use anyhow::{anyhow, bail, Context, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_hash::Hash;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solearn_client::accounts::{AccountReader, ReaderError};
+use solearn_client::errors::decode_error;
+
+use anchor_lang::solana_program::instruction::Instruction;
+
+const COMMITMENT: &str = "confirmed";
+const CONFIRM_TIMEOUT: Duration = Duration::from_secs(60);
+
+pub struct RpcClient {
+    url: String,
+    agent: ureq::Agent,
+}
+
+/// Raw account as returned by `getAccountInfo`.
+pub struct RawAccount {
+    pub owner: Pubkey,
+    pub data: Vec<u8>,
+}
+
+impl RpcClient {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .with_context(|| format!("{method} request to {} failed", self.url))?
+            .into_json()
+            .with_context(|| format!("{method} returned invalid JSON"))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(rpc_error(method, error));
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{method} response has no result"))
+    }
+
+    pub fn get_account(&self, address: &Pubkey) -> Result<Option<RawAccount>> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": COMMITMENT }]),
+        )?;
+        match &result["value"] {
+            Value::Null => Ok(None),
+            account => parse_account(account).map(Some),
+        }
+    }
+
+    /// Program accounts whose data matches every `(offset, bytes)` filter.
+    pub fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: &[(usize, &[u8])],
+    ) -> Result<Vec<(Pubkey, RawAccount)>> {
+        let filters: Vec<Value> = filters
+            .iter()
+            .map(|(offset, bytes)| {
+                json!({ "memcmp": { "offset": offset, "bytes": BASE64.encode(bytes), "encoding": "base64" } })
+            })
+            .collect();
+        let result = self.call(
+            "getProgramAccounts",
+            json!([
+                program_id.to_string(),
+                { "encoding": "base64", "commitment": COMMITMENT, "filters": filters }
+            ]),
+        )?;
+
+        result
+            .as_array()
+            .ok_or_else(|| anyhow!("getProgramAccounts returned no account list"))?
+            .iter()
+            .map(|entry| {
+                let address = parse_pubkey(&entry["pubkey"])?;
+                Ok((address, parse_account(&entry["account"])?))
+            })
+            .collect()
+    }
+
+    fn latest_blockhash(&self) -> Result<Hash> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": COMMITMENT }]))?;
+        result["value"]["blockhash"]
+            .as_str()
+            .ok_or_else(|| anyhow!("getLatestBlockhash returned no blockhash"))?
+            .parse()
+            .map_err(|err| anyhow!("invalid blockhash: {err:?}"))
+    }
+
+    /// Signs `instructions` with `payer`, sends them as one transaction and waits
+    /// until it is confirmed, returning the signature.
+    pub fn send_and_confirm(
+        &self,
+        instructions: &[Instruction],
+        payer: &Keypair,
+    ) -> Result<String> {
+        let blockhash = self.latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        );
+        let encoded = BASE64.encode(bincode::serialize(&transaction)?);
+
+        let signature = self
+            .call(
+                "sendTransaction",
+                json!([encoded, { "encoding": "base64", "preflightCommitment": COMMITMENT }]),
+            )?
+            .as_str()
+            .ok_or_else(|| anyhow!("sendTransaction returned no signature"))?
+            .to_string();
+
+        let started = Instant::now();
+        while started.elapsed() < CONFIRM_TIMEOUT {
+            let result = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    bail!(
+                        "transaction {signature} failed: {}",
+                        describe_tx_error(&status["err"])
+                    );
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed") | Some("finalized")
+                ) {
+                    return Ok(signature);
+                }
+            }
+            sleep(Duration::from_millis(500));
+        }
+        bail!("transaction {signature} was not confirmed within {CONFIRM_TIMEOUT:?}")
+    }
+}
+
+impl AccountReader for RpcClient {
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ReaderError> {
+        Ok(self.get_account(address)?.map(|account| account.data))
+    }
+}
+
+fn parse_pubkey(value: &Value) -> Result<Pubkey> {
+    value
+        .as_str()
+        .ok_or_else(|| anyhow!("expected a base58 address, got {value}"))?
+        .parse()
+        .map_err(|err| anyhow!("invalid address {value}: {err:?}"))
+}
+
+fn parse_account(account: &Value) -> Result<RawAccount> {
+    let data = account["data"][0]
+        .as_str()
+        .ok_or_else(|| anyhow!("account data is not base64 encoded"))?;
+    Ok(RawAccount {
+        owner: parse_pubkey(&account["owner"])?,
+        data: BASE64.decode(data)?,
+    })
+}
+
+fn rpc_error(method: &str, error: &Value) -> anyhow::Error {
+    let message = error["message"].as_str().unwrap_or("unknown error");
+    let data = &error["data"];
+    let mut text = format!("{method} failed: {message}");
+    if !data["err"].is_null() {
+        text.push_str(&format!("\n  cause: {}", describe_tx_error(&data["err"])));
+    }
+    if let Some(logs) = data["logs"].as_array() {
+        text.push_str("\n  logs:");
+        for line in logs.iter().filter_map(Value::as_str) {
+            text.push_str(&format!("\n    {line}"));
+        }
+    }
+    anyhow!(text)
+}
+
+/// Names the program error behind `{"InstructionError": [i, {"Custom": code}]}`.
+fn describe_tx_error(err: &Value) -> String {
+    let custom = err["InstructionError"][1]["Custom"].as_u64();
+    match custom.and_then(|code| decode_error(code as u32)) {
+        Some(error) => error.to_string(),
+        None => err.to_string(),
+    }
+}
@@ -0,0 +1,124 @@
+//! Fetching and decoding program accounts.
+//!
+//! The SDK does not pick an RPC client; implement [`AccountReader`] over whichever
+//! one the service already uses.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+use solearn::states::{
+    Application, Attestation, Bounty, Client, Dispute, HandleRecord, PlatformConfig, Project,
+    Review, Submission, User,
+};
+
+use crate::pda;
+
+pub type ReaderError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Source of raw account data, returning `None` for accounts that do not exist.
+pub trait AccountReader {
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ReaderError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum FetchError {
+    #[error("account {0} not found")]
+    NotFound(Pubkey),
+    #[error("failed to decode account {address}: {source}")]
+    Decode {
+        address: Pubkey,
+        source: anchor_lang::error::Error,
+    },
+    #[error("failed to read account: {0}")]
+    Reader(#[from] ReaderError),
+}
+
+/// Decodes account data, checking the Anchor discriminator.
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> anchor_lang::Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+pub fn fetch<T: AccountDeserialize>(
+    reader: &impl AccountReader,
+    address: &Pubkey,
+) -> Result<T, FetchError> {
+    let data = reader
+        .account_data(address)?
+        .ok_or(FetchError::NotFound(*address))?;
+    decode(&data).map_err(|source| FetchError::Decode {
+        address: *address,
+        source,
+    })
+}
+
+pub fn fetch_config(reader: &impl AccountReader) -> Result<PlatformConfig, FetchError> {
+    fetch(reader, &pda::config().0)
+}
+
+pub fn fetch_user(reader: &impl AccountReader, authority: &Pubkey) -> Result<User, FetchError> {
+    fetch(reader, &pda::user(authority).0)
+}
+
+pub fn fetch_client(reader: &impl AccountReader, authority: &Pubkey) -> Result<Client, FetchError> {
+    fetch(reader, &pda::client(authority).0)
+}
+
+/// Contributor profile holding `name` in the username registry.
+pub fn fetch_user_by_name(reader: &impl AccountReader, name: &str) -> Result<User, FetchError> {
+    let record: HandleRecord = fetch(reader, &pda::user_name(name).0)?;
+    fetch(reader, &record.profile)
+}
+
+/// Client profile holding `company_name` in the company handle registry.
+pub fn fetch_client_by_company_name(
+    reader: &impl AccountReader,
+    company_name: &str,
+) -> Result<Client, FetchError> {
+    let record: HandleRecord = fetch(reader, &pda::company_name(company_name).0)?;
+    fetch(reader, &record.profile)
+}
+
+pub fn fetch_bounty(reader: &impl AccountReader, bounty: &Pubkey) -> Result<Bounty, FetchError> {
+    fetch(reader, bounty)
+}
+
+pub fn fetch_submission(
+    reader: &impl AccountReader,
+    user_wallet: &Pubkey,
+    bounty: &Pubkey,
+) -> Result<Submission, FetchError> {
+    fetch(reader, &pda::submission(user_wallet, bounty).0)
+}
+
+pub fn fetch_dispute(reader: &impl AccountReader, bounty: &Pubkey) -> Result<Dispute, FetchError> {
+    fetch(reader, &pda::dispute(bounty).0)
+}
+
+pub fn fetch_project(reader: &impl AccountReader, project: &Pubkey) -> Result<Project, FetchError> {
+    fetch(reader, project)
+}
+
+pub fn fetch_application(
+    reader: &impl AccountReader,
+    user_wallet: &Pubkey,
+    project: &Pubkey,
+) -> Result<Application, FetchError> {
+    fetch(reader, &pda::application(user_wallet, project).0)
+}
+
+pub fn fetch_attestation(
+    reader: &impl AccountReader,
+    user_wallet: &Pubkey,
+    skill: &str,
+    issuer: &Pubkey,
+) -> Result<Attestation, FetchError> {
+    fetch(reader, &pda::attestation(user_wallet, skill, issuer).0)
+}
+
+pub fn fetch_review(
+    reader: &impl AccountReader,
+    bounty: &Pubkey,
+    reviewer: &Pubkey,
+    reviewee: &Pubkey,
+) -> Result<Review, FetchError> {
+    fetch(reader, &pda::review(bounty, reviewer, reviewee).0)
+}
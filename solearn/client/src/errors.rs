@@ -0,0 +1,323 @@
+//! Maps custom error codes from failed transactions back to the program's error enums.
+
+use solearn::errors::{
+    ApplicationError, AttestationError, BountyError, ClientError, ConfigError, DisputeError,
+    MilestoneError, ProjectError, ReviewError, SubmissionError, UserError,
+};
+
+/// First code used by the program's own errors; lower codes come from Anchor itself.
+pub const CUSTOM_ERROR_START: u32 = 6000;
+
+#[derive(Debug, Clone, Copy)]
+pub enum SolearnError {
+    Bounty(BountyError),
+    User(UserError),
+    Client(ClientError),
+    Submission(SubmissionError),
+    Application(ApplicationError),
+    Project(ProjectError),
+    Milestone(MilestoneError),
+    Dispute(DisputeError),
+    Config(ConfigError),
+    Attestation(AttestationError),
+    Review(ReviewError),
+}
+
+impl SolearnError {
+    pub fn code(&self) -> u32 {
+        match *self {
+            SolearnError::Bounty(error) => error.into(),
+            SolearnError::User(error) => error.into(),
+            SolearnError::Client(error) => error.into(),
+            SolearnError::Submission(error) => error.into(),
+            SolearnError::Application(error) => error.into(),
+            SolearnError::Project(error) => error.into(),
+            SolearnError::Milestone(error) => error.into(),
+            SolearnError::Dispute(error) => error.into(),
+            SolearnError::Config(error) => error.into(),
+            SolearnError::Attestation(error) => error.into(),
+            SolearnError::Review(error) => error.into(),
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            SolearnError::Bounty(error) => error.name(),
+            SolearnError::User(error) => error.name(),
+            SolearnError::Client(error) => error.name(),
+            SolearnError::Submission(error) => error.name(),
+            SolearnError::Application(error) => error.name(),
+            SolearnError::Project(error) => error.name(),
+            SolearnError::Milestone(error) => error.name(),
+            SolearnError::Dispute(error) => error.name(),
+            SolearnError::Config(error) => error.name(),
+            SolearnError::Attestation(error) => error.name(),
+            SolearnError::Review(error) => error.name(),
+        }
+    }
+}
+
+impl std::fmt::Display for SolearnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolearnError::Bounty(error) => error.fmt(f),
+            SolearnError::User(error) => error.fmt(f),
+            SolearnError::Client(error) => error.fmt(f),
+            SolearnError::Submission(error) => error.fmt(f),
+            SolearnError::Application(error) => error.fmt(f),
+            SolearnError::Project(error) => error.fmt(f),
+            SolearnError::Milestone(error) => error.fmt(f),
+            SolearnError::Dispute(error) => error.fmt(f),
+            SolearnError::Config(error) => error.fmt(f),
+            SolearnError::Attestation(error) => error.fmt(f),
+            SolearnError::Review(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SolearnError {}
+
+/// Decodes a custom program error code, e.g. from `InstructionError::Custom`.
+/// Returns `None` for Anchor framework codes and codes the program does not define.
+pub fn decode_error(code: u32) -> Option<SolearnError> {
+    if code < CUSTOM_ERROR_START {
+        return None;
+    }
+    decode_bounty_error(code)
+        .map(SolearnError::Bounty)
+        .or_else(|| decode_user_error(code).map(SolearnError::User))
+        .or_else(|| decode_client_error(code).map(SolearnError::Client))
+        .or_else(|| decode_submission_error(code).map(SolearnError::Submission))
+        .or_else(|| decode_application_error(code).map(SolearnError::Application))
+        .or_else(|| decode_project_error(code).map(SolearnError::Project))
+        .or_else(|| decode_milestone_error(code).map(SolearnError::Milestone))
+        .or_else(|| decode_dispute_error(code).map(SolearnError::Dispute))
+        .or_else(|| decode_config_error(code).map(SolearnError::Config))
+        .or_else(|| decode_attestation_error(code).map(SolearnError::Attestation))
+        .or_else(|| decode_review_error(code).map(SolearnError::Review))
+}
+
+// The exhaustive match stops a new program error from compiling until it is listed here.
+macro_rules! error_table {
+    ($decode:ident, $enum:ident { $($variant:ident),* $(,)? }) => {
+        fn $decode(code: u32) -> Option<$enum> {
+            #[allow(dead_code)]
+            fn exhaustive(error: $enum) {
+                match error {
+                    $($enum::$variant)|* => {}
+                }
+            }
+            [$($enum::$variant),*]
+                .into_iter()
+                .find(|error| u32::from(*error) == code)
+        }
+    };
+}
+
+error_table!(decode_bounty_error, BountyError {
+    BountyAlreadyClosed,
+    CannotUpdateWithSubmissions,
+    CannotDeleteWithSubmissions,
+    BountyDeadlinePassed,
+    BountyNotLive,
+    BountyAlreadyRewarded,
+    InvalidRewardAmount,
+    InvalidDeadline,
+    InsufficientSolBalance,
+    EscrowAccountNotFound,
+    InvalidEscrowAccount,
+    InsufficientTokenBalance,
+    InvalidRewardMint,
+    InvalidPrizeTiers,
+    PrizeTierNotFound,
+    PrizeTierAlreadySettled,
+    NotATieredBounty,
+    TieredBountyRequiresTierAward,
+    DeadlineNotReached,
+    ReviewWindowNotElapsed,
+    BountyHasEngagedContributor,
+    NotGuaranteedPayout,
+    GuaranteedPayoutPending,
+    InvalidTitle,
+    BountyAlreadyMigrated,
+    InvalidSkillGate,
+    BountyLayoutCurrent,
+    BountyNeedsId,
+    NoPayoutHeld,
+    DisputeWindowOpen,
+    PayoutOverflow,
+});
+
+error_table!(decode_user_error, UserError {
+    UserAlreadyExists,
+    UserNotFound,
+    InvalidUserAuthority,
+    EmailAlreadyTaken,
+    InvalidEmailFormat,
+    InvalidNameFormat,
+    IncompleteProfile,
+    InsufficientSkills,
+    NoSubmissions,
+    InvalidBioFormat,
+    UsernameAlreadyTaken,
+    InvalidSocials,
+    UserAlreadyMigrated,
+    UserDeleted,
+});
+
+error_table!(decode_client_error, ClientError {
+    ClientAlreadyExists,
+    ClientNotFound,
+    InvalidClientAuthority,
+    CompanyUsernameAlreadyTaken,
+    InvalidCompanyNameFormat,
+    InvalidCompanyEmailFormat,
+    InvalidCompanyLinkFormat,
+    IncompleteProfile,
+    NoBounties,
+    NotAuthorizedForBounty,
+    ClientAlreadyMigrated,
+    InvalidSocials,
+    HasLiveListings,
+    ClientDeleted,
+});
+
+error_table!(decode_submission_error, SubmissionError {
+    SubmissionAlreadyExists,
+    SubmissionNotFound,
+    InvalidSubmission,
+    SubmissionDeadlinePassed,
+    InsufficientSkills,
+    DescriptionTooLong,
+    InvalidSubmissionLink,
+    AlreadySubmitted,
+    WrongBounty,
+    SubmissionAlreadyAwarded,
+    NotEarliestSubmission,
+    InsufficientReputation,
+    SubmissionAlreadyMigrated,
+    SubmissionLocked,
+    EarliestSubmissionPending,
+    InvalidReviewStatus,
+    FeedbackTooLong,
+    EarliestSubmissionGuaranteed,
+    CannotSubmitToOwnBounty,
+    SubmissionRejected,
+});
+
+error_table!(decode_application_error, ApplicationError {
+    ApplicationAlreadyApproved,
+    ApplicationAlreadyRejected,
+    ApplicationNotPending,
+    CannotApproveNonPending,
+    CannotRejectNonPending,
+    ApplicationDeadlinePassed,
+    ApplicationNotFound,
+    InvalidApplicationStatus,
+    UserAlreadyApplied,
+    BountyNotAcceptingApplications,
+    InsufficientApplicationFee,
+    ApplicationDescriptionTooLong,
+    InvalidApplicationLink,
+    WorkAlreadySubmitted,
+    WorkNotSubmitted,
+    WorkAlreadyApproved,
+    WorkAlreadyRejected,
+    WorkNotApproved,
+    CannotSubmitWorkForNonApprovedApplication,
+    WorkDescriptionTooLong,
+    InvalidWorkLink,
+    InvalidAdditionalFilesLink,
+});
+
+error_table!(decode_project_error, ProjectError {
+    ProjectAlreadyClosed,
+    CannotUpdateWithApplications,
+    CannotDeleteWithApplications,
+    ProjectDeadlinePassed,
+    ProjectNotLive,
+    ProjectAlreadyRewarded,
+    InvalidRewardAmount,
+    InvalidDeadline,
+    InsufficientSolBalance,
+    InvalidProjectTitle,
+    InvalidProjectDescription,
+    ProjectNotAcceptingApplications,
+    UserAlreadyApplied,
+    ProjectNotFound,
+    InvalidProjectStatus,
+    ProjectHasApprovedApplication,
+    ProjectDeadlineNotReached,
+});
+
+error_table!(decode_milestone_error, MilestoneError {
+    InvalidMilestones,
+    InvalidDeliverable,
+    MilestoneNotFound,
+    NotAMilestoneBounty,
+    MilestoneBountyRequiresApproval,
+    ContributorAlreadyEngaged,
+    NoContributorEngaged,
+    NotEngagedContributor,
+    InvalidWorkLink,
+    MilestoneNotPending,
+    MilestoneNotSubmitted,
+    MilestoneAwaitingReview,
+    MilestoneReviewPeriodNotElapsed,
+});
+
+error_table!(decode_dispute_error, DisputeError {
+    EscrowFrozen,
+    NoArbitersDesignated,
+    InvalidArbiters,
+    ArbiterCannotSubmit,
+    ArbiterFeeTooHigh,
+    ArbitersLocked,
+    DisputeNotSupported,
+    NotDisputeParty,
+    NotArbiter,
+    AlreadyVoted,
+    DisputeAlreadyResolved,
+    InvalidEvidenceLink,
+    EvidenceLimitReached,
+    InvalidSplit,
+    InvalidArbiterWallets,
+    RulingPeriodNotElapsed,
+    GuaranteedPayoutLocked,
+    DisputeWindowClosed,
+    NotPassedOver,
+});
+
+error_table!(decode_config_error, ConfigError {
+    NotUpgradeAuthority,
+    NotPlatformAuthority,
+    PlatformFeeTooHigh,
+    InvalidTreasury,
+    InvalidMaxDeadlineDuration,
+    RewardBelowMinimum,
+    DeadlineBeyondMaximum,
+    NotGuardian,
+    InvalidGuardian,
+    ProgramPaused,
+    InstructionGroupPaused,
+    PauseStateUnchanged,
+    InvalidAuthority,
+});
+
+error_table!(decode_attestation_error, AttestationError {
+    NotAnIssuer,
+    InvalidSkill,
+    InvalidExpiry,
+    AttestationAlreadyRevoked,
+    NotAuthorizedToRevoke,
+    SkillNotRequiredByBounty,
+    NotBountyWinner,
+    InvalidAttestation,
+});
+
+error_table!(decode_review_error, ReviewError {
+    BountyNotCompleted,
+    NotBountyWinner,
+    InvalidScore,
+    CommentTooLong,
+});
@@ -0,0 +1,1278 @@
+//! Typed builders for every instruction in the program.
+//!
+//! Builders derive every PDA they can from the keys passed in. Bounty and project
+//! addresses are taken as-is, since they depend on ids and titles the caller holds.
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solearn::states::{
+    Country, DisputeRuling, Industries, MilestoneParams, PauseGroup, PayoutPolicy, SkillGate, Social,
+    SubmissionStatus,
+};
+use solearn::{accounts, instruction, ID};
+
+use crate::pda;
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+// Config
+
+pub fn initialize_config(
+    authority: Pubkey,
+    fee_bps: u16,
+    treasury: Pubkey,
+    min_reward: u64,
+    max_deadline_duration: u64,
+) -> Instruction {
+    build(
+        accounts::InitializeConfig {
+            authority,
+            config: pda::config().0,
+            program: ID,
+            program_data: pda::program_data().0,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeConfig {
+            fee_bps,
+            treasury,
+            min_reward,
+            max_deadline_duration,
+        },
+    )
+}
+
+pub fn update_config(
+    authority: Pubkey,
+    new_authority: Pubkey,
+    fee_bps: u16,
+    treasury: Pubkey,
+    min_reward: u64,
+    max_deadline_duration: u64,
+) -> Instruction {
+    build(
+        accounts::UpdateConfig {
+            authority,
+            config: pda::config().0,
+        },
+        instruction::UpdateConfig {
+            new_authority,
+            fee_bps,
+            treasury,
+            min_reward,
+            max_deadline_duration,
+        },
+    )
+}
+
+pub fn set_guardian(authority: Pubkey, guardian: Pubkey) -> Instruction {
+    build(
+        accounts::SetGuardian {
+            authority,
+            config: pda::config().0,
+        },
+        instruction::SetGuardian { guardian },
+    )
+}
+
+/// `None` pauses every fund-moving instruction.
+pub fn pause(guardian: Pubkey, group: Option<PauseGroup>) -> Instruction {
+    build(
+        accounts::SetPause {
+            guardian,
+            config: pda::config().0,
+        },
+        instruction::Pause { group },
+    )
+}
+
+pub fn unpause(guardian: Pubkey, group: Option<PauseGroup>) -> Instruction {
+    build(
+        accounts::SetPause {
+            guardian,
+            config: pda::config().0,
+        },
+        instruction::Unpause { group },
+    )
+}
+
+// Users
+
+pub fn create_user(authority: Pubkey, name: String, email: String, skills: Vec<String>) -> Instruction {
+    build(
+        accounts::CreateUser {
+            authority,
+            user: pda::user(&authority).0,
+            name_record: pda::user_name(&name).0,
+            email_record: pda::user_email(&email).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateUser { name, email, skills },
+    )
+}
+
+/// `current_name` and `current_email` are the profile's values before the update,
+/// whose registry records are released if they change.
+pub fn update_user(
+    authority: Pubkey,
+    current_name: &str,
+    current_email: &str,
+    name: String,
+    email: String,
+    bio: String,
+    skills: Vec<String>,
+) -> Instruction {
+    build(
+        accounts::UpdateUser {
+            authority,
+            user: pda::user(&authority).0,
+            current_name_record: pda::user_name(current_name).0,
+            current_email_record: pda::user_email(current_email).0,
+            name_record: pda::user_name(&name).0,
+            email_record: pda::user_email(&email).0,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateUser {
+            name,
+            email,
+            bio,
+            skills,
+        },
+    )
+}
+
+/// `name` and `email` are the profile's current values, whose registry records are released.
+pub fn delete_user(authority: Pubkey, name: &str, email: &str) -> Instruction {
+    build(
+        accounts::DeleteUser {
+            authority,
+            user: pda::user(&authority).0,
+            name_record: pda::user_name(name).0,
+            email_record: pda::user_email(email).0,
+            system_program: system_program::ID,
+        },
+        instruction::DeleteUser {},
+    )
+}
+
+pub fn set_user_details(authority: Pubkey, country: Country, socials: Social) -> Instruction {
+    build(
+        accounts::SetUserDetails {
+            authority,
+            user: pda::user(&authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetUserDetails { country, socials },
+    )
+}
+
+pub fn migrate_user(authority: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateUser {
+            authority,
+            user: pda::user(&authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateUser {},
+    )
+}
+
+// Clients
+
+pub fn create_client(
+    authority: Pubkey,
+    company_name: String,
+    company_email: String,
+    company_link: String,
+) -> Instruction {
+    build(
+        accounts::CreateClient {
+            authority,
+            client: pda::client(&authority).0,
+            name_record: pda::company_name(&company_name).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateClient {
+            company_name,
+            company_email,
+            company_link,
+        },
+    )
+}
+
+/// `current_company_name` is the profile's name before the update, whose registry
+/// record is released if it changes.
+pub fn update_client(
+    authority: Pubkey,
+    current_company_name: &str,
+    company_name: String,
+    company_email: String,
+    company_link: String,
+    company_bio: String,
+) -> Instruction {
+    build(
+        accounts::UpdateClient {
+            authority,
+            client: pda::client(&authority).0,
+            current_name_record: pda::company_name(current_company_name).0,
+            name_record: pda::company_name(&company_name).0,
+            system_program: system_program::ID,
+        },
+        instruction::UpdateClient {
+            company_name,
+            company_email,
+            company_link,
+            company_bio,
+        },
+    )
+}
+
+/// `company_name` is the profile's current name, whose registry record is released.
+pub fn delete_client(authority: Pubkey, company_name: &str) -> Instruction {
+    build(
+        accounts::DeleteClient {
+            authority,
+            client: pda::client(&authority).0,
+            name_record: pda::company_name(company_name).0,
+            system_program: system_program::ID,
+        },
+        instruction::DeleteClient {},
+    )
+}
+
+pub fn set_client_details(
+    authority: Pubkey,
+    industry: Industries,
+    country: Country,
+    socials: Social,
+) -> Instruction {
+    build(
+        accounts::SetClientDetails {
+            authority,
+            client: pda::client(&authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::SetClientDetails {
+            industry,
+            country,
+            socials,
+        },
+    )
+}
+
+pub fn migrate_client(authority: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateClient {
+            authority,
+            client: pda::client(&authority).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateClient {},
+    )
+}
+
+// Bounties
+
+/// `bounty_id` must be the client's current `next_bounty_id`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_bounty(
+    authority: Pubkey,
+    bounty_id: u64,
+    title: String,
+    description: String,
+    reward: u64,
+    deadline: u64,
+    skills_needed: Vec<String>,
+    payout_policy: PayoutPolicy,
+    review_period: u64,
+) -> Instruction {
+    let bounty = pda::bounty(&authority, bounty_id).0;
+    build(
+        accounts::CreateBounty {
+            authority,
+            client: pda::client(&authority).0,
+            config: pda::config().0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateBounty {
+            title,
+            description,
+            reward,
+            deadline,
+            skills_needed,
+            payout_policy,
+            review_period,
+        },
+    )
+}
+
+pub fn update_bounty(
+    authority: Pubkey,
+    bounty: Pubkey,
+    new_title: String,
+    new_description: String,
+    new_deadline: u64,
+) -> Instruction {
+    build(
+        accounts::UpdateBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            config: pda::config().0,
+        },
+        instruction::UpdateBounty {
+            new_title,
+            new_description,
+            new_deadline,
+        },
+    )
+}
+
+pub fn delete_bounty(authority: Pubkey, bounty: Pubkey) -> Instruction {
+    build(
+        accounts::DeleteBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::DeleteBounty {},
+    )
+}
+
+pub fn migrate_bounty(authority: Pubkey, title: String) -> Instruction {
+    build(
+        accounts::MigrateBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty: pda::legacy_bounty(&authority, &title).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateBounty { title },
+    )
+}
+
+/// Upgrades the layout of one of `authority`'s bounties, addressed by id or by a
+/// title it was already migrated with.
+pub fn migrate_bounty_layout(authority: Pubkey, bounty: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateBountyLayout {
+            authority,
+            bounty,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateBountyLayout {},
+    )
+}
+
+pub fn migrate_submission(authority: Pubkey, bounty: Pubkey) -> Instruction {
+    build(
+        accounts::MigrateSubmission {
+            authority,
+            bounty,
+            submission: pda::submission(&authority, &bounty).0,
+            system_program: system_program::ID,
+        },
+        instruction::MigrateSubmission {},
+    )
+}
+
+pub fn create_submission(
+    authority: Pubkey,
+    bounty: Pubkey,
+    submission_desc: String,
+    submission_link: String,
+) -> Instruction {
+    build(
+        accounts::CreateSubmission {
+            authority,
+            user: pda::user(&authority).0,
+            bounty,
+            submission: pda::submission(&authority, &bounty).0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateSubmission {
+            submission_desc,
+            submission_link,
+        },
+    )
+}
+
+pub fn update_submission(
+    authority: Pubkey,
+    bounty: Pubkey,
+    submission_desc: String,
+    submission_link: String,
+) -> Instruction {
+    build(
+        accounts::UpdateSubmission {
+            authority,
+            bounty,
+            submission: pda::submission(&authority, &bounty).0,
+        },
+        instruction::UpdateSubmission {
+            submission_desc,
+            submission_link,
+        },
+    )
+}
+
+pub fn withdraw_submission(authority: Pubkey, bounty: Pubkey) -> Instruction {
+    build(
+        accounts::WithdrawSubmission {
+            authority,
+            user: pda::user(&authority).0,
+            bounty,
+            submission: pda::submission(&authority, &bounty).0,
+        },
+        instruction::WithdrawSubmission {},
+    )
+}
+
+/// Review by the bounty's creator of `contributor`'s submission.
+pub fn review_submission(
+    authority: Pubkey,
+    bounty: Pubkey,
+    contributor: Pubkey,
+    status: SubmissionStatus,
+    feedback: String,
+) -> Instruction {
+    build(
+        accounts::ReviewSubmission {
+            authority,
+            bounty,
+            submission: pda::submission(&contributor, &bounty).0,
+        },
+        instruction::ReviewSubmission { status, feedback },
+    )
+}
+
+/// Submission to a bounty that only counts attested skills, presenting the
+/// contributor's `attestations`.
+pub fn create_attested_submission(
+    authority: Pubkey,
+    bounty: Pubkey,
+    submission_desc: String,
+    submission_link: String,
+    attestations: &[Pubkey],
+) -> Instruction {
+    let mut ix = create_submission(authority, bounty, submission_desc, submission_link);
+    ix.accounts.extend(
+        attestations
+            .iter()
+            .map(|attestation| AccountMeta::new_readonly(*attestation, false)),
+    );
+    ix
+}
+
+pub fn select_submission(
+    authority: Pubkey,
+    bounty: Pubkey,
+    winner_wallet: Pubkey,
+    treasury: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SelectSubmission {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            submission: pda::submission(&winner_wallet, &bounty).0,
+            selected_user: pda::user(&winner_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            selected_user_wallet: winner_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::SelectSubmission {},
+    )
+}
+
+/// `bounty_id` must be the client's current `next_bounty_id`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_token_bounty(
+    authority: Pubkey,
+    bounty_id: u64,
+    reward_mint: Pubkey,
+    authority_token_account: Pubkey,
+    token_program: Pubkey,
+    title: String,
+    description: String,
+    reward: u64,
+    deadline: u64,
+    skills_needed: Vec<String>,
+) -> Instruction {
+    let bounty = pda::bounty(&authority, bounty_id).0;
+    build(
+        accounts::CreateTokenBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            reward_mint,
+            authority_token_account,
+            escrow_token_account: pda::escrow_token(&bounty).0,
+            config: pda::config().0,
+            token_program,
+            system_program: system_program::ID,
+        },
+        instruction::CreateTokenBounty {
+            title,
+            description,
+            reward,
+            deadline,
+            skills_needed,
+        },
+    )
+}
+
+pub fn delete_token_bounty(
+    authority: Pubkey,
+    bounty: Pubkey,
+    reward_mint: Pubkey,
+    authority_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::DeleteTokenBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            reward_mint,
+            escrow_token_account: pda::escrow_token(&bounty).0,
+            authority_token_account,
+            config: pda::config().0,
+            token_program,
+            system_program: system_program::ID,
+        },
+        instruction::DeleteTokenBounty {},
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn select_token_submission(
+    authority: Pubkey,
+    bounty: Pubkey,
+    winner_wallet: Pubkey,
+    reward_mint: Pubkey,
+    winner_token_account: Pubkey,
+    treasury_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::SelectTokenSubmission {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            submission: pda::submission(&winner_wallet, &bounty).0,
+            selected_user: pda::user(&winner_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            reward_mint,
+            escrow_token_account: pda::escrow_token(&bounty).0,
+            selected_user_token_account: winner_token_account,
+            config: pda::config().0,
+            treasury_token_account,
+            token_program,
+        },
+        instruction::SelectTokenSubmission {},
+    )
+}
+
+/// `bounty_id` must be the client's current `next_bounty_id`.
+pub fn create_tiered_bounty(
+    authority: Pubkey,
+    bounty_id: u64,
+    title: String,
+    description: String,
+    prizes: Vec<u64>,
+    deadline: u64,
+    skills_needed: Vec<String>,
+) -> Instruction {
+    let bounty = pda::bounty(&authority, bounty_id).0;
+    build(
+        accounts::CreateTieredBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateTieredBounty {
+            title,
+            description,
+            prizes,
+            deadline,
+            skills_needed,
+        },
+    )
+}
+
+pub fn award_prize_tier(
+    authority: Pubkey,
+    bounty: Pubkey,
+    winner_wallet: Pubkey,
+    treasury: Pubkey,
+    tier_index: u8,
+) -> Instruction {
+    build(
+        accounts::AwardPrizeTier {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            submission: pda::submission(&winner_wallet, &bounty).0,
+            selected_user: pda::user(&winner_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            selected_user_wallet: winner_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::AwardPrizeTier { tier_index },
+    )
+}
+
+pub fn forfeit_prize_tier(authority: Pubkey, bounty: Pubkey, tier_index: u8) -> Instruction {
+    build(
+        accounts::ForfeitPrizeTier {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::ForfeitPrizeTier { tier_index },
+    )
+}
+
+pub fn expire_bounty(cranker: Pubkey, bounty: Pubkey, creator_wallet: Pubkey) -> Instruction {
+    build(
+        accounts::ExpireBounty {
+            cranker,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            creator_wallet,
+            client: pda::client(&creator_wallet).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::ExpireBounty {},
+    )
+}
+
+pub fn expire_token_bounty(
+    cranker: Pubkey,
+    bounty: Pubkey,
+    creator_wallet: Pubkey,
+    reward_mint: Pubkey,
+    creator_token_account: Pubkey,
+    token_program: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ExpireTokenBounty {
+            cranker,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            reward_mint,
+            escrow_token_account: pda::escrow_token(&bounty).0,
+            creator_token_account,
+            creator_wallet,
+            client: pda::client(&creator_wallet).0,
+            config: pda::config().0,
+            token_program,
+        },
+        instruction::ExpireTokenBounty {},
+    )
+}
+
+pub fn auto_release_bounty(
+    cranker: Pubkey,
+    bounty: Pubkey,
+    creator_wallet: Pubkey,
+    earliest_wallet: Pubkey,
+    treasury: Pubkey,
+) -> Instruction {
+    build(
+        accounts::AutoReleaseBounty {
+            cranker,
+            client: pda::client(&creator_wallet).0,
+            bounty,
+            submission: pda::submission(&earliest_wallet, &bounty).0,
+            selected_user: pda::user(&earliest_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            selected_user_wallet: earliest_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::AutoReleaseBounty {},
+    )
+}
+
+pub fn release_held_payout(
+    cranker: Pubkey,
+    bounty: Pubkey,
+    creator_wallet: Pubkey,
+    winner_wallet: Pubkey,
+    treasury: Pubkey,
+) -> Instruction {
+    build(
+        accounts::ReleaseHeldPayout {
+            cranker,
+            client: pda::client(&creator_wallet).0,
+            bounty,
+            submission: pda::submission(&winner_wallet, &bounty).0,
+            selected_user: pda::user(&winner_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            selected_user_wallet: winner_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::ReleaseHeldPayout {},
+    )
+}
+
+pub fn set_bounty_arbiters(
+    authority: Pubkey,
+    bounty: Pubkey,
+    arbiters: Vec<Pubkey>,
+    arbiter_fee_bps: u16,
+) -> Instruction {
+    build(
+        accounts::SetBountyArbiters {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+        },
+        instruction::SetBountyArbiters {
+            arbiters,
+            arbiter_fee_bps,
+        },
+    )
+}
+
+pub fn set_bounty_skill_gate(
+    authority: Pubkey,
+    bounty: Pubkey,
+    skill_gate: SkillGate,
+    attested_skills: bool,
+) -> Instruction {
+    build(
+        accounts::SetBountySkillGate {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+        },
+        instruction::SetBountySkillGate {
+            skill_gate,
+            attested_skills,
+        },
+    )
+}
+
+pub fn set_bounty_min_reputation(authority: Pubkey, bounty: Pubkey, min_reputation: u64) -> Instruction {
+    build(
+        accounts::SetBountyMinReputation {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+        },
+        instruction::SetBountyMinReputation { min_reputation },
+    )
+}
+
+// Milestones
+
+/// `bounty_id` must be the client's current `next_bounty_id`.
+pub fn create_milestone_bounty(
+    authority: Pubkey,
+    bounty_id: u64,
+    title: String,
+    description: String,
+    milestones: Vec<MilestoneParams>,
+    deadline: u64,
+    skills_needed: Vec<String>,
+) -> Instruction {
+    let bounty = pda::bounty(&authority, bounty_id).0;
+    build(
+        accounts::CreateMilestoneBounty {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateMilestoneBounty {
+            title,
+            description,
+            milestones,
+            deadline,
+            skills_needed,
+        },
+    )
+}
+
+pub fn engage_contributor(authority: Pubkey, bounty: Pubkey, contributor_wallet: Pubkey) -> Instruction {
+    build(
+        accounts::EngageContributor {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            submission: pda::submission(&contributor_wallet, &bounty).0,
+        },
+        instruction::EngageContributor {},
+    )
+}
+
+pub fn submit_milestone(
+    authority: Pubkey,
+    bounty: Pubkey,
+    milestone_index: u8,
+    work_url: String,
+) -> Instruction {
+    build(
+        accounts::SubmitMilestone { authority, bounty },
+        instruction::SubmitMilestone {
+            milestone_index,
+            work_url,
+        },
+    )
+}
+
+pub fn review_milestone(
+    authority: Pubkey,
+    bounty: Pubkey,
+    contributor_wallet: Pubkey,
+    treasury: Pubkey,
+    milestone_index: u8,
+    approved: bool,
+) -> Instruction {
+    build(
+        accounts::ReviewMilestone {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            selected_user: pda::user(&contributor_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            selected_user_wallet: contributor_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::ReviewMilestone {
+            milestone_index,
+            approved,
+        },
+    )
+}
+
+/// Called by the engaged contributor once the client's review period has lapsed.
+pub fn release_milestone(
+    contributor: Pubkey,
+    bounty: Pubkey,
+    client_wallet: Pubkey,
+    treasury: Pubkey,
+    milestone_index: u8,
+) -> Instruction {
+    build(
+        accounts::ReleaseMilestone {
+            authority: contributor,
+            client: pda::client(&client_wallet).0,
+            bounty,
+            selected_user: pda::user(&contributor).0,
+            escrow_account: pda::escrow(&bounty).0,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::ReleaseMilestone { milestone_index },
+    )
+}
+
+pub fn end_milestone_engagement(authority: Pubkey, bounty: Pubkey) -> Instruction {
+    build(
+        accounts::EndMilestoneEngagement {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            escrow_account: pda::escrow(&bounty).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::EndMilestoneEngagement {},
+    )
+}
+
+// Disputes
+
+pub fn open_dispute(
+    authority: Pubkey,
+    bounty: Pubkey,
+    contributor_wallet: Pubkey,
+    evidence_link: String,
+) -> Instruction {
+    build(
+        accounts::OpenDispute {
+            authority,
+            bounty,
+            submission: pda::submission(&contributor_wallet, &bounty).0,
+            dispute: pda::dispute(&bounty).0,
+            system_program: system_program::ID,
+        },
+        instruction::OpenDispute { evidence_link },
+    )
+}
+
+pub fn submit_evidence(authority: Pubkey, bounty: Pubkey, evidence_link: String) -> Instruction {
+    build(
+        accounts::SubmitEvidence {
+            authority,
+            dispute: pda::dispute(&bounty).0,
+        },
+        instruction::SubmitEvidence { evidence_link },
+    )
+}
+
+/// `majority_arbiters` are the wallets of the arbiters who voted for the winning
+/// ruling, in panel order, when this vote completes a majority; empty otherwise.
+pub fn cast_ruling(
+    arbiter: Pubkey,
+    bounty: Pubkey,
+    client_wallet: Pubkey,
+    contributor_wallet: Pubkey,
+    treasury: Pubkey,
+    ruling: DisputeRuling,
+    majority_arbiters: &[Pubkey],
+) -> Instruction {
+    let mut ix = build(
+        accounts::CastRuling {
+            arbiter,
+            dispute: pda::dispute(&bounty).0,
+            bounty,
+            submission: pda::submission(&contributor_wallet, &bounty).0,
+            client: pda::client(&client_wallet).0,
+            contributor: pda::user(&contributor_wallet).0,
+            escrow_account: pda::escrow(&bounty).0,
+            client_wallet,
+            contributor_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::CastRuling { ruling },
+    );
+    ix.accounts.extend(
+        majority_arbiters
+            .iter()
+            .map(|wallet| AccountMeta::new(*wallet, false)),
+    );
+    ix
+}
+
+/// Lifts the freeze on a bounty whose dispute panel missed its deadline, without a payout.
+pub fn resolve_expired_dispute(cranker: Pubkey, bounty: Pubkey) -> Instruction {
+    build(
+        accounts::ResolveExpiredDispute {
+            cranker,
+            dispute: pda::dispute(&bounty).0,
+            bounty,
+        },
+        instruction::ResolveExpiredDispute {},
+    )
+}
+
+// Attestations
+
+pub fn approve_issuer(authority: Pubkey, client_wallet: Pubkey) -> Instruction {
+    build(
+        accounts::ApproveIssuer {
+            authority,
+            config: pda::config().0,
+            client: pda::client(&client_wallet).0,
+            trusted_issuer: pda::trusted_issuer(&client_wallet).0,
+            system_program: system_program::ID,
+        },
+        instruction::ApproveIssuer {},
+    )
+}
+
+pub fn remove_issuer(authority: Pubkey, wallet: Pubkey) -> Instruction {
+    build(
+        accounts::RemoveIssuer {
+            authority,
+            config: pda::config().0,
+            trusted_issuer: pda::trusted_issuer(&wallet).0,
+        },
+        instruction::RemoveIssuer {},
+    )
+}
+
+/// `expires_at` is a unix timestamp, or 0 for an attestation that never expires.
+pub fn issue_attestation(
+    issuer: Pubkey,
+    user_wallet: Pubkey,
+    skill: String,
+    expires_at: u64,
+) -> Instruction {
+    build(
+        accounts::IssueAttestation {
+            issuer,
+            config: pda::config().0,
+            issuer_record: pda::trusted_issuer(&issuer).0,
+            user_wallet,
+            attestation: pda::attestation(&user_wallet, &skill, &issuer).0,
+            system_program: system_program::ID,
+        },
+        instruction::IssueAttestation { skill, expires_at },
+    )
+}
+
+pub fn attest_from_bounty(authority: Pubkey, bounty: Pubkey, skill: String) -> Instruction {
+    build(
+        accounts::AttestFromBounty {
+            authority,
+            bounty,
+            submission: pda::submission(&authority, &bounty).0,
+            attestation: pda::attestation(&authority, &skill, &bounty).0,
+            system_program: system_program::ID,
+        },
+        instruction::AttestFromBounty { skill },
+    )
+}
+
+pub fn revoke_attestation(
+    authority: Pubkey,
+    user_wallet: Pubkey,
+    skill: &str,
+    issuer: Pubkey,
+) -> Instruction {
+    build(
+        accounts::RevokeAttestation {
+            authority,
+            config: pda::config().0,
+            attestation: pda::attestation(&user_wallet, skill, &issuer).0,
+        },
+        instruction::RevokeAttestation {},
+    )
+}
+
+// Reviews
+
+/// Client rating the contributor whose submission won `bounty`.
+pub fn review_contributor(
+    authority: Pubkey,
+    bounty: Pubkey,
+    contributor_wallet: Pubkey,
+    score: u8,
+    comment: String,
+) -> Instruction {
+    build(
+        accounts::ReviewContributor {
+            authority,
+            client: pda::client(&authority).0,
+            bounty,
+            submission: pda::submission(&contributor_wallet, &bounty).0,
+            contributor: pda::user(&contributor_wallet).0,
+            review: pda::review(&bounty, &authority, &contributor_wallet).0,
+            system_program: system_program::ID,
+        },
+        instruction::ReviewContributor { score, comment },
+    )
+}
+
+/// Winning contributor rating the client that posted `bounty`.
+pub fn review_client(
+    authority: Pubkey,
+    bounty: Pubkey,
+    creator_wallet: Pubkey,
+    score: u8,
+    comment: String,
+) -> Instruction {
+    build(
+        accounts::ReviewClient {
+            authority,
+            bounty,
+            submission: pda::submission(&authority, &bounty).0,
+            client: pda::client(&creator_wallet).0,
+            review: pda::review(&bounty, &authority, &creator_wallet).0,
+            system_program: system_program::ID,
+        },
+        instruction::ReviewClient { score, comment },
+    )
+}
+
+// Projects
+
+/// `project_id` must be the client's current `next_project_id`.
+pub fn create_project(
+    authority: Pubkey,
+    project_id: u64,
+    title: String,
+    description: String,
+    reward: u64,
+    deadline: u64,
+    skills_needed: Vec<String>,
+) -> Instruction {
+    let project = pda::project(&authority, project_id).0;
+    build(
+        accounts::CreateProject {
+            authority,
+            client: pda::client(&authority).0,
+            project,
+            escrow_account: pda::escrow(&project).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::CreateProject {
+            title,
+            description,
+            reward,
+            deadline,
+            skills_needed,
+        },
+    )
+}
+
+pub fn update_project(authority: Pubkey, project: Pubkey, description: String, deadline: u64) -> Instruction {
+    build(
+        accounts::UpdateProject {
+            authority,
+            client: pda::client(&authority).0,
+            project,
+            config: pda::config().0,
+        },
+        instruction::UpdateProject {
+            description,
+            deadline,
+        },
+    )
+}
+
+pub fn close_project(authority: Pubkey, project: Pubkey) -> Instruction {
+    build(
+        accounts::CloseProject {
+            authority,
+            client: pda::client(&authority).0,
+            project,
+            escrow_account: pda::escrow(&project).0,
+            config: pda::config().0,
+            system_program: system_program::ID,
+        },
+        instruction::CloseProject {},
+    )
+}
+
+// Applications
+
+pub fn apply_to_project(authority: Pubkey, project: Pubkey, description: String, link: String) -> Instruction {
+    build(
+        accounts::ApplyToProject {
+            authority,
+            user: pda::user(&authority).0,
+            project,
+            application: pda::application(&authority, &project).0,
+            system_program: system_program::ID,
+        },
+        instruction::ApplyToProject { description, link },
+    )
+}
+
+fn review_application_accounts(
+    authority: Pubkey,
+    project: Pubkey,
+    applicant_wallet: Pubkey,
+) -> accounts::ReviewApplication {
+    accounts::ReviewApplication {
+        authority,
+        client: pda::client(&authority).0,
+        project,
+        application: pda::application(&applicant_wallet, &project).0,
+    }
+}
+
+pub fn approve_application(authority: Pubkey, project: Pubkey, applicant_wallet: Pubkey) -> Instruction {
+    build(
+        review_application_accounts(authority, project, applicant_wallet),
+        instruction::ApproveApplication {},
+    )
+}
+
+pub fn reject_application(authority: Pubkey, project: Pubkey, applicant_wallet: Pubkey) -> Instruction {
+    build(
+        review_application_accounts(authority, project, applicant_wallet),
+        instruction::RejectApplication {},
+    )
+}
+
+pub fn unapprove_application(authority: Pubkey, project: Pubkey, applicant_wallet: Pubkey) -> Instruction {
+    build(
+        review_application_accounts(authority, project, applicant_wallet),
+        instruction::UnapproveApplication {},
+    )
+}
+
+pub fn submit_work(
+    authority: Pubkey,
+    project: Pubkey,
+    work_description: String,
+    work_link: String,
+    additional_files_link: String,
+) -> Instruction {
+    build(
+        accounts::SubmitWork {
+            authority,
+            project,
+            application: pda::application(&authority, &project).0,
+        },
+        instruction::SubmitWork {
+            work_description,
+            work_link,
+            additional_files_link,
+        },
+    )
+}
+
+pub fn approve_work(authority: Pubkey, project: Pubkey, applicant_wallet: Pubkey, treasury: Pubkey) -> Instruction {
+    build(
+        accounts::ApproveWork {
+            authority,
+            client: pda::client(&authority).0,
+            project,
+            application: pda::application(&applicant_wallet, &project).0,
+            applicant: pda::user(&applicant_wallet).0,
+            escrow_account: pda::escrow(&project).0,
+            applicant_wallet,
+            config: pda::config().0,
+            treasury,
+            system_program: system_program::ID,
+        },
+        instruction::ApproveWork {},
+    )
+}
+
+pub fn reject_work(authority: Pubkey, project: Pubkey, applicant_wallet: Pubkey) -> Instruction {
+    build(
+        accounts::RejectWork {
+            authority,
+            client: pda::client(&authority).0,
+            project,
+            application: pda::application(&applicant_wallet, &project).0,
+        },
+        instruction::RejectWork {},
+    )
+}
@@ -0,0 +1,10 @@
+//! Rust client for the solearn program: PDA derivation, instruction builders,
+//! account decoding and error-code decoding.
+
+pub mod accounts;
+pub mod errors;
+pub mod instructions;
+pub mod pda;
+
+pub use solearn::states;
+pub use solearn::ID as PROGRAM_ID;
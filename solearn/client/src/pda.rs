@@ -0,0 +1,126 @@
+//! Program-derived addresses, mirroring the seeds in the program's account constraints.
+
+use anchor_lang::prelude::Pubkey;
+use solearn::states::{
+    handle_hash, skill_hash, COMPANY_NAME_SEED, USER_EMAIL_SEED, USER_NAME_SEED,
+};
+use solearn::ID;
+
+pub fn config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"config"], &ID)
+}
+
+pub fn user(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"user", authority.as_ref()], &ID)
+}
+
+pub fn client(authority: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"client", authority.as_ref()], &ID)
+}
+
+/// Registry record reserving a contributor username.
+pub fn user_name(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_NAME_SEED, handle_hash(name).as_ref()], &ID)
+}
+
+/// Registry record reserving a contributor email, addressed by its hash.
+pub fn user_email(email: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[USER_EMAIL_SEED, handle_hash(email).as_ref()], &ID)
+}
+
+/// Registry record reserving a client's company handle.
+pub fn company_name(company_name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COMPANY_NAME_SEED, handle_hash(company_name).as_ref()],
+        &ID,
+    )
+}
+
+/// Bounty posted under `bounty_id`, the creator's `Client::next_bounty_id` at the time.
+pub fn bounty(creator: &Pubkey, bounty_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"bounty", &bounty_id.to_le_bytes(), creator.as_ref()],
+        &ID,
+    )
+}
+
+/// Bounty posted before ids existed, still addressed by its original title.
+pub fn legacy_bounty(creator: &Pubkey, title: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"bounty", title.as_bytes(), creator.as_ref()], &ID)
+}
+
+/// SOL escrow of a bounty or project.
+pub fn escrow(bounty_or_project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow", bounty_or_project.as_ref()], &ID)
+}
+
+/// Token account holding a token bounty's reward, owned by the bounty's escrow PDA.
+pub fn escrow_token(bounty: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow_token", bounty.as_ref()], &ID)
+}
+
+pub fn submission(user_wallet: &Pubkey, bounty: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"submission", user_wallet.as_ref(), bounty.as_ref()],
+        &ID,
+    )
+}
+
+pub fn dispute(bounty: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"dispute", bounty.as_ref()], &ID)
+}
+
+/// Project posted under `project_id`, the creator's `Client::next_project_id` at the time.
+pub fn project(creator: &Pubkey, project_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"project", &project_id.to_le_bytes(), creator.as_ref()],
+        &ID,
+    )
+}
+
+pub fn application(user_wallet: &Pubkey, project: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[b"application", user_wallet.as_ref(), project.as_ref()],
+        &ID,
+    )
+}
+
+/// The program's upgradeable-loader data account, needed by `initialize_config`.
+pub fn program_data() -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ID.as_ref()],
+        &anchor_lang::solana_program::bpf_loader_upgradeable::ID,
+    )
+}
+
+/// Approval letting a client wallet issue attestations.
+pub fn trusted_issuer(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"issuer", wallet.as_ref()], &ID)
+}
+
+/// Attestation of `skill` for `user_wallet`. `issuer` is the issuing wallet, or the
+/// bounty for attestations earned by winning one.
+pub fn attestation(user_wallet: &Pubkey, skill: &str, issuer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"attestation",
+            user_wallet.as_ref(),
+            skill_hash(skill).as_ref(),
+            issuer.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+/// Review `reviewer` left for `reviewee` on a completed bounty.
+pub fn review(bounty: &Pubkey, reviewer: &Pubkey, reviewee: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"review",
+            bounty.as_ref(),
+            reviewer.as_ref(),
+            reviewee.as_ref(),
+        ],
+        &ID,
+    )
+}
@@ -0,0 +1,519 @@
+//! Test harness running the built solearn program inside LiteSVM.
+//!
+//! Every transaction is paid for by a dedicated fee payer, so the lamport balances
+//! of the actors in a test only move by what the program itself transfers.
+
+use std::path::PathBuf;
+
+use anchor_lang::prelude::{Clock, Pubkey};
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use anchor_lang::solana_program::instruction::{Instruction, InstructionError};
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator, Space};
+use anchor_spl::token::spl_token;
+use litesvm::LiteSVM;
+use solana_account::Account;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
+use solearn::states::{Bounty, Client, PayoutPolicy, User, Versioned};
+use solearn_client::accounts::{AccountReader, ReaderError};
+use solearn_client::{instructions as ix, pda, PROGRAM_ID};
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+pub const DAY: u64 = 24 * 60 * 60;
+
+/// Longest deadline the default test config accepts.
+pub const MAX_DEADLINE_DURATION: u64 = 30 * DAY;
+
+// Packed sizes of SPL token mints and accounts
+const MINT_LEN: usize = 82;
+const TOKEN_ACCOUNT_LEN: usize = 165;
+
+pub type TxResult = Result<(), TransactionError>;
+
+/// Serializes each value in turn, the way Borsh lays out a struct with those fields.
+#[macro_export]
+macro_rules! borsh_fields {
+    ($($field:expr),+ $(,)?) => {{
+        let mut data = Vec::new();
+        $(anchor_lang::AnchorSerialize::serialize(&$field, &mut data).unwrap();)+
+        data
+    }};
+}
+
+pub struct TestEnv {
+    pub svm: LiteSVM,
+    /// Pays every transaction fee.
+    pub payer: Keypair,
+    /// Upgrade authority of the deployed program, and platform authority once
+    /// the config is initialized.
+    pub upgrade_authority: Keypair,
+    pub treasury: Pubkey,
+}
+
+/// Location of the program binary, overridable with `SOLEARN_PROGRAM`.
+fn program_path() -> PathBuf {
+    match std::env::var_os("SOLEARN_PROGRAM") {
+        Some(path) => PathBuf::from(path),
+        None => PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../target/deploy/solearn.so"),
+    }
+}
+
+impl TestEnv {
+    /// Deploys the program behind the upgradeable loader, without a config.
+    pub fn new() -> Self {
+        let path = program_path();
+        let elf = std::fs::read(&path).unwrap_or_else(|err| {
+            panic!(
+                "failed to read {}: {err}; run `anchor build` first",
+                path.display()
+            )
+        });
+
+        let mut svm = LiteSVM::new();
+        let payer = Keypair::new();
+        let upgrade_authority = Keypair::new();
+        svm.airdrop(&payer.pubkey(), 1_000 * LAMPORTS_PER_SOL)
+            .unwrap();
+        svm.airdrop(&upgrade_authority.pubkey(), 10 * LAMPORTS_PER_SOL)
+            .unwrap();
+
+        // ProgramData: state tag 3, deployment slot, Some(upgrade authority), ELF
+        let program_data = pda::program_data().0;
+        let mut data = Vec::with_capacity(45 + elf.len());
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.push(1);
+        data.extend_from_slice(upgrade_authority.pubkey().as_ref());
+        data.extend_from_slice(&elf);
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+        svm.set_account(
+            program_data,
+            Account {
+                lamports,
+                data,
+                owner: bpf_loader_upgradeable::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        // Program: state tag 2 followed by the ProgramData address
+        let mut data = Vec::with_capacity(36);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(program_data.as_ref());
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+        svm.set_account(
+            PROGRAM_ID,
+            Account {
+                lamports,
+                data,
+                owner: bpf_loader_upgradeable::ID,
+                executable: true,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let treasury = Pubkey::new_unique();
+        svm.airdrop(&treasury, LAMPORTS_PER_SOL).unwrap();
+
+        Self {
+            svm,
+            payer,
+            upgrade_authority,
+            treasury,
+        }
+    }
+
+    /// Deploys the program and initializes the config with no platform fee.
+    pub fn initialized() -> Self {
+        Self::with_fee(0)
+    }
+
+    pub fn with_fee(fee_bps: u16) -> Self {
+        let mut env = Self::new();
+        let authority = env.upgrade_authority.insecure_clone();
+        env.send(
+            ix::initialize_config(
+                authority.pubkey(),
+                fee_bps,
+                env.treasury,
+                0,
+                MAX_DEADLINE_DURATION,
+            ),
+            &[&authority],
+        )
+        .unwrap();
+        env
+    }
+
+    /// Sends `instruction` signed by `signers`, with the fee payer paying.
+    pub fn send(&mut self, instruction: Instruction, signers: &[&Keypair]) -> TxResult {
+        self.send_all(&[instruction], signers)
+    }
+
+    pub fn send_all(&mut self, instructions: &[Instruction], signers: &[&Keypair]) -> TxResult {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &all_signers,
+            self.svm.latest_blockhash(),
+        );
+        let result = self.svm.send_transaction(transaction);
+        // Identical transactions later in a test must not be rejected as duplicates
+        self.svm.expire_blockhash();
+        result.map(|_| ()).map_err(|failed| failed.err)
+    }
+
+    /// New funded keypair holding `sol` SOL.
+    pub fn keypair(&mut self, sol: u64) -> Keypair {
+        let keypair = Keypair::new();
+        self.svm
+            .airdrop(&keypair.pubkey(), sol * LAMPORTS_PER_SOL)
+            .unwrap();
+        keypair
+    }
+
+    /// Funded keypair with a client profile.
+    pub fn client(&mut self) -> Keypair {
+        let client = self.keypair(100);
+        // Company handles are unique, so each client gets its own
+        let company_name = format!("Acme {}", &client.pubkey().to_string()[..8]);
+        self.send(
+            ix::create_client(
+                client.pubkey(),
+                company_name,
+                "ops@acme.dev".to_string(),
+                "https://acme.dev".to_string(),
+            ),
+            &[&client],
+        )
+        .unwrap();
+        client
+    }
+
+    /// Funded keypair with a contributor profile.
+    pub fn user(&mut self) -> Keypair {
+        let user = self.keypair(10);
+        // Usernames and emails are unique, so each user gets their own
+        let name = user.pubkey().to_string()[..8].to_lowercase();
+        self.send(
+            ix::create_user(
+                user.pubkey(),
+                name.clone(),
+                format!("{name}@example.com"),
+                vec!["rust".to_string()],
+            ),
+            &[&user],
+        )
+        .unwrap();
+        user
+    }
+
+    /// Posts a `ClientSelects` bounty paying `reward` SOL, due in a week.
+    pub fn bounty(&mut self, client: &Keypair, reward: u64) -> Pubkey {
+        let deadline = self.now() + 7 * DAY;
+        self.bounty_with_policy(client, reward, deadline, PayoutPolicy::ClientSelects, 0)
+    }
+
+    pub fn bounty_with_policy(
+        &mut self,
+        client: &Keypair,
+        reward: u64,
+        deadline: u64,
+        payout_policy: PayoutPolicy,
+        review_period: u64,
+    ) -> Pubkey {
+        let bounty_id = self.next_bounty_id(&client.pubkey());
+        self.send(
+            ix::create_bounty(
+                client.pubkey(),
+                bounty_id,
+                "Fix login".to_string(),
+                "The login form rejects valid emails".to_string(),
+                reward,
+                deadline,
+                vec!["rust".to_string()],
+                payout_policy,
+                review_period,
+            ),
+            &[client],
+        )
+        .unwrap();
+        pda::bounty(&client.pubkey(), bounty_id).0
+    }
+
+    pub fn next_bounty_id(&self, creator: &Pubkey) -> u64 {
+        self.account::<solearn::states::Client>(&pda::client(creator).0)
+            .next_bounty_id
+    }
+
+    pub fn next_project_id(&self, creator: &Pubkey) -> u64 {
+        self.account::<solearn::states::Client>(&pda::client(creator).0)
+            .next_project_id
+    }
+
+    pub fn submit(&mut self, user: &Keypair, bounty: Pubkey) -> TxResult {
+        self.send(
+            ix::create_submission(
+                user.pubkey(),
+                bounty,
+                "Normalized the email before validating".to_string(),
+                "https://github.com/acme/app/pull/1".to_string(),
+            ),
+            &[user],
+        )
+    }
+
+    pub fn now(&self) -> u64 {
+        self.svm.get_sysvar::<Clock>().unix_timestamp as u64
+    }
+
+    pub fn warp_to(&mut self, unix_timestamp: u64) {
+        let mut clock = self.svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = unix_timestamp as i64;
+        self.svm.set_sysvar(&clock);
+    }
+
+    pub fn warp_by(&mut self, seconds: u64) {
+        let now = self.now();
+        self.warp_to(now + seconds);
+    }
+
+    pub fn lamports(&self, address: &Pubkey) -> u64 {
+        self.svm
+            .get_account(address)
+            .map_or(0, |account| account.lamports)
+    }
+
+    pub fn exists(&self, address: &Pubkey) -> bool {
+        self.svm
+            .get_account(address)
+            .is_some_and(|account| account.lamports > 0)
+    }
+
+    /// Decodes a program account, panicking if it is missing or malformed.
+    pub fn account<T: AccountDeserialize>(&self, address: &Pubkey) -> T {
+        let account = self
+            .svm
+            .get_account(address)
+            .unwrap_or_else(|| panic!("account {address} not found"));
+        T::try_deserialize(&mut &account.data[..])
+            .unwrap_or_else(|err| panic!("failed to decode {address}: {err}"))
+    }
+
+    pub fn rent(&self, data_len: usize) -> u64 {
+        self.svm.minimum_balance_for_rent_exemption(data_len)
+    }
+
+    /// Rewrites the state stored at `address`, keeping the account's size and lamports.
+    pub fn overwrite<T: AccountSerialize>(&mut self, address: Pubkey, state: &T) {
+        let mut serialized = Vec::new();
+        state.try_serialize(&mut serialized).unwrap();
+        let current = self.svm.get_account(&address).unwrap();
+        let mut data = current.data.clone();
+        data[..serialized.len()].copy_from_slice(&serialized);
+        self.svm
+            .set_account(address, Account { data, ..current })
+            .unwrap();
+    }
+
+    /// Stores `fields` at `address` as a `T` in its legacy layout: behind `T`'s
+    /// discriminator, in an account sized as the program allocated it before
+    /// layouts were versioned.
+    pub fn set_legacy_account<T: Versioned>(&mut self, address: Pubkey, fields: Vec<u8>) {
+        let len = 8 + T::Legacy::INIT_SPACE;
+        let mut data = T::DISCRIMINATOR.to_vec();
+        data.extend(fields);
+        assert!(data.len() <= len, "fields overflow the legacy layout");
+        data.resize(len, 0);
+        self.svm
+            .set_account(
+                address,
+                Account {
+                    lamports: self.rent(len),
+                    data,
+                    owner: PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    /// Funded keypair with a contributor profile from before layouts were versioned.
+    pub fn legacy_user(&mut self) -> Keypair {
+        let user = self.keypair(10);
+        let (address, bump) = pda::user(&user.pubkey());
+        let fields = borsh_fields![
+            user.pubkey(),
+            "Legacy Dev".to_string(),
+            "legacy@dev.io".to_string(),
+            "LD".to_string(),
+            "Hi I'm a new user".to_string(),
+            vec!["rust".to_string(), "anchor".to_string()],
+            self.now(),
+            3 * LAMPORTS_PER_SOL,
+            4u64,
+            2u64,
+            bump,
+        ];
+        self.set_legacy_account::<User>(address, fields);
+        user
+    }
+
+    /// Funded keypair with a client profile from before layouts were versioned.
+    pub fn legacy_client(&mut self) -> Keypair {
+        let client = self.keypair(100);
+        let (address, bump) = pda::client(&client.pubkey());
+        let fields = borsh_fields![
+            client.pubkey(),
+            "Legacy Labs".to_string(),
+            "ops@legacy.dev".to_string(),
+            "LL".to_string(),
+            "https://legacy.dev".to_string(),
+            "Hi I'm a new client".to_string(),
+            self.now(),
+            5 * LAMPORTS_PER_SOL,
+            3u64,
+            bump,
+        ];
+        self.set_legacy_account::<Client>(address, fields);
+        client
+    }
+
+    /// Live SOL bounty of `reward` SOL from before layouts were versioned, addressed
+    /// by `title` and with its escrow funded. The client's profile is left as is.
+    pub fn legacy_bounty(&mut self, client: &Keypair, title: &str, reward: u64) -> Pubkey {
+        let (address, bump) = pda::legacy_bounty(&client.pubkey(), title);
+        let escrow = pda::escrow(&address).0;
+        let deadline = self.now() + DAY;
+        let fields = borsh_fields![
+            client.pubkey(),
+            pda::client(&client.pubkey()).0,
+            title.to_string(),
+            "Normalize emails".to_string(),
+            reward,
+            true,
+            self.now(),
+            deadline,
+            vec!["rust".to_string()],
+            0u64,
+            Pubkey::default(),
+            Pubkey::default(),
+            escrow,
+            false,
+            bump,
+        ];
+        self.set_legacy_account::<Bounty>(address, fields);
+        self.svm
+            .set_account(
+                escrow,
+                Account {
+                    lamports: reward * LAMPORTS_PER_SOL + self.rent(0),
+                    ..Account::default()
+                },
+            )
+            .unwrap();
+        address
+    }
+
+    /// Creates an SPL token mint with `decimals`, minted by the fee payer.
+    pub fn mint(&mut self, decimals: u8) -> Pubkey {
+        let mint = Keypair::new();
+        let payer = self.payer.pubkey();
+        let create = anchor_lang::solana_program::system_instruction::create_account(
+            &payer,
+            &mint.pubkey(),
+            self.rent(MINT_LEN),
+            MINT_LEN as u64,
+            &spl_token::ID,
+        );
+        let initialize = spl_token::instruction::initialize_mint2(
+            &spl_token::ID,
+            &mint.pubkey(),
+            &payer,
+            None,
+            decimals,
+        )
+        .unwrap();
+        self.send_all(&[create, initialize], &[&mint]).unwrap();
+        mint.pubkey()
+    }
+
+    /// Creates a token account of `mint` owned by `owner`, holding `amount` base units.
+    pub fn token_account(&mut self, mint: &Pubkey, owner: &Pubkey, amount: u64) -> Pubkey {
+        let account = Keypair::new();
+        let payer = self.payer.pubkey();
+        let create = anchor_lang::solana_program::system_instruction::create_account(
+            &payer,
+            &account.pubkey(),
+            self.rent(TOKEN_ACCOUNT_LEN),
+            TOKEN_ACCOUNT_LEN as u64,
+            &spl_token::ID,
+        );
+        let initialize = spl_token::instruction::initialize_account3(
+            &spl_token::ID,
+            &account.pubkey(),
+            mint,
+            owner,
+        )
+        .unwrap();
+        let mut instructions = vec![create, initialize];
+        if amount > 0 {
+            instructions.push(
+                spl_token::instruction::mint_to(
+                    &spl_token::ID,
+                    mint,
+                    &account.pubkey(),
+                    &payer,
+                    &[],
+                    amount,
+                )
+                .unwrap(),
+            );
+        }
+        self.send_all(&instructions, &[&account]).unwrap();
+        account.pubkey()
+    }
+
+    /// Token amount held by an SPL token account.
+    pub fn token_balance(&self, address: &Pubkey) -> u64 {
+        let account = self
+            .svm
+            .get_account(address)
+            .unwrap_or_else(|| panic!("token account {address} not found"));
+        u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+    }
+}
+
+impl Default for TestEnv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AccountReader for TestEnv {
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ReaderError> {
+        Ok(self.svm.get_account(address).map(|account| account.data))
+    }
+}
+
+/// Asserts the transaction failed in its first instruction with `expected`'s custom code.
+#[track_caller]
+pub fn assert_error(result: TxResult, expected: impl Into<u32>) {
+    let code = expected.into();
+    assert_eq!(
+        result,
+        Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(code)
+        )),
+        "expected custom error {code}",
+    );
+}
@@ -0,0 +1,231 @@
+use anchor_lang::prelude::Pubkey;
+use solana_signer::Signer;
+use solearn::errors::{AttestationError, BountyError, SubmissionError};
+use solearn::states::{Attestation, AttestationSource, SkillGate};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY};
+
+#[test]
+fn platform_and_approved_clients_issue_attestations() {
+    let mut env = TestEnv::initialized();
+    let platform = env.upgrade_authority.insecure_clone();
+    let client = env.client();
+    let user = env.user();
+    let now = env.now();
+
+    env.send(
+        ix::issue_attestation(
+            platform.pubkey(),
+            user.pubkey(),
+            " Anchor".to_string(),
+            now + DAY,
+        ),
+        &[&platform],
+    )
+    .unwrap();
+    let attestation: Attestation =
+        env.account(&pda::attestation(&user.pubkey(), "anchor", &platform.pubkey()).0);
+    assert!(attestation.source == AttestationSource::Platform);
+    assert_eq!(attestation.skill, "anchor");
+    assert_eq!(attestation.expires_at, now + DAY);
+
+    let result = env.send(
+        ix::issue_attestation(platform.pubkey(), user.pubkey(), "rust".to_string(), now),
+        &[&platform],
+    );
+    assert_error(result, AttestationError::InvalidExpiry);
+
+    // Clients need the platform's approval first
+    let issue = ix::issue_attestation(client.pubkey(), user.pubkey(), "rust".to_string(), 0);
+    assert_error(
+        env.send(issue.clone(), &[&client]),
+        AttestationError::NotAnIssuer,
+    );
+    env.send(
+        ix::approve_issuer(platform.pubkey(), client.pubkey()),
+        &[&platform],
+    )
+    .unwrap();
+    env.send(issue, &[&client]).unwrap();
+    let attestation: Attestation =
+        env.account(&pda::attestation(&user.pubkey(), "rust", &client.pubkey()).0);
+    assert!(attestation.source == AttestationSource::Client);
+
+    env.send(
+        ix::remove_issuer(platform.pubkey(), client.pubkey()),
+        &[&platform],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::issue_attestation(client.pubkey(), user.pubkey(), "go".to_string(), 0),
+        &[&client],
+    );
+    assert_error(result, AttestationError::NotAnIssuer);
+}
+
+#[test]
+fn issuers_and_platform_revoke_attestations() {
+    let mut env = TestEnv::initialized();
+    let platform = env.upgrade_authority.insecure_clone();
+    let client = env.client();
+    let outsider = env.client();
+    let user = env.user();
+    env.send(
+        ix::approve_issuer(platform.pubkey(), client.pubkey()),
+        &[&platform],
+    )
+    .unwrap();
+    for skill in ["rust", "anchor"] {
+        env.send(
+            ix::issue_attestation(client.pubkey(), user.pubkey(), skill.to_string(), 0),
+            &[&client],
+        )
+        .unwrap();
+    }
+
+    let result = env.send(
+        ix::revoke_attestation(outsider.pubkey(), user.pubkey(), "rust", client.pubkey()),
+        &[&outsider],
+    );
+    assert_error(result, AttestationError::NotAuthorizedToRevoke);
+
+    let revoke = ix::revoke_attestation(client.pubkey(), user.pubkey(), "RUST", client.pubkey());
+    env.send(revoke.clone(), &[&client]).unwrap();
+    let attestation: Attestation =
+        env.account(&pda::attestation(&user.pubkey(), "rust", &client.pubkey()).0);
+    assert_eq!(attestation.revoked_at, env.now());
+    assert!(!attestation.is_active(env.now()));
+    assert_error(
+        env.send(revoke, &[&client]),
+        AttestationError::AttestationAlreadyRevoked,
+    );
+
+    env.send(
+        ix::revoke_attestation(platform.pubkey(), user.pubkey(), "anchor", client.pubkey()),
+        &[&platform],
+    )
+    .unwrap();
+}
+
+#[test]
+fn bounty_winners_attest_required_skills() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let runner_up = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&winner, bounty).unwrap();
+    env.submit(&runner_up, bounty).unwrap();
+
+    let result = env.send(
+        ix::attest_from_bounty(winner.pubkey(), bounty, "rust".to_string()),
+        &[&winner],
+    );
+    assert_error(result, AttestationError::NotBountyWinner);
+
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, winner.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let result = env.send(
+        ix::attest_from_bounty(runner_up.pubkey(), bounty, "rust".to_string()),
+        &[&runner_up],
+    );
+    assert_error(result, AttestationError::NotBountyWinner);
+    let result = env.send(
+        ix::attest_from_bounty(winner.pubkey(), bounty, "go".to_string()),
+        &[&winner],
+    );
+    assert_error(result, AttestationError::SkillNotRequiredByBounty);
+
+    env.send(
+        ix::attest_from_bounty(winner.pubkey(), bounty, "Rust ".to_string()),
+        &[&winner],
+    )
+    .unwrap();
+    let attestation: Attestation =
+        env.account(&pda::attestation(&winner.pubkey(), "rust", &bounty).0);
+    assert!(attestation.source == AttestationSource::Bounty);
+    assert_eq!(attestation.issuer, bounty);
+    assert_eq!(attestation.expires_at, 0);
+}
+
+#[test]
+fn attested_gate_only_counts_active_attestations() {
+    let mut env = TestEnv::initialized();
+    let platform = env.upgrade_authority.insecure_clone();
+    let client = env.client();
+    let bounty = env.bounty(&client, 1);
+
+    let result = env.send(
+        ix::set_bounty_skill_gate(client.pubkey(), bounty, SkillGate::Open, true),
+        &[&client],
+    );
+    assert_error(result, BountyError::InvalidSkillGate);
+    env.send(
+        ix::set_bounty_skill_gate(client.pubkey(), bounty, SkillGate::All, true),
+        &[&client],
+    )
+    .unwrap();
+
+    let [alice, bob, carol, dave] = [env.user(), env.user(), env.user(), env.user()];
+    let expires_at = env.now() + DAY;
+    for user in [&alice, &bob, &dave] {
+        env.send(
+            ix::issue_attestation(
+                platform.pubkey(),
+                user.pubkey(),
+                "rust".to_string(),
+                expires_at,
+            ),
+            &[&platform],
+        )
+        .unwrap();
+    }
+    env.send(
+        ix::revoke_attestation(platform.pubkey(), bob.pubkey(), "rust", platform.pubkey()),
+        &[&platform],
+    )
+    .unwrap();
+    let attested_submission = |user: &solana_keypair::Keypair, attestations: &[Pubkey]| {
+        ix::create_attested_submission(
+            user.pubkey(),
+            bounty,
+            "Normalized the email before validating".to_string(),
+            "https://github.com/acme/app/pull/1".to_string(),
+            attestations,
+        )
+    };
+
+    // Listing the skill on a profile is not enough
+    assert_error(
+        env.submit(&alice, bounty),
+        SubmissionError::InsufficientSkills,
+    );
+
+    let bobs = pda::attestation(&bob.pubkey(), "rust", &platform.pubkey()).0;
+    assert_error(
+        env.send(attested_submission(&bob, &[bobs]), &[&bob]),
+        SubmissionError::InsufficientSkills,
+    );
+
+    // Attestations of someone else are rejected outright
+    let alices = pda::attestation(&alice.pubkey(), "rust", &platform.pubkey()).0;
+    assert_error(
+        env.send(attested_submission(&carol, &[alices]), &[&carol]),
+        AttestationError::InvalidAttestation,
+    );
+
+    env.send(attested_submission(&alice, &[alices]), &[&alice])
+        .unwrap();
+
+    env.warp_to(expires_at);
+    let daves = pda::attestation(&dave.pubkey(), "rust", &platform.pubkey()).0;
+    assert_error(
+        env.send(attested_submission(&dave, &[daves]), &[&dave]),
+        SubmissionError::InsufficientSkills,
+    );
+}
@@ -0,0 +1,570 @@
+use anchor_lang::Space;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, ClientError, ConfigError, SubmissionError};
+use solearn::states::{
+    Bounty, BountyCloseReason, Client, PayoutPolicy, SkillGate, Submission, User, Versioned,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY, LAMPORTS_PER_SOL, MAX_DEADLINE_DURATION};
+
+#[test]
+fn create_bounty_funds_escrow() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let before = env.lamports(&client.pubkey());
+
+    let bounty = env.bounty(&client, 2);
+    let escrow = pda::escrow(&bounty).0;
+    let escrow_rent = env.rent(0);
+    assert_eq!(env.lamports(&escrow), 2 * LAMPORTS_PER_SOL + escrow_rent);
+
+    let bounty_rent = env.rent(8 + Bounty::INIT_SPACE);
+    assert_eq!(
+        before - env.lamports(&client.pubkey()),
+        2 * LAMPORTS_PER_SOL + escrow_rent + bounty_rent
+    );
+
+    let state: Bounty = env.account(&bounty);
+    assert!(state.live);
+    assert_eq!(state.bounty_id, 0);
+    assert_eq!(state.escrow_account, escrow);
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(profile.next_bounty_id, 1);
+    assert_eq!(profile.bounties_posted, 1);
+}
+
+#[test]
+fn create_bounty_enforces_policy() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let now = env.now();
+    let post = |title: &str, reward: u64, deadline: u64| {
+        ix::create_bounty(
+            client.pubkey(),
+            0,
+            title.to_string(),
+            "desc".to_string(),
+            reward,
+            deadline,
+            vec![],
+            PayoutPolicy::ClientSelects,
+            0,
+        )
+    };
+
+    assert_error(
+        env.send(post("", 1, now + DAY), &[&client]),
+        BountyError::InvalidTitle,
+    );
+    assert_error(
+        env.send(post("Fix login", 0, now + DAY), &[&client]),
+        BountyError::InvalidRewardAmount,
+    );
+    assert_error(
+        env.send(post("Fix login", 1, now), &[&client]),
+        BountyError::InvalidDeadline,
+    );
+    assert_error(
+        env.send(post("Fix login", 1, now + 31 * DAY), &[&client]),
+        ConfigError::DeadlineBeyondMaximum,
+    );
+    assert_error(
+        env.send(post("Fix login", 1_000, now + DAY), &[&client]),
+        BountyError::InsufficientSolBalance,
+    );
+}
+
+#[test]
+fn select_submission_pays_winner_and_treasury() {
+    let mut env = TestEnv::with_fee(250);
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 4);
+    env.submit(&user, bounty).unwrap();
+
+    let escrow = pda::escrow(&bounty).0;
+    let user_before = env.lamports(&user.pubkey());
+    let treasury_before = env.lamports(&env.treasury);
+
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), env.treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let fee = 4 * LAMPORTS_PER_SOL * 250 / 10_000;
+    assert_eq!(env.lamports(&env.treasury) - treasury_before, fee);
+    assert_eq!(
+        env.lamports(&user.pubkey()) - user_before,
+        4 * LAMPORTS_PER_SOL - fee
+    );
+    // Only the escrow's rent-exempt minimum stays behind
+    assert_eq!(env.lamports(&escrow), env.rent(0));
+
+    let state: Bounty = env.account(&bounty);
+    assert!(!state.live);
+    assert!(state.bounty_rewarded);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+    assert_eq!(state.selected_user_wallet_key, user.pubkey());
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(profile.earned, 4 * LAMPORTS_PER_SOL - fee);
+    assert_eq!(profile.bounties_completed, 1);
+
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), env.treasury),
+        &[&client],
+    );
+    assert_error(result, BountyError::BountyAlreadyClosed);
+}
+
+#[test]
+fn select_submission_rejects_overflowing_payouts() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+
+    // A reward too large to express in lamports
+    let mut state: Bounty = env.account(&bounty);
+    state.reward = u64::MAX / 1_000_000_000 + 1;
+    env.overwrite(bounty, &state);
+    let treasury = env.treasury;
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), treasury),
+        &[&client],
+    );
+    assert_error(result, BountyError::PayoutOverflow);
+
+    // Earnings that would wrap the winner's tally
+    state.reward = 1;
+    env.overwrite(bounty, &state);
+    let address = pda::user(&user.pubkey()).0;
+    let mut profile: User = env.account(&address);
+    profile.earned = u64::MAX;
+    env.overwrite(address, &profile);
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), treasury),
+        &[&client],
+    );
+    assert_error(result, BountyError::PayoutOverflow);
+    assert!(env.account::<Bounty>(&bounty).live);
+}
+
+#[test]
+fn select_submission_requires_configured_treasury() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), client.pubkey()),
+        &[&client],
+    );
+    assert_error(result, ConfigError::InvalidTreasury);
+}
+
+#[test]
+fn submissions_close_at_the_deadline() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let early = env.user();
+    let late = env.user();
+    let bounty = env.bounty(&client, 1);
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+
+    env.warp_to(deadline);
+    env.submit(&early, bounty).unwrap();
+    let submission: Submission = env.account(&pda::submission(&early.pubkey(), &bounty).0);
+    assert_eq!(submission.submitted_at, deadline);
+
+    env.warp_to(deadline + 1);
+    assert_error(env.submit(&late, bounty), BountyError::BountyDeadlinePassed);
+}
+
+#[test]
+fn skill_gate_restricts_submissions() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + 7 * DAY;
+    env.send(
+        ix::create_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Port to Anchor".to_string(),
+            "Rewrite the program in Anchor".to_string(),
+            1,
+            deadline,
+            vec![
+                "Rust".to_string(),
+                "Anchor".to_string(),
+                "TypeScript".to_string(),
+            ],
+            PayoutPolicy::ClientSelects,
+            0,
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let bounty = pda::bounty(&client.pubkey(), bounty_id).0;
+    assert!(env.account::<Bounty>(&bounty).skill_gate == SkillGate::Open);
+
+    for gate in [SkillGate::AtLeast(0), SkillGate::AtLeast(4)] {
+        let result = env.send(
+            ix::set_bounty_skill_gate(client.pubkey(), bounty, gate, false),
+            &[&client],
+        );
+        assert_error(result, BountyError::InvalidSkillGate);
+    }
+    env.send(
+        ix::set_bounty_skill_gate(client.pubkey(), bounty, SkillGate::AtLeast(2), false),
+        &[&client],
+    )
+    .unwrap();
+
+    // `env.user()` only lists "rust"
+    let novice = env.user();
+    assert_error(
+        env.submit(&novice, bounty),
+        SubmissionError::InsufficientSkills,
+    );
+
+    let expert = env.keypair(10);
+    env.send(
+        ix::create_user(
+            expert.pubkey(),
+            "expert".to_string(),
+            "expert@example.com".to_string(),
+            vec![" RUST".to_string(), "anchor ".to_string()],
+        ),
+        &[&expert],
+    )
+    .unwrap();
+    env.submit(&expert, bounty).unwrap();
+
+    // The gate is fixed once someone has submitted
+    let result = env.send(
+        ix::set_bounty_skill_gate(client.pubkey(), bounty, SkillGate::Open, false),
+        &[&client],
+    );
+    assert_error(result, BountyError::CannotUpdateWithSubmissions);
+}
+
+#[test]
+fn skill_gate_needs_required_skills() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+
+    env.send(
+        ix::set_bounty_skill_gate(client.pubkey(), bounty, SkillGate::All, false),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&user, bounty).unwrap();
+
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + 7 * DAY;
+    env.send(
+        ix::create_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Anything goes".to_string(),
+            String::new(),
+            1,
+            deadline,
+            vec![],
+            PayoutPolicy::ClientSelects,
+            0,
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let bare = pda::bounty(&client.pubkey(), bounty_id).0;
+    let result = env.send(
+        ix::set_bounty_skill_gate(client.pubkey(), bare, SkillGate::Any, false),
+        &[&client],
+    );
+    assert_error(result, BountyError::InvalidSkillGate);
+}
+
+#[test]
+fn update_and_delete_only_without_submissions() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    let deadline = env.now() + 2 * DAY;
+
+    let result = env.send(
+        ix::update_bounty(
+            client.pubkey(),
+            bounty,
+            String::new(),
+            "desc".to_string(),
+            deadline,
+        ),
+        &[&client],
+    );
+    assert_error(result, BountyError::InvalidTitle);
+
+    // A new deadline follows the same policy as one set at creation
+    let now = env.now();
+    for (bad, error) in [
+        (now, u32::from(BountyError::InvalidDeadline)),
+        (
+            now + MAX_DEADLINE_DURATION + 1,
+            u32::from(ConfigError::DeadlineBeyondMaximum),
+        ),
+    ] {
+        let result = env.send(
+            ix::update_bounty(
+                client.pubkey(),
+                bounty,
+                "Fix signup".to_string(),
+                "desc".to_string(),
+                bad,
+            ),
+            &[&client],
+        );
+        assert_error(result, error);
+    }
+
+    env.send(
+        ix::update_bounty(
+            client.pubkey(),
+            bounty,
+            "Fix signup".to_string(),
+            "New scope".to_string(),
+            deadline,
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let state: Bounty = env.account(&bounty);
+    assert_eq!(state.title, "Fix signup");
+    assert_eq!(state.deadline, deadline);
+
+    env.submit(&user, bounty).unwrap();
+    let result = env.send(
+        ix::update_bounty(
+            client.pubkey(),
+            bounty,
+            "Again".to_string(),
+            "desc".to_string(),
+            deadline,
+        ),
+        &[&client],
+    );
+    assert_error(result, BountyError::CannotUpdateWithSubmissions);
+    assert_error(
+        env.send(ix::delete_bounty(client.pubkey(), bounty), &[&client]),
+        BountyError::CannotDeleteWithSubmissions,
+    );
+}
+
+#[test]
+fn delete_bounty_refunds_escrow() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let before = env.lamports(&client.pubkey());
+    let bounty = env.bounty(&client, 3);
+    let escrow = pda::escrow(&bounty).0;
+
+    env.send(ix::delete_bounty(client.pubkey(), bounty), &[&client])
+        .unwrap();
+    assert!(!env.exists(&bounty));
+    assert!(!env.exists(&escrow));
+    assert_eq!(env.lamports(&client.pubkey()), before);
+}
+
+#[test]
+fn expire_bounty_after_review_window() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let cranker = env.keypair(1);
+    let bounty = env.bounty(&client, 2);
+    let escrow = pda::escrow(&bounty).0;
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+    let expire = ix::expire_bounty(cranker.pubkey(), bounty, client.pubkey());
+
+    env.warp_to(deadline + 7 * DAY);
+    assert_error(
+        env.send(expire.clone(), &[&cranker]),
+        BountyError::ReviewWindowNotElapsed,
+    );
+
+    env.warp_to(deadline + 7 * DAY + 1);
+    let escrowed = env.lamports(&escrow);
+    let before = env.lamports(&client.pubkey());
+    env.send(expire, &[&cranker]).unwrap();
+
+    assert_eq!(env.lamports(&client.pubkey()) - before, escrowed);
+    assert_eq!(env.lamports(&escrow), 0);
+    let state: Bounty = env.account(&bounty);
+    assert!(!state.live);
+    assert!(state.close_reason == BountyCloseReason::Expired);
+}
+
+#[test]
+fn guaranteed_bounty_cannot_expire_with_submissions() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let cranker = env.keypair(1);
+    let deadline = env.now() + DAY;
+    let bounty = env.bounty_with_policy(
+        &client,
+        1,
+        deadline,
+        PayoutPolicy::GuaranteedToEarliest,
+        DAY,
+    );
+    env.submit(&user, bounty).unwrap();
+
+    env.warp_to(deadline + 8 * DAY);
+    let result = env.send(
+        ix::expire_bounty(cranker.pubkey(), bounty, client.pubkey()),
+        &[&cranker],
+    );
+    assert_error(result, BountyError::GuaranteedPayoutPending);
+}
+
+#[test]
+fn auto_release_pays_earliest_submission() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let first = env.user();
+    let second = env.user();
+    let cranker = env.keypair(1);
+    let deadline = env.now() + DAY;
+    let bounty = env.bounty_with_policy(
+        &client,
+        2,
+        deadline,
+        PayoutPolicy::GuaranteedToEarliest,
+        3 * DAY,
+    );
+    env.submit(&first, bounty).unwrap();
+    env.warp_by(60);
+    env.submit(&second, bounty).unwrap();
+
+    let release = |wallet| {
+        ix::auto_release_bounty(
+            cranker.pubkey(),
+            bounty,
+            client.pubkey(),
+            wallet,
+            env.treasury,
+        )
+    };
+    let release_first = release(first.pubkey());
+    let release_second = release(second.pubkey());
+
+    env.warp_to(deadline + 3 * DAY);
+    assert_error(
+        env.send(release_first.clone(), &[&cranker]),
+        BountyError::ReviewWindowNotElapsed,
+    );
+
+    env.warp_to(deadline + 3 * DAY + 1);
+    assert_error(
+        env.send(release_second, &[&cranker]),
+        SubmissionError::NotEarliestSubmission,
+    );
+
+    let before = env.lamports(&first.pubkey());
+    env.send(release_first, &[&cranker]).unwrap();
+    assert_eq!(env.lamports(&first.pubkey()) - before, 2 * LAMPORTS_PER_SOL);
+    assert_eq!(env.lamports(&pda::escrow(&bounty).0), env.rent(0));
+}
+
+#[test]
+fn auto_release_requires_guaranteed_policy() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let cranker = env.keypair(1);
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+
+    env.warp_by(30 * DAY);
+    let result = env.send(
+        ix::auto_release_bounty(
+            cranker.pubkey(),
+            bounty,
+            client.pubkey(),
+            user.pubkey(),
+            env.treasury,
+        ),
+        &[&cranker],
+    );
+    assert_error(result, BountyError::NotGuaranteedPayout);
+}
+
+#[test]
+fn migrate_bounty_assigns_an_id() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    env.bounty(&client, 1);
+    let legacy = env.legacy_bounty(&client, "Fix login", 1);
+
+    env.send(
+        ix::migrate_bounty(client.pubkey(), "Fix login".to_string()),
+        &[&client],
+    )
+    .unwrap();
+    let migrated: Bounty = env.account(&legacy);
+    assert_eq!(migrated.bounty_id, 1);
+    assert_eq!(migrated.legacy_title_seed, "Fix login");
+    assert_eq!(migrated.address_seed(), b"Fix login".to_vec());
+    assert_eq!(migrated.version, Bounty::LAYOUT_VERSION);
+    assert_eq!(migrated.title, "Fix login");
+    assert_eq!(migrated.reward, 1);
+    assert_eq!(migrated.required_skills, vec!["rust"]);
+    assert!(migrated.live && migrated.close_reason == BountyCloseReason::None);
+    assert!(migrated.payout_policy == PayoutPolicy::ClientSelects);
+    assert_eq!(env.next_bounty_id(&client.pubkey()), 2);
+
+    let result = env.send(
+        ix::migrate_bounty(client.pubkey(), "Fix login".to_string()),
+        &[&client],
+    );
+    assert_error(result, BountyError::BountyAlreadyMigrated);
+
+    // The escrow is still the one the bounty was funded through
+    env.submit(&user, legacy).unwrap();
+    let before = env.lamports(&user.pubkey());
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), legacy, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(env.lamports(&user.pubkey()) - before, LAMPORTS_PER_SOL);
+}
+
+#[test]
+fn migrate_bounty_rejects_other_clients_bounties() {
+    let mut env = TestEnv::initialized();
+    let owner = env.client();
+    let other = env.client();
+
+    // A legacy bounty at `other`'s address whose state points at `owner`'s profile
+    let legacy = env.legacy_bounty(&other, "Fix login", 1);
+    let mut account = env.svm.get_account(&legacy).unwrap();
+    let owner_profile = pda::client(&owner.pubkey()).0;
+    account.data[8 + 32..8 + 64].copy_from_slice(owner_profile.as_ref());
+    env.svm.set_account(legacy, account).unwrap();
+
+    let result = env.send(
+        ix::migrate_bounty(other.pubkey(), "Fix login".to_string()),
+        &[&other],
+    );
+    assert_error(result, ClientError::NotAuthorizedForBounty);
+}
@@ -0,0 +1,149 @@
+use solana_signer::Signer;
+use solearn::errors::ConfigError;
+use solearn::states::{PauseGroup, PlatformConfig};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY, MAX_DEADLINE_DURATION};
+
+#[test]
+fn initialize_config_records_policy() {
+    let env = TestEnv::with_fee(250);
+    let config: PlatformConfig = env.account(&pda::config().0);
+    assert_eq!(config.authority, env.upgrade_authority.pubkey());
+    assert_eq!(config.treasury, env.treasury);
+    assert_eq!(config.fee_bps, 250);
+    assert_eq!(config.max_deadline_duration, MAX_DEADLINE_DURATION);
+    assert_eq!(config.guardian, env.upgrade_authority.pubkey());
+    assert!(!config.paused);
+}
+
+#[test]
+fn initialize_config_requires_upgrade_authority() {
+    let mut env = TestEnv::new();
+    let stranger = env.keypair(1);
+    let result = env.send(
+        ix::initialize_config(stranger.pubkey(), 0, env.treasury, 0, DAY),
+        &[&stranger],
+    );
+    assert_error(result, ConfigError::NotUpgradeAuthority);
+}
+
+#[test]
+fn initialize_config_rejects_bad_policy() {
+    let mut env = TestEnv::new();
+    let authority = env.upgrade_authority.insecure_clone();
+    let result = env.send(
+        ix::initialize_config(authority.pubkey(), 1_001, env.treasury, 0, DAY),
+        &[&authority],
+    );
+    assert_error(result, ConfigError::PlatformFeeTooHigh);
+
+    let result = env.send(
+        ix::initialize_config(authority.pubkey(), 0, Default::default(), 0, DAY),
+        &[&authority],
+    );
+    assert_error(result, ConfigError::InvalidTreasury);
+}
+
+#[test]
+fn update_config_is_restricted_to_platform_authority() {
+    let mut env = TestEnv::initialized();
+    let authority = env.upgrade_authority.insecure_clone();
+    let stranger = env.keypair(1);
+    let treasury = env.treasury;
+
+    let result = env.send(
+        ix::update_config(stranger.pubkey(), stranger.pubkey(), 0, treasury, 0, DAY),
+        &[&stranger],
+    );
+    assert_error(result, ConfigError::NotPlatformAuthority);
+
+    let result = env.send(
+        ix::update_config(authority.pubkey(), Default::default(), 0, treasury, 0, DAY),
+        &[&authority],
+    );
+    assert_error(result, ConfigError::InvalidAuthority);
+
+    env.send(
+        ix::update_config(
+            authority.pubkey(),
+            authority.pubkey(),
+            500,
+            treasury,
+            2,
+            DAY,
+        ),
+        &[&authority],
+    )
+    .unwrap();
+    let config: PlatformConfig = env.account(&pda::config().0);
+    assert_eq!(config.fee_bps, 500);
+    assert_eq!(config.min_reward, 2);
+    assert_eq!(config.max_deadline_duration, DAY);
+}
+
+#[test]
+fn guardian_pauses_and_unpauses_groups() {
+    let mut env = TestEnv::initialized();
+    let authority = env.upgrade_authority.insecure_clone();
+    let guardian = env.keypair(1);
+    let client = env.client();
+
+    let result = env.send(
+        ix::set_guardian(guardian.pubkey(), guardian.pubkey()),
+        &[&guardian],
+    );
+    assert_error(result, ConfigError::NotPlatformAuthority);
+    env.send(
+        ix::set_guardian(authority.pubkey(), guardian.pubkey()),
+        &[&authority],
+    )
+    .unwrap();
+
+    let result = env.send(ix::pause(authority.pubkey(), None), &[&authority]);
+    assert_error(result, ConfigError::NotGuardian);
+
+    env.send(
+        ix::pause(guardian.pubkey(), Some(PauseGroup::Deposits)),
+        &[&guardian],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::pause(guardian.pubkey(), Some(PauseGroup::Deposits)),
+        &[&guardian],
+    );
+    assert_error(result, ConfigError::PauseStateUnchanged);
+
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + DAY;
+    let post = ix::create_bounty(
+        client.pubkey(),
+        bounty_id,
+        "Fix login".to_string(),
+        "desc".to_string(),
+        1,
+        deadline,
+        vec![],
+        solearn::states::PayoutPolicy::ClientSelects,
+        0,
+    );
+    assert_error(
+        env.send(post.clone(), &[&client]),
+        ConfigError::InstructionGroupPaused,
+    );
+
+    env.send(
+        ix::unpause(guardian.pubkey(), Some(PauseGroup::Deposits)),
+        &[&guardian],
+    )
+    .unwrap();
+    env.send(ix::pause(guardian.pubkey(), None), &[&guardian])
+        .unwrap();
+    assert_error(
+        env.send(post.clone(), &[&client]),
+        ConfigError::ProgramPaused,
+    );
+
+    env.send(ix::unpause(guardian.pubkey(), None), &[&guardian])
+        .unwrap();
+    env.send(post, &[&client]).unwrap();
+}
@@ -0,0 +1,521 @@
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, DisputeError};
+use solearn::states::{
+    Bounty, BountyCloseReason, Dispute, DisputeRuling, DisputeStatus, PayoutPolicy, Submission,
+    SubmissionStatus, DISPUTE_RULING_SECONDS, SELECTION_DISPUTE_SECONDS,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY, LAMPORTS_PER_SOL};
+
+fn evidence(n: u8) -> String {
+    format!("https://evidence.example/{n}")
+}
+
+#[test]
+fn set_bounty_arbiters_validates_panel() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let arbiter = env.keypair(1).pubkey();
+    let bounty = env.bounty(&client, 1);
+
+    let set = |arbiters: Vec<_>, fee_bps| {
+        ix::set_bounty_arbiters(client.pubkey(), bounty, arbiters, fee_bps)
+    };
+    assert_error(
+        env.send(set(vec![arbiter], 1_001), &[&client]),
+        DisputeError::ArbiterFeeTooHigh,
+    );
+    assert_error(
+        env.send(set(vec![client.pubkey()], 0), &[&client]),
+        DisputeError::InvalidArbiters,
+    );
+    assert_error(
+        env.send(set(vec![arbiter, arbiter], 0), &[&client]),
+        DisputeError::InvalidArbiters,
+    );
+    let four = (0..4)
+        .map(|_| anchor_lang::prelude::Pubkey::new_unique())
+        .collect();
+    assert_error(
+        env.send(set(four, 0), &[&client]),
+        DisputeError::InvalidArbiters,
+    );
+
+    env.send(set(vec![arbiter], 100), &[&client]).unwrap();
+    let state: Bounty = env.account(&bounty);
+    assert_eq!(state.arbiters, vec![arbiter]);
+    assert_eq!(state.arbiter_fee_bps, 100);
+
+    env.submit(&user, bounty).unwrap();
+    assert_error(
+        env.send(set(vec![], 0), &[&client]),
+        DisputeError::ArbitersLocked,
+    );
+}
+
+#[test]
+fn arbiters_cannot_submit() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let arbiter = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), bounty, vec![arbiter.pubkey()], 0),
+        &[&client],
+    )
+    .unwrap();
+    assert_error(
+        env.submit(&arbiter, bounty),
+        DisputeError::ArbiterCannotSubmit,
+    );
+}
+
+#[test]
+fn open_dispute_requires_arbiters_and_a_party() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let outsider = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+
+    let result = env.send(
+        ix::open_dispute(user.pubkey(), bounty, user.pubkey(), evidence(0)),
+        &[&user],
+    );
+    assert_error(result, DisputeError::NoArbitersDesignated);
+
+    let other = env.bounty(&client, 1);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), other, vec![outsider.pubkey()], 0),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&user, other).unwrap();
+    let stranger = env.keypair(1);
+    let result = env.send(
+        ix::open_dispute(stranger.pubkey(), other, user.pubkey(), evidence(0)),
+        &[&stranger],
+    );
+    assert_error(result, DisputeError::NotDisputeParty);
+    let result = env.send(
+        ix::open_dispute(user.pubkey(), other, user.pubkey(), String::new()),
+        &[&user],
+    );
+    assert_error(result, DisputeError::InvalidEvidenceLink);
+}
+
+#[test]
+fn majority_ruling_splits_escrow() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let panel: Vec<_> = (0..3).map(|_| env.keypair(1)).collect();
+    let keys: Vec<_> = panel.iter().map(|arbiter| arbiter.pubkey()).collect();
+    let bounty = env.bounty(&client, 10);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), bounty, keys.clone(), 300),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&user, bounty).unwrap();
+
+    env.send(
+        ix::open_dispute(user.pubkey(), bounty, user.pubkey(), evidence(0)),
+        &[&user],
+    )
+    .unwrap();
+    let dispute = pda::dispute(&bounty).0;
+    assert!(env.account::<Bounty>(&bounty).disputed);
+
+    // The escrow is frozen while the dispute is open
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), env.treasury),
+        &[&client],
+    );
+    assert_error(result, DisputeError::EscrowFrozen);
+
+    env.send(
+        ix::submit_evidence(client.pubkey(), bounty, evidence(1)),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::submit_evidence(user.pubkey(), bounty, evidence(2)),
+        &[&user],
+    )
+    .unwrap();
+    env.send(
+        ix::submit_evidence(user.pubkey(), bounty, evidence(3)),
+        &[&user],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::submit_evidence(user.pubkey(), bounty, evidence(4)),
+        &[&user],
+    );
+    assert_error(result, DisputeError::EvidenceLimitReached);
+
+    let treasury = env.treasury;
+    let cast = |arbiter, ruling, majority: &[_]| {
+        ix::cast_ruling(
+            arbiter,
+            bounty,
+            client.pubkey(),
+            user.pubkey(),
+            treasury,
+            ruling,
+            majority,
+        )
+    };
+    let pay = DisputeRuling::PayContributor;
+
+    let stranger = env.keypair(1);
+    assert_error(
+        env.send(cast(stranger.pubkey(), pay, &[]), &[&stranger]),
+        DisputeError::NotArbiter,
+    );
+    let result = env.send(
+        cast(
+            keys[0],
+            DisputeRuling::Split {
+                contributor_bps: 10_001,
+            },
+            &[],
+        ),
+        &[&panel[0]],
+    );
+    assert_error(result, DisputeError::InvalidSplit);
+
+    env.send(cast(keys[0], pay, &[]), &[&panel[0]]).unwrap();
+    assert_error(
+        env.send(cast(keys[0], pay, &[]), &[&panel[0]]),
+        DisputeError::AlreadyVoted,
+    );
+
+    // The deciding vote must pass the wallets of every arbiter in the majority
+    let result = env.send(cast(keys[1], pay, &[keys[1]]), &[&panel[1]]);
+    assert_error(result, DisputeError::InvalidArbiterWallets);
+
+    let escrow = pda::escrow(&bounty).0;
+    let payable = env.lamports(&escrow) - env.rent(0);
+    let user_before = env.lamports(&user.pubkey());
+    let arbiter_before = env.lamports(&keys[0]);
+    env.send(cast(keys[1], pay, &[keys[0], keys[1]]), &[&panel[1]])
+        .unwrap();
+
+    let fee_per_arbiter = payable * 300 / 10_000 / 2;
+    assert_eq!(payable, 10 * LAMPORTS_PER_SOL);
+    assert_eq!(env.lamports(&keys[0]) - arbiter_before, fee_per_arbiter);
+    assert_eq!(
+        env.lamports(&user.pubkey()) - user_before,
+        payable - 2 * fee_per_arbiter
+    );
+    assert_eq!(env.lamports(&escrow), env.rent(0));
+
+    let state: Dispute = env.account(&dispute);
+    assert!(state.status == DisputeStatus::Resolved);
+    assert!(state.ruling == Some(pay));
+    let bounty_state: Bounty = env.account(&bounty);
+    assert!(!bounty_state.live);
+    assert!(!bounty_state.disputed);
+    assert!(bounty_state.close_reason == BountyCloseReason::Rewarded);
+    let submission: Submission = env.account(&pda::submission(&user.pubkey(), &bounty).0);
+    assert!(submission.status == SubmissionStatus::Winner);
+
+    let result = env.send(cast(keys[2], pay, &[]), &[&panel[2]]);
+    assert_error(result, DisputeError::DisputeAlreadyResolved);
+}
+
+#[test]
+fn silent_panel_lifts_the_freeze_without_a_payout() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let arbiter = env.keypair(1);
+    let cranker = env.keypair(1);
+    let bounty = env.bounty(&client, 4);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), bounty, vec![arbiter.pubkey()], 300),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&user, bounty).unwrap();
+    env.send(
+        ix::open_dispute(client.pubkey(), bounty, user.pubkey(), evidence(0)),
+        &[&client],
+    )
+    .unwrap();
+    let resolve = ix::resolve_expired_dispute(cranker.pubkey(), bounty);
+
+    let result = env.send(resolve.clone(), &[&cranker]);
+    assert_error(result, DisputeError::RulingPeriodNotElapsed);
+
+    env.warp_by(DISPUTE_RULING_SECONDS + 1);
+    let escrow = pda::escrow(&bounty).0;
+    let escrow_before = env.lamports(&escrow);
+    let user_before = env.lamports(&user.pubkey());
+    env.send(resolve.clone(), &[&cranker]).unwrap();
+
+    // Nobody ruled, so the bounty carries on as it was
+    assert_eq!(env.lamports(&escrow), escrow_before);
+    assert_eq!(env.lamports(&user.pubkey()), user_before);
+    let state: Dispute = env.account(&pda::dispute(&bounty).0);
+    assert!(state.status == DisputeStatus::Resolved);
+    assert!(state.ruling.is_none());
+    let bounty_state: Bounty = env.account(&bounty);
+    assert!(bounty_state.live);
+    assert!(!bounty_state.disputed);
+    assert!(bounty_state.close_reason == BountyCloseReason::None);
+
+    let result = env.send(resolve, &[&cranker]);
+    assert_error(result, DisputeError::DisputeAlreadyResolved);
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+}
+
+/// Paneled bounty of `reward` SOL with a one-arbiter panel and submissions from
+/// `picked` and `passed_over`, of which the client has picked `picked`.
+fn held_selection(env: &mut TestEnv, reward: u64) -> (Keypair, Keypair, Keypair, Keypair, Pubkey) {
+    let client = env.client();
+    let picked = env.user();
+    let passed_over = env.user();
+    let arbiter = env.keypair(1);
+    let bounty = env.bounty(&client, reward);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), bounty, vec![arbiter.pubkey()], 0),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&picked, bounty).unwrap();
+    env.submit(&passed_over, bounty).unwrap();
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, picked.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    (client, picked, passed_over, arbiter, bounty)
+}
+
+#[test]
+fn paneled_selection_holds_the_payout_until_the_window_closes() {
+    let mut env = TestEnv::initialized();
+    let (client, picked, passed_over, _, bounty) = held_selection(&mut env, 2);
+    let treasury = env.treasury;
+    let cranker = env.keypair(1);
+    let release = ix::release_held_payout(
+        cranker.pubkey(),
+        bounty,
+        client.pubkey(),
+        picked.pubkey(),
+        treasury,
+    );
+
+    let state: Bounty = env.account(&bounty);
+    assert!(state.payout_held && !state.live && !state.bounty_rewarded);
+    assert!(state.close_reason == BountyCloseReason::None);
+    assert_eq!(
+        env.lamports(&pda::escrow(&bounty).0),
+        2 * LAMPORTS_PER_SOL + env.rent(0)
+    );
+    let result = env.send(release.clone(), &[&cranker]);
+    assert_error(result, BountyError::DisputeWindowOpen);
+
+    // Only a contributor the client passed over can contest the pick
+    let result = env.send(
+        ix::open_dispute(client.pubkey(), bounty, passed_over.pubkey(), evidence(0)),
+        &[&client],
+    );
+    assert_error(result, DisputeError::NotPassedOver);
+    let result = env.send(
+        ix::open_dispute(picked.pubkey(), bounty, picked.pubkey(), evidence(0)),
+        &[&picked],
+    );
+    assert_error(result, DisputeError::NotPassedOver);
+
+    env.warp_by(SELECTION_DISPUTE_SECONDS + 1);
+    let result = env.send(
+        ix::open_dispute(
+            passed_over.pubkey(),
+            bounty,
+            passed_over.pubkey(),
+            evidence(0),
+        ),
+        &[&passed_over],
+    );
+    assert_error(result, DisputeError::DisputeWindowClosed);
+
+    let before = env.lamports(&picked.pubkey());
+    env.send(release.clone(), &[&cranker]).unwrap();
+    assert_eq!(
+        env.lamports(&picked.pubkey()) - before,
+        2 * LAMPORTS_PER_SOL
+    );
+    let state: Bounty = env.account(&bounty);
+    assert!(state.bounty_rewarded && !state.payout_held);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+    let submission: Submission = env.account(&pda::submission(&picked.pubkey(), &bounty).0);
+    assert!(submission.status == SubmissionStatus::Winner);
+
+    let result = env.send(release, &[&cranker]);
+    assert_error(result, BountyError::NoPayoutHeld);
+}
+
+#[test]
+fn disputed_selection_pays_the_rest_to_the_pick() {
+    let mut env = TestEnv::initialized();
+    let (client, picked, passed_over, arbiter, bounty) = held_selection(&mut env, 4);
+    let treasury = env.treasury;
+    env.send(
+        ix::open_dispute(
+            passed_over.pubkey(),
+            bounty,
+            passed_over.pubkey(),
+            evidence(0),
+        ),
+        &[&passed_over],
+    )
+    .unwrap();
+
+    let client_before = env.lamports(&client.pubkey());
+    let passed_over_before = env.lamports(&passed_over.pubkey());
+    env.send(
+        ix::cast_ruling(
+            arbiter.pubkey(),
+            bounty,
+            client.pubkey(),
+            passed_over.pubkey(),
+            treasury,
+            DisputeRuling::Split {
+                contributor_bps: 2_500,
+            },
+            &[arbiter.pubkey()],
+        ),
+        &[&arbiter],
+    )
+    .unwrap();
+
+    // The pick stands for what the panel didn't award, and none of it goes back
+    assert_eq!(
+        env.lamports(&passed_over.pubkey()) - passed_over_before,
+        LAMPORTS_PER_SOL
+    );
+    assert_eq!(env.lamports(&client.pubkey()), client_before);
+    let state: Bounty = env.account(&bounty);
+    assert!(state.payout_held && !state.disputed);
+
+    env.warp_by(SELECTION_DISPUTE_SECONDS + 1);
+    let before = env.lamports(&picked.pubkey());
+    env.send(
+        ix::release_held_payout(
+            client.pubkey(),
+            bounty,
+            client.pubkey(),
+            picked.pubkey(),
+            treasury,
+        ),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(
+        env.lamports(&picked.pubkey()) - before,
+        3 * LAMPORTS_PER_SOL
+    );
+}
+
+#[test]
+fn overturned_selection_pays_the_disputing_contributor() {
+    let mut env = TestEnv::initialized();
+    let (client, picked, passed_over, arbiter, bounty) = held_selection(&mut env, 2);
+    let treasury = env.treasury;
+    env.send(
+        ix::open_dispute(
+            passed_over.pubkey(),
+            bounty,
+            passed_over.pubkey(),
+            evidence(0),
+        ),
+        &[&passed_over],
+    )
+    .unwrap();
+
+    let before = env.lamports(&passed_over.pubkey());
+    env.send(
+        ix::cast_ruling(
+            arbiter.pubkey(),
+            bounty,
+            client.pubkey(),
+            passed_over.pubkey(),
+            treasury,
+            DisputeRuling::PayContributor,
+            &[arbiter.pubkey()],
+        ),
+        &[&arbiter],
+    )
+    .unwrap();
+    assert_eq!(
+        env.lamports(&passed_over.pubkey()) - before,
+        2 * LAMPORTS_PER_SOL
+    );
+    let state: Bounty = env.account(&bounty);
+    assert!(state.bounty_rewarded && !state.payout_held);
+    assert_eq!(state.selected_user_wallet_key, passed_over.pubkey());
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+
+    env.warp_by(SELECTION_DISPUTE_SECONDS + 1);
+    let result = env.send(
+        ix::release_held_payout(
+            client.pubkey(),
+            bounty,
+            client.pubkey(),
+            picked.pubkey(),
+            treasury,
+        ),
+        &[&client],
+    );
+    assert_error(result, BountyError::NoPayoutHeld);
+}
+
+#[test]
+fn client_cannot_dispute_a_guaranteed_payout_after_the_deadline() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let arbiter = env.keypair(1);
+    let deadline = env.now() + DAY;
+    let bounty = env.bounty_with_policy(
+        &client,
+        1,
+        deadline,
+        PayoutPolicy::GuaranteedToEarliest,
+        DAY,
+    );
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), bounty, vec![arbiter.pubkey()], 0),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&user, bounty).unwrap();
+
+    env.warp_to(deadline + 1);
+    let result = env.send(
+        ix::open_dispute(client.pubkey(), bounty, user.pubkey(), evidence(0)),
+        &[&client],
+    );
+    assert_error(result, DisputeError::GuaranteedPayoutLocked);
+
+    // The contributor can still raise one
+    env.send(
+        ix::open_dispute(user.pubkey(), bounty, user.pubkey(), evidence(0)),
+        &[&user],
+    )
+    .unwrap();
+}
@@ -0,0 +1,296 @@
+//! Random sequences of bounty operations across several clients and contributors,
+//! with the escrow invariants checked after every step.
+
+use std::collections::HashSet;
+
+use anchor_lang::prelude::Pubkey;
+use proptest::prelude::*;
+use proptest::sample::Index;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::states::{Bounty, Client, PayoutPolicy, User};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{TestEnv, DAY, LAMPORTS_PER_SOL};
+
+const CLIENTS: usize = 3;
+const USERS: usize = 4;
+const FEE_BPS: u16 = 250;
+
+#[derive(Debug, Clone)]
+enum Op {
+    CreateBounty {
+        client: Index,
+        reward: u64,
+        days: u64,
+    },
+    UpdateBounty {
+        bounty: Index,
+        days: u64,
+    },
+    DeleteBounty {
+        bounty: Index,
+    },
+    Submit {
+        user: Index,
+        bounty: Index,
+    },
+    Select {
+        bounty: Index,
+        user: Index,
+    },
+    Warp {
+        hours: u64,
+    },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        3 => (any::<Index>(), 1..=3u64, 1..=10u64)
+            .prop_map(|(client, reward, days)| Op::CreateBounty { client, reward, days }),
+        1 => (any::<Index>(), 1..=10u64).prop_map(|(bounty, days)| Op::UpdateBounty { bounty, days }),
+        1 => any::<Index>().prop_map(|bounty| Op::DeleteBounty { bounty }),
+        4 => (any::<Index>(), any::<Index>()).prop_map(|(user, bounty)| Op::Submit { user, bounty }),
+        2 => (any::<Index>(), any::<Index>()).prop_map(|(bounty, user)| Op::Select { bounty, user }),
+        1 => (1..=72u64).prop_map(|hours| Op::Warp { hours }),
+    ]
+}
+
+struct PostedBounty {
+    address: Pubkey,
+    client: usize,
+}
+
+struct Harness {
+    env: TestEnv,
+    clients: Vec<Keypair>,
+    users: Vec<Keypair>,
+    bounties: Vec<PostedBounty>,
+    paid: HashSet<Pubkey>,
+    treasury_start: u64,
+    total_start: u64,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let mut env = TestEnv::with_fee(FEE_BPS);
+        let clients = (0..CLIENTS).map(|_| env.client()).collect();
+        let users = (0..USERS).map(|_| env.user()).collect();
+        let treasury_start = env.lamports(&env.treasury);
+        let mut harness = Self {
+            env,
+            clients,
+            users,
+            bounties: Vec::new(),
+            paid: HashSet::new(),
+            treasury_start,
+            total_start: 0,
+        };
+        harness.total_start = harness.total_lamports();
+        harness
+    }
+
+    /// Every account the operations can move lamports between. The fee payer is
+    /// left out, so the sum only changes if the program mints or burns lamports.
+    fn tracked_accounts(&self) -> Vec<Pubkey> {
+        let mut accounts = vec![self.env.treasury];
+        for client in &self.clients {
+            accounts.push(client.pubkey());
+            accounts.push(pda::client(&client.pubkey()).0);
+        }
+        for user in &self.users {
+            accounts.push(user.pubkey());
+            accounts.push(pda::user(&user.pubkey()).0);
+        }
+        for bounty in &self.bounties {
+            accounts.push(bounty.address);
+            accounts.push(pda::escrow(&bounty.address).0);
+            for user in &self.users {
+                accounts.push(pda::submission(&user.pubkey(), &bounty.address).0);
+            }
+        }
+        accounts
+    }
+
+    fn total_lamports(&self) -> u64 {
+        self.tracked_accounts()
+            .iter()
+            .map(|address| self.env.lamports(address))
+            .sum()
+    }
+
+    fn apply(&mut self, op: &Op) {
+        match op {
+            Op::CreateBounty {
+                client,
+                reward,
+                days,
+            } => {
+                let index = client.index(CLIENTS);
+                let client = &self.clients[index];
+                let bounty_id = self.env.next_bounty_id(&client.pubkey());
+                let deadline = self.env.now() + days * DAY;
+                let result = self.env.send(
+                    ix::create_bounty(
+                        client.pubkey(),
+                        bounty_id,
+                        format!("Bounty {bounty_id}"),
+                        "desc".to_string(),
+                        *reward,
+                        deadline,
+                        vec![],
+                        PayoutPolicy::ClientSelects,
+                        0,
+                    ),
+                    &[client],
+                );
+                if result.is_ok() {
+                    self.bounties.push(PostedBounty {
+                        address: pda::bounty(&client.pubkey(), bounty_id).0,
+                        client: index,
+                    });
+                }
+            }
+            Op::UpdateBounty { bounty, days } => {
+                let Some(posted) = self.pick(bounty) else {
+                    return;
+                };
+                let client = &self.clients[posted.client];
+                let deadline = self.env.now() + days * DAY;
+                let _ = self.env.send(
+                    ix::update_bounty(
+                        client.pubkey(),
+                        posted.address,
+                        "Updated".to_string(),
+                        "desc".to_string(),
+                        deadline,
+                    ),
+                    &[client],
+                );
+            }
+            Op::DeleteBounty { bounty } => {
+                let Some(posted) = self.pick(bounty) else {
+                    return;
+                };
+                let client = &self.clients[posted.client];
+                let _ = self.env.send(
+                    ix::delete_bounty(client.pubkey(), posted.address),
+                    &[client],
+                );
+            }
+            Op::Submit { user, bounty } => {
+                let Some(posted) = self.pick(bounty) else {
+                    return;
+                };
+                let user = &self.users[user.index(USERS)];
+                let _ = self.env.submit(user, posted.address);
+            }
+            Op::Select { bounty, user } => {
+                let Some(posted) = self.pick(bounty) else {
+                    return;
+                };
+                let client = &self.clients[posted.client];
+                let user = &self.users[user.index(USERS)];
+                let result = self.env.send(
+                    ix::select_submission(
+                        client.pubkey(),
+                        posted.address,
+                        user.pubkey(),
+                        self.env.treasury,
+                    ),
+                    &[client],
+                );
+                if result.is_ok() {
+                    assert!(
+                        self.paid.insert(posted.address),
+                        "bounty {} was paid twice",
+                        posted.address
+                    );
+                }
+            }
+            Op::Warp { hours } => self.env.warp_by(hours * 60 * 60),
+        }
+    }
+
+    fn pick(&self, index: &Index) -> Option<PostedBounty> {
+        if self.bounties.is_empty() {
+            return None;
+        }
+        let posted = &self.bounties[index.index(self.bounties.len())];
+        Some(PostedBounty {
+            address: posted.address,
+            client: posted.client,
+        })
+    }
+
+    fn check_invariants(&self) {
+        let env = &self.env;
+        assert_eq!(
+            self.total_lamports(),
+            self.total_start,
+            "lamports were created or destroyed"
+        );
+
+        let escrow_rent = env.rent(0);
+        for posted in &self.bounties {
+            let escrow = env.lamports(&pda::escrow(&posted.address).0);
+            if !env.exists(&posted.address) {
+                // Deleted: the escrow was refunded in full
+                assert_eq!(escrow, 0, "deleted bounty left lamports in escrow");
+                continue;
+            }
+            let bounty: Bounty = env.account(&posted.address);
+            if bounty.live {
+                assert!(
+                    escrow >= escrow_rent + bounty.reward * LAMPORTS_PER_SOL,
+                    "live bounty {} is underfunded",
+                    posted.address
+                );
+                assert!(!bounty.bounty_rewarded);
+            } else {
+                assert!(bounty.bounty_rewarded);
+                assert!(self.paid.contains(&posted.address));
+                assert_eq!(escrow, escrow_rent, "paid bounty kept more than rent");
+            }
+
+            let submissions = self
+                .users
+                .iter()
+                .filter(|user| env.exists(&pda::submission(&user.pubkey(), &posted.address).0))
+                .count();
+            assert_eq!(bounty.no_of_submissions as usize, submissions);
+        }
+
+        let earned: u64 = self
+            .users
+            .iter()
+            .map(|user| env.account::<User>(&pda::user(&user.pubkey()).0).earned)
+            .sum();
+        let rewarded: u64 = self
+            .clients
+            .iter()
+            .map(|client| {
+                env.account::<Client>(&pda::client(&client.pubkey()).0)
+                    .rewarded
+            })
+            .sum();
+        let fees = env.lamports(&env.treasury) - self.treasury_start;
+        assert_eq!(
+            rewarded,
+            earned + fees,
+            "earned and rewarded totals diverged"
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn escrow_invariants_hold(ops in prop::collection::vec(op(), 1..40)) {
+        let mut harness = Harness::new();
+        for op in &ops {
+            harness.apply(op);
+            harness.check_invariants();
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Space;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, ClientError, SubmissionError, UserError};
+use solearn::states::{
+    Bounty, Client, Country, Industries, Social, Submission, SubmissionStatus, User, Versioned,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, borsh_fields, TestEnv, LAMPORTS_PER_SOL};
+
+fn data_len(env: &TestEnv, address: &Pubkey) -> usize {
+    env.svm.get_account(address).unwrap().data.len()
+}
+
+#[test]
+fn new_accounts_start_at_the_current_version() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(profile.version, User::LAYOUT_VERSION);
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(profile.version, Client::LAYOUT_VERSION);
+    assert_eq!(
+        env.account::<Bounty>(&bounty).version,
+        Bounty::LAYOUT_VERSION
+    );
+    let submission: Submission = env.account(&pda::submission(&user.pubkey(), &bounty).0);
+    assert_eq!(submission.version, Submission::LAYOUT_VERSION);
+}
+
+#[test]
+fn user_migrates_from_the_unversioned_layout() {
+    let mut env = TestEnv::initialized();
+    let user = env.legacy_user();
+    let address = pda::user(&user.pubkey()).0;
+
+    env.send(ix::migrate_user(user.pubkey()), &[&user]).unwrap();
+    assert_eq!(data_len(&env, &address), 8 + User::INIT_SPACE);
+    let profile: User = env.account(&address);
+    assert_eq!(profile.version, User::LAYOUT_VERSION);
+    assert_eq!(profile.authority, user.pubkey());
+    assert_eq!(profile.name, "Legacy Dev");
+    assert_eq!(profile.email, "legacy@dev.io");
+    assert_eq!(profile.skills, vec!["rust", "anchor"]);
+    assert_eq!(profile.earned, 3 * LAMPORTS_PER_SOL);
+    assert_eq!(profile.bounties_submitted, 4);
+    assert_eq!(profile.bounties_completed, 2);
+    assert_eq!(profile.rating_count, 0);
+    assert!(profile.country == Country::Other);
+    assert!(profile.socials.twitter.is_empty() && profile.socials.linkedin.is_empty());
+
+    let socials = Social {
+        twitter: "acme".to_string(),
+        ..Social::default()
+    };
+    env.send(
+        ix::set_user_details(user.pubkey(), Country::Canada, socials),
+        &[&user],
+    )
+    .unwrap();
+    let profile: User = env.account(&address);
+    assert!(profile.country == Country::Canada);
+    assert_eq!(profile.socials.twitter, "acme");
+
+    let result = env.send(ix::migrate_user(user.pubkey()), &[&user]);
+    assert_error(result, UserError::UserAlreadyMigrated);
+}
+
+#[test]
+fn client_migrates_and_keeps_posting() {
+    let mut env = TestEnv::initialized();
+    let client = env.legacy_client();
+    let address = pda::client(&client.pubkey()).0;
+
+    env.send(ix::migrate_client(client.pubkey()), &[&client])
+        .unwrap();
+    assert_eq!(data_len(&env, &address), 8 + Client::INIT_SPACE);
+    let profile: Client = env.account(&address);
+    assert_eq!(profile.version, Client::LAYOUT_VERSION);
+    assert_eq!(profile.company_link, "https://legacy.dev");
+    assert!(profile.industry == Industries::Other);
+    assert!(profile.country == Country::Other);
+    assert_eq!(profile.next_bounty_id, 0);
+    assert_eq!(profile.bounties_posted, 3);
+
+    let bounty = env.bounty(&client, 1);
+    assert_eq!(env.account::<Bounty>(&bounty).bounty_id, 0);
+    assert_eq!(env.account::<Client>(&address).bounties_posted, 4);
+
+    let result = env.send(ix::migrate_client(client.pubkey()), &[&client]);
+    assert_error(result, ClientError::ClientAlreadyMigrated);
+}
+
+#[test]
+fn bounty_migrates_and_still_pays_out() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let other = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 2);
+    env.submit(&user, bounty).unwrap();
+    let before: Bounty = env.account(&bounty);
+
+    // Layouts from version 1 on keep their size and only differ in how fields are read
+    let mut state = before.clone();
+    state.version = Bounty::LAYOUT_VERSION - 1;
+    env.overwrite(bounty, &state);
+
+    let result = env.send(ix::migrate_bounty_layout(other.pubkey(), bounty), &[&other]);
+    assert_error(result, ClientError::NotAuthorizedForBounty);
+
+    env.send(
+        ix::migrate_bounty_layout(client.pubkey(), bounty),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(data_len(&env, &bounty), 8 + Bounty::INIT_SPACE);
+    let after: Bounty = env.account(&bounty);
+    assert_eq!(after.version, Bounty::LAYOUT_VERSION);
+    assert_eq!(after.title, before.title);
+    assert_eq!(after.reward, before.reward);
+    assert_eq!(after.no_of_submissions, 1);
+    assert_eq!(after.first_submission, before.first_submission);
+
+    let result = env.send(
+        ix::migrate_bounty_layout(client.pubkey(), bounty),
+        &[&client],
+    );
+    assert_error(result, BountyError::BountyLayoutCurrent);
+
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    assert!(env.account::<Bounty>(&bounty).bounty_rewarded);
+}
+
+#[test]
+fn title_addressed_bounties_need_an_id_first() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let legacy = env.legacy_bounty(&client, "Fix login", 1);
+
+    let result = env.send(
+        ix::migrate_bounty_layout(client.pubkey(), legacy),
+        &[&client],
+    );
+    assert_error(result, BountyError::BountyNeedsId);
+
+    env.send(
+        ix::migrate_bounty(client.pubkey(), "Fix login".to_string()),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(data_len(&env, &legacy), 8 + Bounty::INIT_SPACE);
+    let migrated: Bounty = env.account(&legacy);
+    assert_eq!(migrated.version, Bounty::LAYOUT_VERSION);
+    assert_eq!(migrated.legacy_title_seed, "Fix login");
+    assert_eq!(migrated.bounty_id, 0);
+    assert_eq!(migrated.escrow_account, pda::escrow(&legacy).0);
+}
+
+#[test]
+fn submission_migrates_from_the_unversioned_layout() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    let (address, bump) = pda::submission(&user.pubkey(), &bounty);
+    let fields = borsh_fields![
+        user.pubkey(),
+        pda::user(&user.pubkey()).0,
+        bounty,
+        "Fixed the login flow".to_string(),
+        "https://github.com/acme/pull/1".to_string(),
+        bump,
+    ];
+    env.set_legacy_account::<Submission>(address, fields);
+
+    env.send(ix::migrate_submission(user.pubkey(), bounty), &[&user])
+        .unwrap();
+    assert_eq!(data_len(&env, &address), 8 + Submission::INIT_SPACE);
+    let after: Submission = env.account(&address);
+    assert_eq!(after.version, Submission::LAYOUT_VERSION);
+    assert_eq!(after.user_wallet_key, user.pubkey());
+    assert_eq!(after.bounty_key, bounty);
+    assert_eq!(after.description, "Fixed the login flow");
+    assert_eq!(after.work_url, "https://github.com/acme/pull/1");
+    // When the work was submitted wasn't recorded
+    assert_eq!(after.submitted_at, 0);
+    assert!(after.revisions.is_empty());
+    assert!(after.status == SubmissionStatus::Pending && after.feedback.is_empty());
+
+    let result = env.send(ix::migrate_submission(user.pubkey(), bounty), &[&user]);
+    assert_error(result, SubmissionError::SubmissionAlreadyMigrated);
+}
@@ -0,0 +1,314 @@
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, DisputeError, MilestoneError};
+use solearn::states::{
+    Bounty, BountyCloseReason, DisputeRuling, MilestoneParams, MilestoneStatus,
+    MILESTONE_REVIEW_SECONDS,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY, LAMPORTS_PER_SOL};
+
+fn post_milestones(env: &mut TestEnv, client: &Keypair, rewards: &[u64]) -> Pubkey {
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + 7 * DAY;
+    let milestones = rewards
+        .iter()
+        .enumerate()
+        .map(|(index, reward)| MilestoneParams {
+            reward: *reward,
+            deliverable: format!("Phase {}", index + 1),
+        })
+        .collect();
+    env.send(
+        ix::create_milestone_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Build the indexer".to_string(),
+            "desc".to_string(),
+            milestones,
+            deadline,
+            vec![],
+        ),
+        &[client],
+    )
+    .unwrap();
+    pda::bounty(&client.pubkey(), bounty_id).0
+}
+
+fn work_url() -> String {
+    "https://github.com/acme/indexer/pull/7".to_string()
+}
+
+#[test]
+fn milestone_engagement_pays_per_milestone() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let contributor = env.user();
+    let outsider = env.user();
+    let bounty = post_milestones(&mut env, &client, &[2, 3]);
+    assert_eq!(
+        env.lamports(&pda::escrow(&bounty).0),
+        5 * LAMPORTS_PER_SOL + env.rent(0)
+    );
+    env.submit(&contributor, bounty).unwrap();
+    env.submit(&outsider, bounty).unwrap();
+
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, contributor.pubkey(), env.treasury),
+        &[&client],
+    );
+    assert_error(result, MilestoneError::MilestoneBountyRequiresApproval);
+
+    env.send(
+        ix::engage_contributor(client.pubkey(), bounty, contributor.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::engage_contributor(client.pubkey(), bounty, outsider.pubkey()),
+        &[&client],
+    );
+    assert_error(result, MilestoneError::ContributorAlreadyEngaged);
+
+    let result = env.send(
+        ix::submit_milestone(outsider.pubkey(), bounty, 0, work_url()),
+        &[&outsider],
+    );
+    assert_error(result, MilestoneError::NotEngagedContributor);
+
+    let result = env.send(
+        ix::review_milestone(
+            client.pubkey(),
+            bounty,
+            contributor.pubkey(),
+            env.treasury,
+            0,
+            true,
+        ),
+        &[&client],
+    );
+    assert_error(result, MilestoneError::MilestoneNotSubmitted);
+
+    env.send(
+        ix::submit_milestone(contributor.pubkey(), bounty, 0, work_url()),
+        &[&contributor],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::submit_milestone(contributor.pubkey(), bounty, 0, work_url()),
+        &[&contributor],
+    );
+    assert_error(result, MilestoneError::MilestoneNotPending);
+
+    // A rejection sends the milestone back for another attempt
+    env.send(
+        ix::review_milestone(
+            client.pubkey(),
+            bounty,
+            contributor.pubkey(),
+            env.treasury,
+            0,
+            false,
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let state: Bounty = env.account(&bounty);
+    assert!(state.milestones[0].status == MilestoneStatus::Pending);
+
+    env.send(
+        ix::submit_milestone(contributor.pubkey(), bounty, 0, work_url()),
+        &[&contributor],
+    )
+    .unwrap();
+    let before = env.lamports(&contributor.pubkey());
+    env.send(
+        ix::review_milestone(
+            client.pubkey(),
+            bounty,
+            contributor.pubkey(),
+            env.treasury,
+            0,
+            true,
+        ),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(
+        env.lamports(&contributor.pubkey()) - before,
+        2 * LAMPORTS_PER_SOL
+    );
+
+    // The contributor keeps the engagement until the deadline
+    let result = env.send(
+        ix::end_milestone_engagement(client.pubkey(), bounty),
+        &[&client],
+    );
+    assert_error(result, BountyError::DeadlineNotReached);
+
+    // Ending the engagement refunds the milestones that were never delivered and
+    // closes the escrow
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+    env.warp_to(deadline + 1);
+    let before = env.lamports(&client.pubkey());
+    env.send(
+        ix::end_milestone_engagement(client.pubkey(), bounty),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(
+        env.lamports(&client.pubkey()) - before,
+        3 * LAMPORTS_PER_SOL + env.rent(0)
+    );
+
+    let state: Bounty = env.account(&bounty);
+    assert!(state.milestones[0].status == MilestoneStatus::Approved);
+    assert!(state.milestones[1].status == MilestoneStatus::Refunded);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+    assert!(!env.exists(&pda::escrow(&bounty).0));
+}
+
+#[test]
+fn engagement_cannot_end_with_work_awaiting_review() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let contributor = env.user();
+    let bounty = post_milestones(&mut env, &client, &[1]);
+    env.submit(&contributor, bounty).unwrap();
+    env.send(
+        ix::engage_contributor(client.pubkey(), bounty, contributor.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::submit_milestone(contributor.pubkey(), bounty, 0, work_url()),
+        &[&contributor],
+    )
+    .unwrap();
+
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+    env.warp_to(deadline + 1);
+    let result = env.send(
+        ix::end_milestone_engagement(client.pubkey(), bounty),
+        &[&client],
+    );
+    assert_error(result, MilestoneError::MilestoneAwaitingReview);
+}
+
+#[test]
+fn contributor_releases_milestone_the_client_never_reviews() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let contributor = env.user();
+    let outsider = env.user();
+    let bounty = post_milestones(&mut env, &client, &[2]);
+    env.submit(&contributor, bounty).unwrap();
+    env.send(
+        ix::engage_contributor(client.pubkey(), bounty, contributor.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::submit_milestone(contributor.pubkey(), bounty, 0, work_url()),
+        &[&contributor],
+    )
+    .unwrap();
+    let treasury = env.treasury;
+    let release = |wallet| ix::release_milestone(wallet, bounty, client.pubkey(), treasury, 0);
+
+    let result = env.send(release(contributor.pubkey()), &[&contributor]);
+    assert_error(result, MilestoneError::MilestoneReviewPeriodNotElapsed);
+
+    env.warp_by(MILESTONE_REVIEW_SECONDS + 1);
+    let result = env.send(release(outsider.pubkey()), &[&outsider]);
+    assert_error(result, MilestoneError::NotEngagedContributor);
+
+    let before = env.lamports(&contributor.pubkey());
+    env.send(release(contributor.pubkey()), &[&contributor])
+        .unwrap();
+    assert_eq!(
+        env.lamports(&contributor.pubkey()) - before,
+        2 * LAMPORTS_PER_SOL
+    );
+    let state: Bounty = env.account(&bounty);
+    assert!(state.milestones[0].status == MilestoneStatus::Approved);
+    assert!(!state.live);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+}
+
+#[test]
+fn engaged_contributor_can_dispute_rejected_work() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let contributor = env.user();
+    let arbiter = env.keypair(1);
+    let bounty = post_milestones(&mut env, &client, &[1, 2]);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), bounty, vec![arbiter.pubkey()], 0),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&contributor, bounty).unwrap();
+    env.send(
+        ix::engage_contributor(client.pubkey(), bounty, contributor.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::submit_milestone(contributor.pubkey(), bounty, 0, work_url()),
+        &[&contributor],
+    )
+    .unwrap();
+    env.send(
+        ix::review_milestone(
+            client.pubkey(),
+            bounty,
+            contributor.pubkey(),
+            env.treasury,
+            0,
+            false,
+        ),
+        &[&client],
+    )
+    .unwrap();
+
+    // The rejected contributor freezes the escrow instead of watching it be refunded
+    env.send(
+        ix::open_dispute(
+            contributor.pubkey(),
+            bounty,
+            contributor.pubkey(),
+            "https://evidence.example/0".to_string(),
+        ),
+        &[&contributor],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::end_milestone_engagement(client.pubkey(), bounty),
+        &[&client],
+    );
+    assert_error(result, DisputeError::EscrowFrozen);
+
+    let before = env.lamports(&contributor.pubkey());
+    env.send(
+        ix::cast_ruling(
+            arbiter.pubkey(),
+            bounty,
+            client.pubkey(),
+            contributor.pubkey(),
+            env.treasury,
+            DisputeRuling::PayContributor,
+            &[arbiter.pubkey()],
+        ),
+        &[&arbiter],
+    )
+    .unwrap();
+    assert_eq!(
+        env.lamports(&contributor.pubkey()) - before,
+        3 * LAMPORTS_PER_SOL
+    );
+    let state: Bounty = env.account(&bounty);
+    assert!(!state.live);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+}
@@ -0,0 +1,606 @@
+use anchor_lang::error::ErrorCode;
+use solana_signer::Signer;
+use solearn::errors::{ClientError, UserError};
+use solearn::states::{
+    Bounty, Client, Country, HandleRecord, Industries, PayoutPolicy, Social, User, Versioned,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv};
+
+#[test]
+fn user_lifecycle() {
+    let mut env = TestEnv::initialized();
+    let user = env.user();
+    let address = pda::user(&user.pubkey()).0;
+
+    let profile: User = env.account(&address);
+    assert_eq!(profile.authority, user.pubkey());
+    assert!(profile.email.starts_with(&profile.name));
+
+    let result = env.send(
+        ix::update_user(
+            user.pubkey(),
+            &profile.name,
+            &profile.email,
+            "alice b".to_string(),
+            format!("{}@example.org", "a".repeat(100)),
+            String::new(),
+            vec![],
+        ),
+        &[&user],
+    );
+    assert_error(result, UserError::InvalidEmailFormat);
+
+    env.send(
+        ix::update_user(
+            user.pubkey(),
+            &profile.name,
+            &profile.email,
+            "alice b".to_string(),
+            "alice@example.org".to_string(),
+            "Rust developer".to_string(),
+            vec!["rust".to_string(), "anchor".to_string()],
+        ),
+        &[&user],
+    )
+    .unwrap();
+    let profile: User = env.account(&address);
+    assert_eq!(profile.email, "alice@example.org");
+    assert_eq!(profile.bio, "Rust developer");
+    assert_eq!(profile.skills.len(), 2);
+
+    env.send(
+        ix::delete_user(user.pubkey(), &profile.name, &profile.email),
+        &[&user],
+    )
+    .unwrap();
+    // The profile stays behind as a tombstone with its contact details cleared
+    let tombstone: User = env.account(&address);
+    assert!(tombstone.is_deleted());
+    assert_eq!(tombstone.name, profile.name);
+    assert!(tombstone.email.is_empty() && tombstone.bio.is_empty());
+    assert!(tombstone.skills.is_empty());
+
+    let result = env.send(
+        ix::delete_user(user.pubkey(), &profile.name, &profile.email),
+        &[&user],
+    );
+    assert_error(result, UserError::UserDeleted);
+}
+
+#[test]
+fn create_user_validates_input() {
+    let mut env = TestEnv::initialized();
+    let user = env.keypair(1);
+    let result = env.send(
+        ix::create_user(user.pubkey(), String::new(), "a@b.c".to_string(), vec![]),
+        &[&user],
+    );
+    assert_error(result, UserError::InvalidNameFormat);
+
+    let result = env.send(
+        ix::create_user(user.pubkey(), "x".repeat(33), "a@b.c".to_string(), vec![]),
+        &[&user],
+    );
+    assert_error(result, UserError::InvalidNameFormat);
+
+    let result = env.send(
+        ix::create_user(user.pubkey(), "alice".to_string(), String::new(), vec![]),
+        &[&user],
+    );
+    assert_error(result, UserError::InvalidEmailFormat);
+}
+
+#[test]
+fn client_lifecycle() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let address = pda::client(&client.pubkey()).0;
+    let company_name = env.account::<Client>(&address).company_name;
+
+    env.send(
+        ix::update_client(
+            client.pubkey(),
+            &company_name,
+            "Acme Inc".to_string(),
+            "hello@acme.dev".to_string(),
+            "https://acme.dev".to_string(),
+            "We build things".to_string(),
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let profile: Client = env.account(&address);
+    assert_eq!(profile.company_name, "Acme Inc");
+    assert_eq!(profile.company_bio, "We build things");
+    assert_eq!(profile.next_bounty_id, 0);
+
+    env.send(ix::delete_client(client.pubkey(), "Acme Inc"), &[&client])
+        .unwrap();
+    let tombstone: Client = env.account(&address);
+    assert!(tombstone.is_deleted());
+    assert_eq!(tombstone.company_name, "Acme Inc");
+    assert!(tombstone.company_email.is_empty() && tombstone.company_bio.is_empty());
+
+    let result = env.send(ix::delete_client(client.pubkey(), "Acme Inc"), &[&client]);
+    assert_error(result, ClientError::ClientDeleted);
+}
+
+#[test]
+fn create_client_validates_input() {
+    let mut env = TestEnv::initialized();
+    let client = env.keypair(1);
+    let result = env.send(
+        ix::create_client(
+            client.pubkey(),
+            String::new(),
+            "ops@acme.dev".to_string(),
+            "https://acme.dev".to_string(),
+        ),
+        &[&client],
+    );
+    assert_error(result, ClientError::InvalidCompanyNameFormat);
+
+    let result = env.send(
+        ix::create_client(
+            client.pubkey(),
+            "Acme".to_string(),
+            String::new(),
+            "https://acme.dev".to_string(),
+        ),
+        &[&client],
+    );
+    assert_error(result, ClientError::InvalidCompanyEmailFormat);
+
+    let result = env.send(
+        ix::create_client(
+            client.pubkey(),
+            "Acme".to_string(),
+            "ops@acme.dev".to_string(),
+            String::new(),
+        ),
+        &[&client],
+    );
+    assert_error(result, ClientError::InvalidCompanyLinkFormat);
+}
+
+#[test]
+fn profile_details_are_validated() {
+    let mut env = TestEnv::initialized();
+    let user = env.user();
+    let client = env.client();
+    let socials = Social {
+        twitter: "acme".to_string(),
+        linkedin: "acme-labs".to_string(),
+        website: "https://acme.dev".to_string(),
+        ..Social::default()
+    };
+
+    env.send(
+        ix::set_user_details(user.pubkey(), Country::India, socials.clone()),
+        &[&user],
+    )
+    .unwrap();
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert!(profile.country == Country::India);
+    assert_eq!(profile.socials.twitter, "acme");
+
+    env.send(
+        ix::set_client_details(
+            client.pubkey(),
+            Industries::Defi,
+            Country::UK,
+            socials.clone(),
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert!(profile.industry == Industries::Defi);
+    assert!(profile.country == Country::UK);
+    assert_eq!(profile.socials.website, "https://acme.dev");
+
+    let insecure = Social {
+        website: "http://acme.dev".to_string(),
+        ..socials.clone()
+    };
+    let result = env.send(
+        ix::set_user_details(user.pubkey(), Country::Other, insecure),
+        &[&user],
+    );
+    assert_error(result, UserError::InvalidSocials);
+    let spaced = Social {
+        twitter: "acme labs".to_string(),
+        ..socials
+    };
+    let result = env.send(
+        ix::set_client_details(client.pubkey(), Industries::Other, Country::Other, spaced),
+        &[&client],
+    );
+    assert_error(result, ClientError::InvalidSocials);
+}
+
+#[test]
+fn older_profiles_are_migrated_before_setting_details() {
+    let mut env = TestEnv::initialized();
+    let client = env.legacy_client();
+    let address = pda::client(&client.pubkey()).0;
+    let set_details = || {
+        ix::set_client_details(
+            client.pubkey(),
+            Industries::Nfts,
+            Country::Canada,
+            Social::default(),
+        )
+    };
+
+    // Profile updates don't grow accounts; `migrate_client` does
+    let result = env.send(set_details(), &[&client]);
+    assert_error(result, ErrorCode::AccountDidNotDeserialize);
+
+    env.send(ix::migrate_client(client.pubkey()), &[&client])
+        .unwrap();
+    env.send(set_details(), &[&client]).unwrap();
+    let profile: Client = env.account(&address);
+    assert_eq!(profile.version, Client::LAYOUT_VERSION);
+    assert!(profile.industry == Industries::Nfts);
+    assert_eq!(profile.company_name, "Legacy Labs");
+}
+
+#[test]
+fn migrate_client_appends_bounty_counter() {
+    let mut env = TestEnv::initialized();
+    let client = env.legacy_client();
+    let address = pda::client(&client.pubkey()).0;
+
+    env.send(ix::migrate_client(client.pubkey()), &[&client])
+        .unwrap();
+    let profile: Client = env.account(&address);
+    assert_eq!(profile.company_name, "Legacy Labs");
+    assert_eq!(profile.bounties_posted, 3);
+    assert_eq!(profile.next_bounty_id, 0);
+    assert_eq!(profile.rating_count, 0);
+    assert_eq!(profile.reputation.score, 0);
+    // New enum fields are written as their defaults rather than read from zeroes
+    assert!(profile.industry == Industries::Other);
+    assert!(profile.country == Country::Other);
+    assert_eq!(profile.version, Client::LAYOUT_VERSION);
+
+    let result = env.send(ix::migrate_client(client.pubkey()), &[&client]);
+    assert_error(result, ClientError::ClientAlreadyMigrated);
+}
+
+#[test]
+fn usernames_and_emails_are_unique() {
+    let mut env = TestEnv::initialized();
+    let alice = env.keypair(1);
+    let bob = env.keypair(1);
+    env.send(
+        ix::create_user(
+            alice.pubkey(),
+            "Alice".to_string(),
+            "alice@example.com".to_string(),
+            vec![],
+        ),
+        &[&alice],
+    )
+    .unwrap();
+
+    let record: HandleRecord = env.account(&pda::user_name("alice").0);
+    assert_eq!(record.authority, alice.pubkey());
+    assert_eq!(record.profile, pda::user(&alice.pubkey()).0);
+    let fetched = solearn_client::accounts::fetch_user_by_name(&env, " ALICE ").unwrap();
+    assert_eq!(fetched.authority, alice.pubkey());
+
+    // Handles compare case-insensitively
+    let result = env.send(
+        ix::create_user(
+            bob.pubkey(),
+            " alice".to_string(),
+            "bob@example.com".to_string(),
+            vec![],
+        ),
+        &[&bob],
+    );
+    assert_error(result, UserError::UsernameAlreadyTaken);
+    let result = env.send(
+        ix::create_user(
+            bob.pubkey(),
+            "bob".to_string(),
+            "Alice@Example.com".to_string(),
+            vec![],
+        ),
+        &[&bob],
+    );
+    assert_error(result, UserError::EmailAlreadyTaken);
+
+    // Renaming frees the old handle and keeps the unchanged email
+    env.send(
+        ix::update_user(
+            alice.pubkey(),
+            "Alice",
+            "alice@example.com",
+            "alicia".to_string(),
+            "alice@example.com".to_string(),
+            String::new(),
+            vec![],
+        ),
+        &[&alice],
+    )
+    .unwrap();
+    assert!(!env.exists(&pda::user_name("alice").0));
+    assert!(env.exists(&pda::user_email("alice@example.com").0));
+    env.send(
+        ix::create_user(
+            bob.pubkey(),
+            "alice".to_string(),
+            "bob@example.com".to_string(),
+            vec![],
+        ),
+        &[&bob],
+    )
+    .unwrap();
+
+    let result = env.send(
+        ix::update_user(
+            alice.pubkey(),
+            "alicia",
+            "alice@example.com",
+            "alicia".to_string(),
+            "bob@example.com".to_string(),
+            String::new(),
+            vec![],
+        ),
+        &[&alice],
+    );
+    assert_error(result, UserError::EmailAlreadyTaken);
+
+    // Deleting releases both records
+    env.send(
+        ix::delete_user(alice.pubkey(), "alicia", "alice@example.com"),
+        &[&alice],
+    )
+    .unwrap();
+    assert!(!env.exists(&pda::user_name("alicia").0));
+    assert!(!env.exists(&pda::user_email("alice@example.com").0));
+}
+
+#[test]
+fn company_handles_are_unique() {
+    let mut env = TestEnv::initialized();
+    let acme = env.keypair(1);
+    let rival = env.keypair(1);
+    let create = |wallet: &solana_keypair::Keypair, name: &str| {
+        ix::create_client(
+            wallet.pubkey(),
+            name.to_string(),
+            "ops@example.com".to_string(),
+            "https://example.com".to_string(),
+        )
+    };
+    env.send(create(&acme, "Acme"), &[&acme]).unwrap();
+    assert_error(
+        env.send(create(&rival, "ACME"), &[&rival]),
+        ClientError::CompanyUsernameAlreadyTaken,
+    );
+
+    let fetched = solearn_client::accounts::fetch_client_by_company_name(&env, "acme").unwrap();
+    assert_eq!(fetched.authority, acme.pubkey());
+
+    env.send(ix::delete_client(acme.pubkey(), "Acme"), &[&acme])
+        .unwrap();
+    assert!(!env.exists(&pda::company_name("Acme").0));
+    env.send(create(&rival, "ACME"), &[&rival]).unwrap();
+}
+
+#[test]
+fn deleting_a_client_waits_for_live_bounties() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let address = pda::client(&client.pubkey()).0;
+    let company_name = env.account::<Client>(&address).company_name;
+    let treasury = env.treasury;
+
+    let unclaimed = env.bounty(&client, 1);
+    let awarded = env.bounty(&client, 1);
+    assert_eq!(env.account::<Client>(&address).live_bounties, 2);
+
+    let result = env.send(
+        ix::delete_client(client.pubkey(), &company_name),
+        &[&client],
+    );
+    assert_error(result, ClientError::HasLiveListings);
+
+    env.send(ix::delete_bounty(client.pubkey(), unclaimed), &[&client])
+        .unwrap();
+    env.submit(&user, awarded).unwrap();
+    env.send(
+        ix::select_submission(client.pubkey(), awarded, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(env.account::<Client>(&address).live_bounties, 0);
+
+    env.send(
+        ix::delete_client(client.pubkey(), &company_name),
+        &[&client],
+    )
+    .unwrap();
+    // A deleted client can't post again
+    let bounty_id = env.account::<Client>(&address).next_bounty_id;
+    let deadline = env.now() + 86_400;
+    let result = env.send(
+        ix::create_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Fix login".to_string(),
+            "Normalize emails".to_string(),
+            1,
+            deadline,
+            vec!["rust".to_string()],
+            PayoutPolicy::ClientSelects,
+            0,
+        ),
+        &[&client],
+    );
+    assert_error(result, ClientError::ClientDeleted);
+}
+
+#[test]
+fn bounties_posted_before_the_counter_dont_release_it() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let address = pda::client(&client.pubkey()).0;
+    let company_name = env.account::<Client>(&address).company_name;
+    let legacy = env.bounty(&client, 1);
+    let counted = env.bounty(&client, 1);
+
+    // Put `legacy` back to how a bounty posted before the counter stands: untracked
+    let mut state: Bounty = env.account(&legacy);
+    state.counted_live = false;
+    env.overwrite(legacy, &state);
+    let mut profile: Client = env.account(&address);
+    profile.live_bounties = 1;
+    env.overwrite(address, &profile);
+
+    env.send(ix::delete_bounty(client.pubkey(), legacy), &[&client])
+        .unwrap();
+    assert_eq!(env.account::<Client>(&address).live_bounties, 1);
+    let result = env.send(
+        ix::delete_client(client.pubkey(), &company_name),
+        &[&client],
+    );
+    assert_error(result, ClientError::HasLiveListings);
+
+    env.send(ix::delete_bounty(client.pubkey(), counted), &[&client])
+        .unwrap();
+    assert_eq!(env.account::<Client>(&address).live_bounties, 0);
+    env.send(
+        ix::delete_client(client.pubkey(), &company_name),
+        &[&client],
+    )
+    .unwrap();
+}
+
+#[test]
+fn deleted_user_submissions_can_still_be_selected() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    let treasury = env.treasury;
+    env.submit(&user, bounty).unwrap();
+
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    env.send(
+        ix::delete_user(user.pubkey(), &profile.name, &profile.email),
+        &[&user],
+    )
+    .unwrap();
+    let other = env.bounty(&client, 1);
+    assert_error(env.submit(&user, other), UserError::UserDeleted);
+
+    // The tombstone still resolves, so the pending submission can win and be paid
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(profile.bounties_completed, 1);
+}
+
+#[test]
+fn deleted_profiles_can_be_recreated() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let treasury = env.treasury;
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let address = pda::user(&user.pubkey()).0;
+    let profile: User = env.account(&address);
+    let result = env.send(
+        ix::create_user(
+            user.pubkey(),
+            "Alice".to_string(),
+            "alice@example.net".to_string(),
+            vec![],
+        ),
+        &[&user],
+    );
+    assert_error(result, UserError::UserAlreadyExists);
+    env.send(
+        ix::delete_user(user.pubkey(), &profile.name, &profile.email),
+        &[&user],
+    )
+    .unwrap();
+
+    // Re-creating clears the tombstone but keeps the track record
+    env.send(
+        ix::create_user(
+            user.pubkey(),
+            "Alice".to_string(),
+            "alice@example.net".to_string(),
+            vec!["rust".to_string()],
+        ),
+        &[&user],
+    )
+    .unwrap();
+    let restored: User = env.account(&address);
+    assert!(!restored.is_deleted());
+    assert_eq!(restored.name, "Alice");
+    assert_eq!(restored.email, "alice@example.net");
+    assert_eq!(restored.bounties_completed, 1);
+    assert_eq!(restored.earned, profile.earned);
+    assert_eq!(restored.joined_at, profile.joined_at);
+    let other = env.bounty(&client, 1);
+    env.submit(&user, other).unwrap();
+
+    let address = pda::client(&client.pubkey()).0;
+    let profile: Client = env.account(&address);
+    let result = env.send(
+        ix::create_client(
+            client.pubkey(),
+            "Acme Two".to_string(),
+            "hi@acme.two".to_string(),
+            "https://acme.two".to_string(),
+        ),
+        &[&client],
+    );
+    assert_error(result, ClientError::ClientAlreadyExists);
+
+    env.send(ix::withdraw_submission(user.pubkey(), other), &[&user])
+        .unwrap();
+    env.send(ix::delete_bounty(client.pubkey(), other), &[&client])
+        .unwrap();
+    env.send(
+        ix::delete_client(client.pubkey(), &profile.company_name),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::create_client(
+            client.pubkey(),
+            "Acme Two".to_string(),
+            "hi@acme.two".to_string(),
+            "https://acme.two".to_string(),
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let restored: Client = env.account(&address);
+    assert!(!restored.is_deleted());
+    assert_eq!(restored.company_name, "Acme Two");
+    assert_eq!(restored.rewarded, profile.rewarded);
+    // Ids keep counting so new bounties don't collide with the closed ones
+    assert_eq!(restored.next_bounty_id, 2);
+    env.bounty(&client, 1);
+}
@@ -0,0 +1,387 @@
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::errors::{ApplicationError, ConfigError, ProjectError};
+use solearn::states::{Application, ApplicationStatus, Project, User, WorkStatus};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{
+    assert_error, TestEnv, TxResult, DAY, LAMPORTS_PER_SOL, MAX_DEADLINE_DURATION,
+};
+
+const TITLE: &str = "Mobile app";
+
+fn post_project(env: &mut TestEnv, client: &Keypair, reward: u64) -> Pubkey {
+    let project_id = env.next_project_id(&client.pubkey());
+    let deadline = env.now() + 7 * DAY;
+    env.send(
+        ix::create_project(
+            client.pubkey(),
+            project_id,
+            TITLE.to_string(),
+            "Ship the iOS client".to_string(),
+            reward,
+            deadline,
+            vec!["swift".to_string()],
+        ),
+        &[client],
+    )
+    .unwrap();
+    pda::project(&client.pubkey(), project_id).0
+}
+
+fn apply(env: &mut TestEnv, user: &Keypair, project: Pubkey) -> TxResult {
+    env.send(
+        ix::apply_to_project(
+            user.pubkey(),
+            project,
+            "I have shipped three iOS apps".to_string(),
+            "https://portfolio.example".to_string(),
+        ),
+        &[user],
+    )
+}
+
+fn submit_work(env: &mut TestEnv, user: &Keypair, project: Pubkey) -> TxResult {
+    env.send(
+        ix::submit_work(
+            user.pubkey(),
+            project,
+            "Build uploaded to TestFlight".to_string(),
+            "https://github.com/acme/ios/pull/3".to_string(),
+            String::new(),
+        ),
+        &[user],
+    )
+}
+
+#[test]
+fn create_project_validates_and_funds_escrow() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let deadline = env.now() + DAY;
+    let result = env.send(
+        ix::create_project(
+            client.pubkey(),
+            0,
+            String::new(),
+            "desc".to_string(),
+            1,
+            deadline,
+            vec![],
+        ),
+        &[&client],
+    );
+    assert_error(result, ProjectError::InvalidProjectTitle);
+    let result = env.send(
+        ix::create_project(
+            client.pubkey(),
+            0,
+            TITLE.to_string(),
+            "desc".to_string(),
+            0,
+            deadline,
+            vec![],
+        ),
+        &[&client],
+    );
+    assert_error(result, ProjectError::InvalidRewardAmount);
+
+    let project = post_project(&mut env, &client, 5);
+    assert_eq!(
+        env.lamports(&pda::escrow(&project).0),
+        5 * LAMPORTS_PER_SOL + env.rent(0)
+    );
+    assert_eq!(env.account::<Project>(&project).project_id, 0);
+
+    // Projects are addressed by id, so titles can repeat
+    let second = post_project(&mut env, &client, 1);
+    assert_ne!(second, project);
+    assert_eq!(env.account::<Project>(&second).project_id, 1);
+    assert_eq!(env.account::<Project>(&second).title, TITLE);
+}
+
+#[test]
+fn projects_follow_platform_policy() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let authority = env.upgrade_authority.insecure_clone();
+    let treasury = env.treasury;
+    env.send(
+        ix::update_config(
+            authority.pubkey(),
+            authority.pubkey(),
+            0,
+            treasury,
+            2,
+            MAX_DEADLINE_DURATION,
+        ),
+        &[&authority],
+    )
+    .unwrap();
+
+    let project_id = env.next_project_id(&client.pubkey());
+    let create = |reward, deadline| {
+        ix::create_project(
+            client.pubkey(),
+            project_id,
+            TITLE.to_string(),
+            "desc".to_string(),
+            reward,
+            deadline,
+            vec![],
+        )
+    };
+    let result = env.send(create(1, env.now() + DAY), &[&client]);
+    assert_error(result, ConfigError::RewardBelowMinimum);
+    let result = env.send(create(2, env.now() + MAX_DEADLINE_DURATION + 1), &[&client]);
+    assert_error(result, ConfigError::DeadlineBeyondMaximum);
+
+    let project = post_project(&mut env, &client, 2);
+    let result = env.send(
+        ix::update_project(
+            client.pubkey(),
+            project,
+            "desc".to_string(),
+            env.now() + MAX_DEADLINE_DURATION + 1,
+        ),
+        &[&client],
+    );
+    assert_error(result, ConfigError::DeadlineBeyondMaximum);
+    assert_eq!(env.account::<Project>(&project).platform_fee_bps, 0);
+}
+
+#[test]
+fn approved_work_pays_the_platform_fee() {
+    let mut env = TestEnv::with_fee(500);
+    let treasury = env.treasury;
+    let client = env.client();
+    let user = env.user();
+    let project = post_project(&mut env, &client, 4);
+    apply(&mut env, &user, project).unwrap();
+    env.send(
+        ix::approve_application(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    submit_work(&mut env, &user, project).unwrap();
+
+    let result = env.send(
+        ix::approve_work(client.pubkey(), project, user.pubkey(), client.pubkey()),
+        &[&client],
+    );
+    assert_error(result, ConfigError::InvalidTreasury);
+
+    let user_before = env.lamports(&user.pubkey());
+    let treasury_before = env.lamports(&treasury);
+    env.send(
+        ix::approve_work(client.pubkey(), project, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    let fee = 4 * LAMPORTS_PER_SOL / 20;
+    assert_eq!(env.lamports(&treasury) - treasury_before, fee);
+    assert_eq!(
+        env.lamports(&user.pubkey()) - user_before,
+        4 * LAMPORTS_PER_SOL - fee
+    );
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(profile.earned, 4 * LAMPORTS_PER_SOL - fee);
+}
+
+#[test]
+fn update_and_close_project() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let before = env.lamports(&client.pubkey());
+    let project = post_project(&mut env, &client, 5);
+    let deadline = env.now() + 3 * DAY;
+
+    env.send(
+        ix::update_project(client.pubkey(), project, "New scope".to_string(), deadline),
+        &[&client],
+    )
+    .unwrap();
+    let state: Project = env.account(&project);
+    assert_eq!(state.description, "New scope");
+    assert_eq!(state.deadline, deadline);
+
+    apply(&mut env, &user, project).unwrap();
+    let result = env.send(
+        ix::update_project(client.pubkey(), project, "Again".to_string(), deadline),
+        &[&client],
+    );
+    assert_error(result, ProjectError::CannotUpdateWithApplications);
+
+    env.send(ix::close_project(client.pubkey(), project), &[&client])
+        .unwrap();
+    assert!(!env.exists(&project));
+    assert_eq!(env.lamports(&client.pubkey()), before);
+}
+
+#[test]
+fn applications_close_at_the_deadline() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let project = post_project(&mut env, &client, 1);
+    let deadline = env.account::<Project>(&project).deadline;
+
+    env.warp_to(deadline + 1);
+    assert_error(
+        apply(&mut env, &user, project),
+        ApplicationError::ApplicationDeadlinePassed,
+    );
+}
+
+#[test]
+fn approved_application_is_paid_for_approved_work() {
+    let mut env = TestEnv::initialized();
+    let treasury = env.treasury;
+    let client = env.client();
+    let chosen = env.user();
+    let other = env.user();
+    let project = post_project(&mut env, &client, 5);
+    apply(&mut env, &chosen, project).unwrap();
+    apply(&mut env, &other, project).unwrap();
+
+    assert_error(
+        submit_work(&mut env, &chosen, project),
+        ApplicationError::CannotSubmitWorkForNonApprovedApplication,
+    );
+
+    env.send(
+        ix::reject_application(client.pubkey(), project, other.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::reject_application(client.pubkey(), project, other.pubkey()),
+        &[&client],
+    );
+    assert_error(result, ApplicationError::CannotRejectNonPending);
+    let result = env.send(
+        ix::approve_application(client.pubkey(), project, other.pubkey()),
+        &[&client],
+    );
+    assert_error(result, ApplicationError::CannotApproveNonPending);
+
+    env.send(
+        ix::approve_application(client.pubkey(), project, chosen.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    let result = env.send(ix::close_project(client.pubkey(), project), &[&client]);
+    assert_error(result, ProjectError::ProjectHasApprovedApplication);
+
+    let result = env.send(
+        ix::approve_work(client.pubkey(), project, chosen.pubkey(), treasury),
+        &[&client],
+    );
+    assert_error(result, ApplicationError::WorkNotSubmitted);
+
+    submit_work(&mut env, &chosen, project).unwrap();
+    let before = env.lamports(&chosen.pubkey());
+    let client_before = env.lamports(&client.pubkey());
+    let project_rent = env.lamports(&project);
+    env.send(
+        ix::approve_work(client.pubkey(), project, chosen.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(
+        env.lamports(&chosen.pubkey()) - before,
+        5 * LAMPORTS_PER_SOL
+    );
+
+    // Approval closes the project and its escrow, returning both rents to the client
+    assert!(!env.exists(&project));
+    assert!(!env.exists(&pda::escrow(&project).0));
+    assert_eq!(
+        env.lamports(&client.pubkey()) - client_before,
+        project_rent + env.rent(0)
+    );
+    let application: Application = env.account(&pda::application(&chosen.pubkey(), &project).0);
+    assert!(application.status == ApplicationStatus::Approved);
+    assert!(application.work_status == WorkStatus::Approved);
+}
+
+#[test]
+fn rejected_work_cannot_be_approved() {
+    let mut env = TestEnv::initialized();
+    let treasury = env.treasury;
+    let client = env.client();
+    let user = env.user();
+    let project = post_project(&mut env, &client, 1);
+    apply(&mut env, &user, project).unwrap();
+    env.send(
+        ix::approve_application(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    submit_work(&mut env, &user, project).unwrap();
+
+    env.send(
+        ix::reject_work(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::approve_work(client.pubkey(), project, user.pubkey(), treasury),
+        &[&client],
+    );
+    assert_error(result, ApplicationError::WorkAlreadyRejected);
+}
+
+#[test]
+fn lapsed_approval_can_be_withdrawn_to_recover_escrow() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let project = post_project(&mut env, &client, 2);
+    apply(&mut env, &user, project).unwrap();
+    env.send(
+        ix::approve_application(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+
+    let result = env.send(
+        ix::unapprove_application(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    );
+    assert_error(result, ProjectError::ProjectDeadlineNotReached);
+
+    // Delivered work has to be reviewed before the approval can lapse
+    let deadline = env.account::<Project>(&project).deadline;
+    env.warp_to(deadline + 1);
+    submit_work(&mut env, &user, project).unwrap();
+    let result = env.send(
+        ix::unapprove_application(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    );
+    assert_error(result, ApplicationError::WorkAlreadySubmitted);
+    env.send(
+        ix::reject_work(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+
+    env.send(
+        ix::unapprove_application(client.pubkey(), project, user.pubkey()),
+        &[&client],
+    )
+    .unwrap();
+    let application: Application = env.account(&pda::application(&user.pubkey(), &project).0);
+    assert!(application.status == ApplicationStatus::Rejected);
+    assert_error(
+        submit_work(&mut env, &user, project),
+        ApplicationError::CannotSubmitWorkForNonApprovedApplication,
+    );
+
+    let before = env.lamports(&client.pubkey());
+    let escrow = env.lamports(&pda::escrow(&project).0);
+    env.send(ix::close_project(client.pubkey(), project), &[&client])
+        .unwrap();
+    assert!(env.lamports(&client.pubkey()) - before >= escrow);
+}
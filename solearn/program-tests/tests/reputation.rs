@@ -0,0 +1,224 @@
+use solana_signer::Signer;
+use solearn::errors::{BountyError, SubmissionError};
+use solearn::states::{
+    Bounty, Client, DisputeRuling, User, BOUNTY_EXPIRED_POINTS, BOUNTY_PAID_POINTS,
+    BOUNTY_WON_POINTS, DISPUTE_LOST_POINTS, REPUTATION_HALF_LIFE_SECONDS, REVIEW_WINDOW_SECONDS,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv};
+
+#[test]
+fn payouts_build_reputation_on_both_sides() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&winner, bounty).unwrap();
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, winner.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let now = env.now();
+    let profile: User = env.account(&pda::user(&winner.pubkey()).0);
+    assert_eq!(profile.reputation.current(now), BOUNTY_WON_POINTS as u64);
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(profile.reputation.current(now), BOUNTY_PAID_POINTS as u64);
+
+    // A five-star review adds two stars' worth, a one-star review takes two away
+    env.send(
+        ix::review_contributor(client.pubkey(), bounty, winner.pubkey(), 5, String::new()),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::review_client(winner.pubkey(), bounty, client.pubkey(), 1, String::new()),
+        &[&winner],
+    )
+    .unwrap();
+    let profile: User = env.account(&pda::user(&winner.pubkey()).0);
+    assert_eq!(profile.reputation.current(now), 120);
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(profile.reputation.current(now), 30);
+}
+
+#[test]
+fn reputation_decays_with_inactivity() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&winner, bounty).unwrap();
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, winner.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let profile: User = env.account(&pda::user(&winner.pubkey()).0);
+    let reputation = profile.reputation;
+    let start = reputation.updated_at;
+    assert_eq!(reputation.current(start + REPUTATION_HALF_LIFE_SECONDS), 50);
+    assert_eq!(
+        reputation.current(start + REPUTATION_HALF_LIFE_SECONDS * 3 / 2),
+        38
+    );
+    assert_eq!(
+        reputation.current(start + REPUTATION_HALF_LIFE_SECONDS * 64),
+        0
+    );
+}
+
+#[test]
+fn min_reputation_gates_submissions() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let veteran = env.user();
+    let newcomer = env.user();
+
+    let first = env.bounty(&client, 1);
+    env.submit(&veteran, first).unwrap();
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), first, veteran.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let bounty = env.bounty(&client, 1);
+    env.send(
+        ix::set_bounty_min_reputation(client.pubkey(), bounty, 80),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(env.account::<Bounty>(&bounty).min_reputation, 80);
+
+    assert_error(
+        env.submit(&newcomer, bounty),
+        SubmissionError::InsufficientReputation,
+    );
+    env.submit(&veteran, bounty).unwrap();
+
+    // Submitters were admitted under the current minimum
+    let result = env.send(
+        ix::set_bounty_min_reputation(client.pubkey(), bounty, 0),
+        &[&client],
+    );
+    assert_error(result, BountyError::CannotUpdateWithSubmissions);
+
+    // Half a year without activity halves the veteran's standing below the minimum
+    env.warp_by(REPUTATION_HALF_LIFE_SECONDS);
+    let other = env.bounty(&client, 1);
+    env.send(
+        ix::set_bounty_min_reputation(client.pubkey(), other, 80),
+        &[&client],
+    )
+    .unwrap();
+    assert_error(
+        env.submit(&veteran, other),
+        SubmissionError::InsufficientReputation,
+    );
+}
+
+#[test]
+fn lapsed_submissions_cost_the_client_on_expiry() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let cranker = env.keypair(1);
+    let quiet = env.bounty(&client, 1);
+    let lapsed = env.bounty(&client, 1);
+    env.submit(&user, lapsed).unwrap();
+
+    let deadline = env.account::<Bounty>(&lapsed).deadline;
+    env.warp_to(deadline + REVIEW_WINDOW_SECONDS + 1);
+
+    // Nothing was submitted, so nothing was left unpaid
+    env.send(
+        ix::expire_bounty(cranker.pubkey(), quiet, client.pubkey()),
+        &[&cranker],
+    )
+    .unwrap();
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(profile.reputation.score, 0);
+
+    let paid = env.bounty(&client, 1);
+    env.submit(&user, paid).unwrap();
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), paid, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::expire_bounty(cranker.pubkey(), lapsed, client.pubkey()),
+        &[&cranker],
+    )
+    .unwrap();
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(
+        profile.reputation.current(env.now()),
+        (BOUNTY_PAID_POINTS + BOUNTY_EXPIRED_POINTS) as u64
+    );
+}
+
+#[test]
+fn losing_a_dispute_costs_reputation() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let arbiter = env.keypair(1);
+    let treasury = env.treasury;
+
+    let won = env.bounty(&client, 1);
+    env.submit(&user, won).unwrap();
+    env.send(
+        ix::select_submission(client.pubkey(), won, user.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let disputed = env.bounty(&client, 1);
+    env.send(
+        ix::set_bounty_arbiters(client.pubkey(), disputed, vec![arbiter.pubkey()], 0),
+        &[&client],
+    )
+    .unwrap();
+    env.submit(&user, disputed).unwrap();
+    env.send(
+        ix::open_dispute(
+            user.pubkey(),
+            disputed,
+            user.pubkey(),
+            "https://evidence.example/0".to_string(),
+        ),
+        &[&user],
+    )
+    .unwrap();
+    env.send(
+        ix::cast_ruling(
+            arbiter.pubkey(),
+            disputed,
+            client.pubkey(),
+            user.pubkey(),
+            env.treasury,
+            DisputeRuling::Refund,
+            &[arbiter.pubkey()],
+        ),
+        &[&arbiter],
+    )
+    .unwrap();
+
+    let now = env.now();
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(
+        profile.reputation.current(now),
+        (BOUNTY_WON_POINTS + DISPUTE_LOST_POINTS) as u64
+    );
+    // Winning the dispute earns the client nothing beyond the refund
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!(profile.reputation.current(now), BOUNTY_PAID_POINTS as u64);
+}
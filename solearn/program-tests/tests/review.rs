@@ -0,0 +1,121 @@
+use solana_signer::Signer;
+use solearn::errors::ReviewError;
+use solearn::states::{Client, Review, ReviewerRole, User};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv};
+
+#[test]
+fn both_sides_review_a_completed_bounty() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&winner, bounty).unwrap();
+
+    let result = env.send(
+        ix::review_contributor(client.pubkey(), bounty, winner.pubkey(), 5, String::new()),
+        &[&client],
+    );
+    assert_error(result, ReviewError::BountyNotCompleted);
+
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, winner.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    env.send(
+        ix::review_contributor(
+            client.pubkey(),
+            bounty,
+            winner.pubkey(),
+            5,
+            "Fast and thorough".to_string(),
+        ),
+        &[&client],
+    )
+    .unwrap();
+    env.send(
+        ix::review_client(winner.pubkey(), bounty, client.pubkey(), 4, String::new()),
+        &[&winner],
+    )
+    .unwrap();
+
+    let review: Review = env.account(&pda::review(&bounty, &client.pubkey(), &winner.pubkey()).0);
+    assert!(review.reviewer_role == ReviewerRole::Client);
+    assert_eq!(review.reviewee, winner.pubkey());
+    assert_eq!(review.score, 5);
+    assert_eq!(review.comment, "Fast and thorough");
+
+    let profile: User = env.account(&pda::user(&winner.pubkey()).0);
+    assert_eq!((profile.rating_sum, profile.rating_count), (5, 1));
+    let profile: Client = env.account(&pda::client(&client.pubkey()).0);
+    assert_eq!((profile.rating_sum, profile.rating_count), (4, 1));
+
+    // One review per side
+    let result = env.send(
+        ix::review_contributor(client.pubkey(), bounty, winner.pubkey(), 1, String::new()),
+        &[&client],
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn only_winners_review_and_scores_are_bounded() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let runner_up = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&winner, bounty).unwrap();
+    env.submit(&runner_up, bounty).unwrap();
+    let treasury = env.treasury;
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, winner.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+
+    let result = env.send(
+        ix::review_client(
+            runner_up.pubkey(),
+            bounty,
+            client.pubkey(),
+            1,
+            String::new(),
+        ),
+        &[&runner_up],
+    );
+    assert_error(result, ReviewError::NotBountyWinner);
+    let result = env.send(
+        ix::review_contributor(
+            client.pubkey(),
+            bounty,
+            runner_up.pubkey(),
+            1,
+            String::new(),
+        ),
+        &[&client],
+    );
+    assert_error(result, ReviewError::NotBountyWinner);
+
+    for score in [0, 6] {
+        let result = env.send(
+            ix::review_client(
+                winner.pubkey(),
+                bounty,
+                client.pubkey(),
+                score,
+                String::new(),
+            ),
+            &[&winner],
+        );
+        assert_error(result, ReviewError::InvalidScore);
+    }
+    let result = env.send(
+        ix::review_client(winner.pubkey(), bounty, client.pubkey(), 3, "x".repeat(201)),
+        &[&winner],
+    );
+    assert_error(result, ReviewError::CommentTooLong);
+}
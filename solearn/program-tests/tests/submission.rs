@@ -0,0 +1,413 @@
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, ClientError, SubmissionError};
+use solearn::states::{
+    Bounty, PayoutPolicy, Submission, SubmissionStatus, User, MAX_SUBMISSION_REVISIONS,
+    REVIEW_WINDOW_SECONDS,
+};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY};
+
+fn revise(env: &mut TestEnv, user: &Keypair, bounty: Pubkey, n: u32) {
+    env.send(
+        ix::update_submission(
+            user.pubkey(),
+            bounty,
+            format!("Revision {n}"),
+            format!("https://github.com/acme/app/pull/{}", n + 1),
+        ),
+        &[user],
+    )
+    .unwrap();
+}
+
+#[test]
+fn revisions_keep_a_bounded_history() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+    let address = pda::submission(&user.pubkey(), &bounty).0;
+    let original: Submission = env.account(&address);
+
+    env.warp_by(60);
+    revise(&mut env, &user, bounty, 1);
+    let submission: Submission = env.account(&address);
+    assert_eq!(submission.description, "Revision 1");
+    assert_eq!(submission.work_url, "https://github.com/acme/app/pull/2");
+    assert_eq!(submission.submitted_at, original.submitted_at);
+    assert_eq!(submission.revisions.len(), 1);
+    assert_eq!(submission.revisions[0].work_url, original.work_url);
+    assert_eq!(submission.revisions[0].revised_at, env.now());
+
+    for n in 2..=MAX_SUBMISSION_REVISIONS as u32 + 1 {
+        revise(&mut env, &user, bounty, n);
+    }
+    // Only the latest links are kept, oldest first
+    let submission: Submission = env.account(&address);
+    assert_eq!(submission.revisions.len(), MAX_SUBMISSION_REVISIONS);
+    assert_eq!(
+        submission.revisions[0].work_url,
+        "https://github.com/acme/app/pull/3"
+    );
+
+    let result = env.send(
+        ix::update_submission(user.pubkey(), bounty, String::new(), String::new()),
+        &[&user],
+    );
+    assert_error(result, SubmissionError::InvalidSubmissionLink);
+}
+
+#[test]
+fn withdrawing_refunds_rent_and_allows_a_fresh_submission() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+    let address = pda::submission(&user.pubkey(), &bounty).0;
+
+    let rent = env.lamports(&address);
+    let before = env.lamports(&user.pubkey());
+    env.send(ix::withdraw_submission(user.pubkey(), bounty), &[&user])
+        .unwrap();
+    assert!(!env.exists(&address));
+    assert_eq!(env.lamports(&user.pubkey()), before + rent);
+    assert_eq!(env.account::<Bounty>(&bounty).no_of_submissions, 0);
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(profile.bounties_submitted, 0);
+
+    env.submit(&user, bounty).unwrap();
+    assert_eq!(env.account::<Bounty>(&bounty).no_of_submissions, 1);
+}
+
+#[test]
+fn changes_close_at_the_deadline() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+
+    env.warp_to(deadline);
+    revise(&mut env, &user, bounty, 1);
+
+    env.warp_to(deadline + 1);
+    let result = env.send(
+        ix::update_submission(
+            user.pubkey(),
+            bounty,
+            "Too late".to_string(),
+            "https://github.com/acme/app/pull/9".to_string(),
+        ),
+        &[&user],
+    );
+    assert_error(result, BountyError::BountyDeadlinePassed);
+    let result = env.send(ix::withdraw_submission(user.pubkey(), bounty), &[&user]);
+    assert_error(result, BountyError::BountyDeadlinePassed);
+}
+
+#[test]
+fn awarded_submissions_are_locked() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + DAY;
+    env.send(
+        ix::create_tiered_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Hackathon".to_string(),
+            "desc".to_string(),
+            vec![2, 1],
+            deadline,
+            vec![],
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let bounty = pda::bounty(&client.pubkey(), bounty_id).0;
+    env.submit(&winner, bounty).unwrap();
+    env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, winner.pubkey(), env.treasury, 0),
+        &[&client],
+    )
+    .unwrap();
+
+    // The bounty is still live for the second tier, but the paid work can't change
+    let result = env.send(
+        ix::update_submission(
+            winner.pubkey(),
+            bounty,
+            "Swapped".to_string(),
+            "https://github.com/acme/app/pull/9".to_string(),
+        ),
+        &[&winner],
+    );
+    assert_error(result, SubmissionError::SubmissionLocked);
+    let result = env.send(ix::withdraw_submission(winner.pubkey(), bounty), &[&winner]);
+    assert_error(result, SubmissionError::SubmissionLocked);
+}
+
+#[test]
+fn earliest_guaranteed_submission_stays_while_others_wait() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let first = env.user();
+    let second = env.user();
+    let deadline = env.now() + DAY;
+    let bounty = env.bounty_with_policy(
+        &client,
+        1,
+        deadline,
+        PayoutPolicy::GuaranteedToEarliest,
+        DAY,
+    );
+    env.submit(&first, bounty).unwrap();
+    env.submit(&second, bounty).unwrap();
+
+    let result = env.send(ix::withdraw_submission(first.pubkey(), bounty), &[&first]);
+    assert_error(result, SubmissionError::EarliestSubmissionPending);
+
+    // Once the later one leaves, the earliest can too and the bounty can expire
+    env.send(ix::withdraw_submission(second.pubkey(), bounty), &[&second])
+        .unwrap();
+    env.send(ix::withdraw_submission(first.pubkey(), bounty), &[&first])
+        .unwrap();
+    let state: Bounty = env.account(&bounty);
+    assert_eq!(state.no_of_submissions, 0);
+    assert_eq!(state.first_submission, Pubkey::default());
+}
+
+#[test]
+fn clients_review_submissions() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let other = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+    let address = pda::submission(&user.pubkey(), &bounty).0;
+    let review = |status, feedback: &str| {
+        ix::review_submission(
+            client.pubkey(),
+            bounty,
+            user.pubkey(),
+            status,
+            feedback.to_string(),
+        )
+    };
+
+    env.send(review(SubmissionStatus::Shortlisted, ""), &[&client])
+        .unwrap();
+    let submission: Submission = env.account(&address);
+    assert!(submission.status == SubmissionStatus::Shortlisted);
+    assert_eq!(submission.reviewed_at, env.now());
+
+    env.send(
+        review(SubmissionStatus::ChangesRequested, "Add a test"),
+        &[&client],
+    )
+    .unwrap();
+    let submission: Submission = env.account(&address);
+    assert!(submission.status == SubmissionStatus::ChangesRequested);
+    assert_eq!(submission.feedback, "Add a test");
+
+    // Revising answers the request
+    revise(&mut env, &user, bounty, 1);
+    let submission: Submission = env.account(&address);
+    assert!(submission.status == SubmissionStatus::Pending);
+
+    let result = env.send(review(SubmissionStatus::Winner, ""), &[&client]);
+    assert_error(result, SubmissionError::InvalidReviewStatus);
+    let result = env.send(
+        review(SubmissionStatus::Rejected, &"x".repeat(201)),
+        &[&client],
+    );
+    assert_error(result, SubmissionError::FeedbackTooLong);
+    let result = env.send(
+        ix::review_submission(
+            other.pubkey(),
+            bounty,
+            user.pubkey(),
+            SubmissionStatus::Rejected,
+            String::new(),
+        ),
+        &[&other],
+    );
+    assert_error(result, ClientError::NotAuthorizedForBounty);
+
+    env.send(
+        review(SubmissionStatus::Rejected, "Out of scope"),
+        &[&client],
+    )
+    .unwrap();
+    let result = env.send(
+        ix::update_submission(
+            user.pubkey(),
+            bounty,
+            "Please reconsider".to_string(),
+            "https://github.com/acme/app/pull/9".to_string(),
+        ),
+        &[&user],
+    );
+    assert_error(result, SubmissionError::SubmissionLocked);
+    // Withdrawing now would let the same wallet submit again
+    let result = env.send(ix::withdraw_submission(user.pubkey(), bounty), &[&user]);
+    assert_error(result, SubmissionError::SubmissionRejected);
+}
+
+#[test]
+fn requested_changes_stay_open_through_the_review_window() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let user = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&user, bounty).unwrap();
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+    let request_changes = ix::review_submission(
+        client.pubkey(),
+        bounty,
+        user.pubkey(),
+        SubmissionStatus::ChangesRequested,
+        "Add a test".to_string(),
+    );
+
+    env.warp_to(deadline + DAY);
+    env.send(request_changes.clone(), &[&client]).unwrap();
+    revise(&mut env, &user, bounty, 1);
+    let address = pda::submission(&user.pubkey(), &bounty).0;
+    assert!(env.account::<Submission>(&address).status == SubmissionStatus::Pending);
+
+    // Answered requests close again, as do requests once the review window ends
+    let result = env.send(
+        ix::update_submission(
+            user.pubkey(),
+            bounty,
+            "Another pass".to_string(),
+            "https://github.com/acme/app/pull/9".to_string(),
+        ),
+        &[&user],
+    );
+    assert_error(result, BountyError::BountyDeadlinePassed);
+    env.send(request_changes, &[&client]).unwrap();
+    env.warp_to(deadline + REVIEW_WINDOW_SECONDS + 1);
+    let result = env.send(
+        ix::update_submission(
+            user.pubkey(),
+            bounty,
+            "Too late".to_string(),
+            "https://github.com/acme/app/pull/9".to_string(),
+        ),
+        &[&user],
+    );
+    assert_error(result, BountyError::BountyDeadlinePassed);
+}
+
+#[test]
+fn rejected_submitters_reclaim_rent_after_the_bounty_closes() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let rejected = env.user();
+    let bounty = env.bounty(&client, 1);
+    env.submit(&winner, bounty).unwrap();
+    env.submit(&rejected, bounty).unwrap();
+    env.send(
+        ix::review_submission(
+            client.pubkey(),
+            bounty,
+            rejected.pubkey(),
+            SubmissionStatus::Rejected,
+            String::new(),
+        ),
+        &[&client],
+    )
+    .unwrap();
+
+    let deadline = env.account::<Bounty>(&bounty).deadline;
+    env.warp_to(deadline + 1);
+    let treasury = env.treasury;
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, rejected.pubkey(), treasury),
+        &[&client],
+    );
+    assert_error(result, SubmissionError::SubmissionRejected);
+    env.send(
+        ix::select_submission(client.pubkey(), bounty, winner.pubkey(), treasury),
+        &[&client],
+    )
+    .unwrap();
+    let submission: Submission = env.account(&pda::submission(&winner.pubkey(), &bounty).0);
+    assert!(submission.status == SubmissionStatus::Winner);
+
+    // Past the deadline and closed, only the rejected submission can still leave
+    let result = env.send(ix::withdraw_submission(winner.pubkey(), bounty), &[&winner]);
+    assert_error(result, BountyError::BountyAlreadyClosed);
+    env.send(
+        ix::withdraw_submission(rejected.pubkey(), bounty),
+        &[&rejected],
+    )
+    .unwrap();
+    assert!(!env.exists(&pda::submission(&rejected.pubkey(), &bounty).0));
+    // The closed bounty keeps its final submission count
+    assert_eq!(env.account::<Bounty>(&bounty).no_of_submissions, 2);
+}
+
+#[test]
+fn earliest_guaranteed_submission_cannot_be_rejected() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let first = env.user();
+    let second = env.user();
+    let deadline = env.now() + DAY;
+    let bounty = env.bounty_with_policy(
+        &client,
+        1,
+        deadline,
+        PayoutPolicy::GuaranteedToEarliest,
+        DAY,
+    );
+    env.submit(&first, bounty).unwrap();
+    env.submit(&second, bounty).unwrap();
+    let reject = |contributor: Pubkey| {
+        ix::review_submission(
+            client.pubkey(),
+            bounty,
+            contributor,
+            SubmissionStatus::Rejected,
+            String::new(),
+        )
+    };
+
+    let result = env.send(reject(first.pubkey()), &[&client]);
+    assert_error(result, SubmissionError::EarliestSubmissionGuaranteed);
+    env.send(reject(second.pubkey()), &[&client]).unwrap();
+}
+
+#[test]
+fn clients_cannot_submit_to_their_own_bounties() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let bounty = env.bounty(&client, 1);
+    // The same wallet can hold both a client and a contributor profile
+    env.send(
+        ix::create_user(
+            client.pubkey(),
+            "Self dealer".to_string(),
+            "self@acme.dev".to_string(),
+            vec!["rust".to_string()],
+        ),
+        &[&client],
+    )
+    .unwrap();
+
+    assert_error(
+        env.submit(&client, bounty),
+        SubmissionError::CannotSubmitToOwnBounty,
+    );
+}
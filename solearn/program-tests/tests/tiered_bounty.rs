@@ -0,0 +1,189 @@
+use anchor_lang::prelude::Pubkey;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, ConfigError, SubmissionError};
+use solearn::states::{Bounty, BountyCloseReason, PrizeTierStatus};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY, LAMPORTS_PER_SOL, MAX_DEADLINE_DURATION};
+
+fn post_tiered(
+    env: &mut TestEnv,
+    client: &Keypair,
+    prizes: Vec<u64>,
+) -> Result<Pubkey, solana_transaction_error::TransactionError> {
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + DAY;
+    env.send(
+        ix::create_tiered_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Hackathon".to_string(),
+            "desc".to_string(),
+            prizes,
+            deadline,
+            vec![],
+        ),
+        &[client],
+    )?;
+    Ok(pda::bounty(&client.pubkey(), bounty_id).0)
+}
+
+#[test]
+fn create_tiered_bounty_validates_prizes() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    assert_error(
+        post_tiered(&mut env, &client, vec![]).map(|_| ()),
+        BountyError::InvalidPrizeTiers,
+    );
+    assert_error(
+        post_tiered(&mut env, &client, vec![1; 6]).map(|_| ()),
+        BountyError::InvalidPrizeTiers,
+    );
+    assert_error(
+        post_tiered(&mut env, &client, vec![1, 2]).map(|_| ()),
+        BountyError::InvalidPrizeTiers,
+    );
+
+    let bounty = post_tiered(&mut env, &client, vec![3, 2, 1]).unwrap();
+    assert_eq!(
+        env.lamports(&pda::escrow(&bounty).0),
+        6 * LAMPORTS_PER_SOL + env.rent(0)
+    );
+}
+
+#[test]
+fn award_and_forfeit_tiers() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let first = env.user();
+    let second = env.user();
+    let bounty = post_tiered(&mut env, &client, vec![3, 1]).unwrap();
+    env.submit(&first, bounty).unwrap();
+    env.submit(&second, bounty).unwrap();
+
+    let result = env.send(
+        ix::select_submission(client.pubkey(), bounty, first.pubkey(), env.treasury),
+        &[&client],
+    );
+    assert_error(result, BountyError::TieredBountyRequiresTierAward);
+
+    let result = env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, first.pubkey(), env.treasury, 2),
+        &[&client],
+    );
+    assert_error(result, BountyError::PrizeTierNotFound);
+
+    let before = env.lamports(&first.pubkey());
+    env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, first.pubkey(), env.treasury, 0),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(env.lamports(&first.pubkey()) - before, 3 * LAMPORTS_PER_SOL);
+
+    let result = env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, second.pubkey(), env.treasury, 0),
+        &[&client],
+    );
+    assert_error(result, BountyError::PrizeTierAlreadySettled);
+    let result = env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, first.pubkey(), env.treasury, 1),
+        &[&client],
+    );
+    assert_error(result, SubmissionError::SubmissionAlreadyAwarded);
+
+    let result = env.send(
+        ix::forfeit_prize_tier(client.pubkey(), bounty, 1),
+        &[&client],
+    );
+    assert_error(result, BountyError::DeadlineNotReached);
+
+    env.warp_by(DAY + 1);
+    let before = env.lamports(&client.pubkey());
+    env.send(
+        ix::forfeit_prize_tier(client.pubkey(), bounty, 1),
+        &[&client],
+    )
+    .unwrap();
+    // The last tier closes the escrow and returns its rent too
+    assert_eq!(
+        env.lamports(&client.pubkey()) - before,
+        LAMPORTS_PER_SOL + env.rent(0)
+    );
+
+    let state: Bounty = env.account(&bounty);
+    assert!(state.prize_tiers[0].status == PrizeTierStatus::Awarded);
+    assert!(state.prize_tiers[1].status == PrizeTierStatus::Forfeited);
+    assert!(!state.live);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+    assert!(!env.exists(&pda::escrow(&bounty).0));
+}
+
+#[test]
+fn tiered_bounties_follow_platform_policy() {
+    let mut env = TestEnv::with_fee(500);
+    let client = env.client();
+    let winner = env.user();
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let post = |deadline| {
+        ix::create_tiered_bounty(
+            client.pubkey(),
+            bounty_id,
+            "Hackathon".to_string(),
+            "desc".to_string(),
+            vec![2, 1],
+            deadline,
+            vec![],
+        )
+    };
+
+    let now = env.now();
+    assert_error(
+        env.send(post(now), &[&client]),
+        BountyError::InvalidDeadline,
+    );
+    assert_error(
+        env.send(post(now + MAX_DEADLINE_DURATION + 1), &[&client]),
+        ConfigError::DeadlineBeyondMaximum,
+    );
+    env.send(post(now + DAY), &[&client]).unwrap();
+    let bounty = pda::bounty(&client.pubkey(), bounty_id).0;
+    assert_eq!(env.account::<Bounty>(&bounty).platform_fee_bps, 500);
+
+    // Each tier pays out less the fee fixed at posting time
+    env.submit(&winner, bounty).unwrap();
+    let before = env.lamports(&winner.pubkey());
+    let treasury_before = env.lamports(&env.treasury);
+    env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, winner.pubkey(), env.treasury, 0),
+        &[&client],
+    )
+    .unwrap();
+    let fee = 2 * LAMPORTS_PER_SOL * 500 / 10_000;
+    assert_eq!(env.lamports(&env.treasury) - treasury_before, fee);
+    assert_eq!(
+        env.lamports(&winner.pubkey()) - before,
+        2 * LAMPORTS_PER_SOL - fee
+    );
+}
+
+#[test]
+fn awarding_the_last_tier_closes_the_escrow() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let winner = env.user();
+    let bounty = post_tiered(&mut env, &client, vec![2]).unwrap();
+    env.submit(&winner, bounty).unwrap();
+
+    let before = env.lamports(&client.pubkey());
+    let treasury = env.treasury;
+    env.send(
+        ix::award_prize_tier(client.pubkey(), bounty, winner.pubkey(), treasury, 0),
+        &[&client],
+    )
+    .unwrap();
+    assert_eq!(env.lamports(&client.pubkey()) - before, env.rent(0));
+    assert!(!env.exists(&pda::escrow(&bounty).0));
+    assert!(!env.account::<Bounty>(&bounty).live);
+}
@@ -0,0 +1,321 @@
+use anchor_spl::token::spl_token;
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solearn::errors::{BountyError, DisputeError};
+use solearn::states::{Bounty, BountyCloseReason, User};
+use solearn_client::{instructions as ix, pda};
+use solearn_program_tests::{assert_error, TestEnv, DAY, MAX_DEADLINE_DURATION};
+
+const DECIMALS: u8 = 6;
+const UNIT: u64 = 1_000_000;
+
+struct TokenBounty {
+    client: Keypair,
+    mint: anchor_lang::prelude::Pubkey,
+    client_tokens: anchor_lang::prelude::Pubkey,
+    bounty: anchor_lang::prelude::Pubkey,
+}
+
+fn post_token_bounty(env: &mut TestEnv, reward: u64) -> TokenBounty {
+    let client = env.client();
+    let mint = env.mint(DECIMALS);
+    let client_tokens = env.token_account(&mint, &client.pubkey(), 100 * UNIT);
+    let bounty_id = env.next_bounty_id(&client.pubkey());
+    let deadline = env.now() + DAY;
+    env.send(
+        ix::create_token_bounty(
+            client.pubkey(),
+            bounty_id,
+            mint,
+            client_tokens,
+            spl_token::ID,
+            "Audit the vault".to_string(),
+            "desc".to_string(),
+            reward,
+            deadline,
+            vec![],
+        ),
+        &[&client],
+    )
+    .unwrap();
+    let bounty = pda::bounty(&client.pubkey(), bounty_id).0;
+    TokenBounty {
+        client,
+        mint,
+        client_tokens,
+        bounty,
+    }
+}
+
+#[test]
+fn create_token_bounty_escrows_tokens() {
+    let mut env = TestEnv::initialized();
+    let posted = post_token_bounty(&mut env, 40);
+
+    let escrow_tokens = pda::escrow_token(&posted.bounty).0;
+    assert_eq!(env.token_balance(&escrow_tokens), 40 * UNIT);
+    assert_eq!(env.token_balance(&posted.client_tokens), 60 * UNIT);
+    let state: Bounty = env.account(&posted.bounty);
+    assert_eq!(state.reward_mint, posted.mint);
+}
+
+#[test]
+fn token_rewards_are_not_held_to_the_sol_minimum() {
+    let mut env = TestEnv::initialized();
+    let authority = env.upgrade_authority.insecure_clone();
+    let treasury = env.treasury;
+    env.send(
+        ix::update_config(
+            authority.pubkey(),
+            authority.pubkey(),
+            0,
+            treasury,
+            50,
+            MAX_DEADLINE_DURATION,
+        ),
+        &[&authority],
+    )
+    .unwrap();
+
+    // 40 tokens is below a 50 SOL minimum, which says nothing about this mint
+    let posted = post_token_bounty(&mut env, 40);
+    let escrow_tokens = pda::escrow_token(&posted.bounty).0;
+    assert_eq!(env.token_balance(&escrow_tokens), 40 * UNIT);
+}
+
+#[test]
+fn create_token_bounty_checks_balance() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    let mint = env.mint(DECIMALS);
+    let client_tokens = env.token_account(&mint, &client.pubkey(), 5 * UNIT);
+    let deadline = env.now() + DAY;
+    let result = env.send(
+        ix::create_token_bounty(
+            client.pubkey(),
+            0,
+            mint,
+            client_tokens,
+            spl_token::ID,
+            "Audit the vault".to_string(),
+            "desc".to_string(),
+            6,
+            deadline,
+            vec![],
+        ),
+        &[&client],
+    );
+    assert_error(result, BountyError::InsufficientTokenBalance);
+}
+
+#[test]
+fn create_token_bounty_rejects_oversized_decimals() {
+    let mut env = TestEnv::initialized();
+    let client = env.client();
+    // 10^20 base units per token no longer fits in a u64
+    let mint = env.mint(20);
+    let client_tokens = env.token_account(&mint, &client.pubkey(), u64::MAX);
+    let deadline = env.now() + DAY;
+    let result = env.send(
+        ix::create_token_bounty(
+            client.pubkey(),
+            0,
+            mint,
+            client_tokens,
+            spl_token::ID,
+            "Audit the vault".to_string(),
+            "desc".to_string(),
+            1,
+            deadline,
+            vec![],
+        ),
+        &[&client],
+    );
+    assert_error(result, BountyError::InvalidRewardAmount);
+}
+
+#[test]
+fn select_token_submission_pays_winner_and_treasury() {
+    let mut env = TestEnv::with_fee(500);
+    let posted = post_token_bounty(&mut env, 40);
+    let user = env.user();
+    env.submit(&user, posted.bounty).unwrap();
+    let user_tokens = env.token_account(&posted.mint, &user.pubkey(), 0);
+    let treasury = env.treasury;
+    let treasury_tokens = env.token_account(&posted.mint, &treasury, 0);
+
+    // The winner's token account must belong to the winner
+    let result = env.send(
+        ix::select_token_submission(
+            posted.client.pubkey(),
+            posted.bounty,
+            user.pubkey(),
+            posted.mint,
+            posted.client_tokens,
+            treasury_tokens,
+            spl_token::ID,
+        ),
+        &[&posted.client],
+    );
+    assert!(result.is_err());
+
+    env.send(
+        ix::select_token_submission(
+            posted.client.pubkey(),
+            posted.bounty,
+            user.pubkey(),
+            posted.mint,
+            user_tokens,
+            treasury_tokens,
+            spl_token::ID,
+        ),
+        &[&posted.client],
+    )
+    .unwrap();
+
+    assert_eq!(env.token_balance(&user_tokens), 38 * UNIT);
+    assert_eq!(env.token_balance(&treasury_tokens), 2 * UNIT);
+    assert!(!env.exists(&pda::escrow_token(&posted.bounty).0));
+    let state: Bounty = env.account(&posted.bounty);
+    assert!(state.close_reason == BountyCloseReason::Rewarded);
+
+    // Token payouts count the completion but earn no reputation
+    let profile: User = env.account(&pda::user(&user.pubkey()).0);
+    assert_eq!(profile.bounties_completed, 1);
+    assert_eq!(profile.reputation.score, 0);
+}
+
+#[test]
+fn token_bounty_wins_do_not_attest_skills() {
+    let mut env = TestEnv::initialized();
+    let posted = post_token_bounty(&mut env, 40);
+    let user = env.user();
+    env.submit(&user, posted.bounty).unwrap();
+    let user_tokens = env.token_account(&posted.mint, &user.pubkey(), 0);
+    let treasury = env.treasury;
+    let treasury_tokens = env.token_account(&posted.mint, &treasury, 0);
+    env.send(
+        ix::select_token_submission(
+            posted.client.pubkey(),
+            posted.bounty,
+            user.pubkey(),
+            posted.mint,
+            user_tokens,
+            treasury_tokens,
+            spl_token::ID,
+        ),
+        &[&posted.client],
+    )
+    .unwrap();
+
+    let result = env.send(
+        ix::attest_from_bounty(user.pubkey(), posted.bounty, "rust".to_string()),
+        &[&user],
+    );
+    assert_error(result, BountyError::InvalidRewardMint);
+}
+
+#[test]
+fn delete_token_bounty_returns_tokens() {
+    let mut env = TestEnv::initialized();
+    let posted = post_token_bounty(&mut env, 40);
+
+    env.send(
+        ix::delete_token_bounty(
+            posted.client.pubkey(),
+            posted.bounty,
+            posted.mint,
+            posted.client_tokens,
+            spl_token::ID,
+        ),
+        &[&posted.client],
+    )
+    .unwrap();
+    assert_eq!(env.token_balance(&posted.client_tokens), 100 * UNIT);
+    assert!(!env.exists(&posted.bounty));
+}
+
+#[test]
+fn expire_token_bounty_after_review_window() {
+    let mut env = TestEnv::initialized();
+    let posted = post_token_bounty(&mut env, 40);
+    let cranker = env.keypair(1);
+    let deadline = env.account::<Bounty>(&posted.bounty).deadline;
+    let expire = ix::expire_token_bounty(
+        cranker.pubkey(),
+        posted.bounty,
+        posted.client.pubkey(),
+        posted.mint,
+        posted.client_tokens,
+        spl_token::ID,
+    );
+
+    env.warp_to(deadline + 7 * DAY);
+    assert_error(
+        env.send(expire.clone(), &[&cranker]),
+        BountyError::ReviewWindowNotElapsed,
+    );
+
+    env.warp_to(deadline + 7 * DAY + 1);
+    env.send(expire, &[&cranker]).unwrap();
+    assert_eq!(env.token_balance(&posted.client_tokens), 100 * UNIT);
+    let state: Bounty = env.account(&posted.bounty);
+    assert!(state.close_reason == BountyCloseReason::Expired);
+}
+
+#[test]
+fn disputed_token_bounties_are_frozen() {
+    let mut env = TestEnv::initialized();
+    let posted = post_token_bounty(&mut env, 40);
+    let user = env.user();
+    env.submit(&user, posted.bounty).unwrap();
+    let user_tokens = env.token_account(&posted.mint, &user.pubkey(), 0);
+    let treasury = env.treasury;
+    let treasury_tokens = env.token_account(&posted.mint, &treasury, 0);
+    let cranker = env.keypair(1);
+
+    let mut state: Bounty = env.account(&posted.bounty);
+    state.disputed = true;
+    env.overwrite(posted.bounty, &state);
+
+    let result = env.send(
+        ix::select_token_submission(
+            posted.client.pubkey(),
+            posted.bounty,
+            user.pubkey(),
+            posted.mint,
+            user_tokens,
+            treasury_tokens,
+            spl_token::ID,
+        ),
+        &[&posted.client],
+    );
+    assert_error(result, DisputeError::EscrowFrozen);
+    let result = env.send(
+        ix::delete_token_bounty(
+            posted.client.pubkey(),
+            posted.bounty,
+            posted.mint,
+            posted.client_tokens,
+            spl_token::ID,
+        ),
+        &[&posted.client],
+    );
+    assert_error(result, DisputeError::EscrowFrozen);
+
+    let deadline = state.deadline;
+    env.warp_to(deadline + 7 * DAY + 1);
+    let result = env.send(
+        ix::expire_token_bounty(
+            cranker.pubkey(),
+            posted.bounty,
+            posted.client.pubkey(),
+            posted.mint,
+            posted.client_tokens,
+            spl_token::ID,
+        ),
+        &[&cranker],
+    );
+    assert_error(result, DisputeError::EscrowFrozen);
+    assert_eq!(env.token_balance(&posted.client_tokens), 60 * UNIT);
+}
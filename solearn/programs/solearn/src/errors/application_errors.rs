@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+#[error_code(offset = 6400)]
 pub enum ApplicationError {
     #[msg("Application is already approved")]
     ApplicationAlreadyApproved,
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+#[error_code(offset = 6900)]
+pub enum AttestationError {
+    #[msg("Signer is not an approved attestation issuer")]
+    NotAnIssuer,
+    #[msg("Invalid skill")]
+    InvalidSkill,
+    #[msg("Attestation expiry must be in the future")]
+    InvalidExpiry,
+    #[msg("Attestation has already been revoked")]
+    AttestationAlreadyRevoked,
+    #[msg("Signer cannot revoke this attestation")]
+    NotAuthorizedToRevoke,
+    #[msg("Bounty did not require this skill")]
+    SkillNotRequiredByBounty,
+    #[msg("Submission was not rewarded by this bounty")]
+    NotBountyWinner,
+    #[msg("Attestation does not belong to the submitting contributor")]
+    InvalidAttestation,
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+#[error_code(offset = 6000)]
 pub enum BountyError {
     #[msg("Bounty is already closed")]
     BountyAlreadyClosed,
@@ -24,4 +24,44 @@ pub enum BountyError {
     EscrowAccountNotFound,
     #[msg("Invalid escrow account")]
     InvalidEscrowAccount,
+    #[msg("Insufficient token balance for reward")]
+    InsufficientTokenBalance,
+    #[msg("Reward mint does not match bounty")]
+    InvalidRewardMint,
+    #[msg("Prize tiers must be non-empty, ranked and within the tier limit")]
+    InvalidPrizeTiers,
+    #[msg("Prize tier does not exist")]
+    PrizeTierNotFound,
+    #[msg("Prize tier has already been awarded or forfeited")]
+    PrizeTierAlreadySettled,
+    #[msg("Bounty does not have prize tiers")]
+    NotATieredBounty,
+    #[msg("Tiered bounties must be paid out per tier")]
+    TieredBountyRequiresTierAward,
+    #[msg("Bounty deadline has not been reached")]
+    DeadlineNotReached,
+    #[msg("Review window after the deadline has not elapsed")]
+    ReviewWindowNotElapsed,
+    #[msg("Bounty has an engaged contributor")]
+    BountyHasEngagedContributor,
+    #[msg("Bounty does not guarantee a payout")]
+    NotGuaranteedPayout,
+    #[msg("Bounty has a guaranteed payout pending")]
+    GuaranteedPayoutPending,
+    #[msg("Invalid bounty title")]
+    InvalidTitle,
+    #[msg("Bounty is already addressed by id")]
+    BountyAlreadyMigrated,
+    #[msg("Skill gate needs required skills and a count between 1 and their number")]
+    InvalidSkillGate,
+    #[msg("Bounty account is already up to date")]
+    BountyLayoutCurrent,
+    #[msg("Bounty is still addressed by its title and must be migrated with it")]
+    BountyNeedsId,
+    #[msg("Bounty has no payout held")]
+    NoPayoutHeld,
+    #[msg("The selection's dispute window has not closed")]
+    DisputeWindowOpen,
+    #[msg("Payout amount overflowed")]
+    PayoutOverflow,
 }
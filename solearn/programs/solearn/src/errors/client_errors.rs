@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+#[error_code(offset = 6200)]
 pub enum ClientError {
     #[msg("Client already exists")]
     ClientAlreadyExists,
@@ -22,4 +22,12 @@ pub enum ClientError {
     NoBounties,
     #[msg("Client is not authorized for this bounty")]
     NotAuthorizedForBounty,
+    #[msg("Client account is already up to date")]
+    ClientAlreadyMigrated,
+    #[msg("Invalid social links")]
+    InvalidSocials,
+    #[msg("Client still has live bounties or projects")]
+    HasLiveListings,
+    #[msg("Client profile has been deleted")]
+    ClientDeleted,
 }
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[error_code(offset = 6800)]
+pub enum ConfigError {
+    #[msg("Signer is not the program upgrade authority")]
+    NotUpgradeAuthority,
+    #[msg("Signer is not the platform authority")]
+    NotPlatformAuthority,
+    #[msg("Platform fee exceeds the maximum")]
+    PlatformFeeTooHigh,
+    #[msg("Invalid treasury account")]
+    InvalidTreasury,
+    #[msg("Invalid maximum deadline duration")]
+    InvalidMaxDeadlineDuration,
+    #[msg("Reward is below the platform minimum")]
+    RewardBelowMinimum,
+    #[msg("Deadline is beyond the platform maximum")]
+    DeadlineBeyondMaximum,
+    #[msg("Signer is not the platform guardian")]
+    NotGuardian,
+    #[msg("Invalid guardian account")]
+    InvalidGuardian,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Instruction group is paused")]
+    InstructionGroupPaused,
+    #[msg("Pause state is already set")]
+    PauseStateUnchanged,
+    #[msg("Invalid platform authority")]
+    InvalidAuthority,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+#[error_code(offset = 6700)]
+pub enum DisputeError {
+    #[msg("Bounty escrow is frozen by an open dispute")]
+    EscrowFrozen,
+    #[msg("Bounty has no arbiters designated")]
+    NoArbitersDesignated,
+    #[msg("Arbiter panel is invalid")]
+    InvalidArbiters,
+    #[msg("Arbiters cannot submit to bounties they arbitrate")]
+    ArbiterCannotSubmit,
+    #[msg("Arbiter fee exceeds the maximum")]
+    ArbiterFeeTooHigh,
+    #[msg("Arbiters can only be changed before the first submission")]
+    ArbitersLocked,
+    #[msg("Only SOL bounties without prize tiers can be disputed")]
+    DisputeNotSupported,
+    #[msg("Signer is not a party to this dispute")]
+    NotDisputeParty,
+    #[msg("Signer is not an arbiter for this dispute")]
+    NotArbiter,
+    #[msg("Arbiter has already voted")]
+    AlreadyVoted,
+    #[msg("Dispute is already resolved")]
+    DisputeAlreadyResolved,
+    #[msg("Evidence link is invalid")]
+    InvalidEvidenceLink,
+    #[msg("Evidence limit reached")]
+    EvidenceLimitReached,
+    #[msg("Invalid split ruling")]
+    InvalidSplit,
+    #[msg("Arbiter wallet accounts do not match the majority")]
+    InvalidArbiterWallets,
+    #[msg("The panel's ruling period has not elapsed")]
+    RulingPeriodNotElapsed,
+    #[msg("A guaranteed payout cannot be disputed by the client after the deadline")]
+    GuaranteedPayoutLocked,
+    #[msg("The window to dispute this selection has closed")]
+    DisputeWindowClosed,
+    #[msg("Only a contributor passed over by the selection can dispute it")]
+    NotPassedOver,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[error_code(offset = 6600)]
+pub enum MilestoneError {
+    #[msg("Milestones must be non-empty and within the milestone limit")]
+    InvalidMilestones,
+    #[msg("Milestone deliverable is invalid")]
+    InvalidDeliverable,
+    #[msg("Milestone does not exist")]
+    MilestoneNotFound,
+    #[msg("Bounty does not have milestones")]
+    NotAMilestoneBounty,
+    #[msg("Milestone bounties must be released per milestone")]
+    MilestoneBountyRequiresApproval,
+    #[msg("A contributor has already been engaged for this bounty")]
+    ContributorAlreadyEngaged,
+    #[msg("No contributor has been engaged for this bounty")]
+    NoContributorEngaged,
+    #[msg("Signer is not the engaged contributor")]
+    NotEngagedContributor,
+    #[msg("Milestone work link is invalid")]
+    InvalidWorkLink,
+    #[msg("Milestone is not open for submission")]
+    MilestoneNotPending,
+    #[msg("Milestone has no work awaiting review")]
+    MilestoneNotSubmitted,
+    #[msg("Milestone work is awaiting review")]
+    MilestoneAwaitingReview,
+    #[msg("The client's review period for this milestone has not elapsed")]
+    MilestoneReviewPeriodNotElapsed,
+}
@@ -1,9 +1,15 @@
+// Each domain's codes start at its own offset, 100 apart, so a code maps back to one enum
 pub mod bounty_errors;
 pub mod user_errors;
 pub mod client_errors;
 pub mod submission_errors;
 pub mod application_errors;
 pub mod project_errors;
+pub mod milestone_errors;
+pub mod dispute_errors;
+pub mod config_errors;
+pub mod attestation_errors;
+pub mod review_errors;
 
 pub use bounty_errors::*;
 pub use user_errors::*;
@@ -11,3 +17,8 @@ pub use client_errors::*;
 pub use submission_errors::*;
 pub use application_errors::*;
 pub use project_errors::*;
+pub use milestone_errors::*;
+pub use dispute_errors::*;
+pub use config_errors::*;
+pub use attestation_errors::*;
+pub use review_errors::*;
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+#[error_code(offset = 6500)]
 pub enum ProjectError {
     #[msg("Project is already closed")]
     ProjectAlreadyClosed,
@@ -20,7 +20,7 @@ pub enum ProjectError {
     InvalidDeadline,
     #[msg("Insufficient SOL balance for reward")]
     InsufficientSolBalance,
-    #[msg("Project title is empty")]
+    #[msg("Project title must be between 1 and 32 bytes")]
     InvalidProjectTitle,
     #[msg("Project description is empty")]
     InvalidProjectDescription,
@@ -32,4 +32,8 @@ pub enum ProjectError {
     ProjectNotFound,
     #[msg("Invalid project status")]
     InvalidProjectStatus,
+    #[msg("Project has an approved application")]
+    ProjectHasApprovedApplication,
+    #[msg("Project deadline has not passed yet")]
+    ProjectDeadlineNotReached,
 }
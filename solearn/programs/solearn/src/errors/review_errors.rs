@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[error_code(offset = 7000)]
+pub enum ReviewError {
+    #[msg("Bounty has not been completed")]
+    BountyNotCompleted,
+    #[msg("Submission was not rewarded by this bounty")]
+    NotBountyWinner,
+    #[msg("Review score must be between 1 and 5")]
+    InvalidScore,
+    #[msg("Review comment is too long")]
+    CommentTooLong,
+}
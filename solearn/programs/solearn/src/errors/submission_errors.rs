@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+#[error_code(offset = 6300)]
 pub enum SubmissionError {
     #[msg("Submission already exists")]
     SubmissionAlreadyExists,
@@ -20,4 +20,26 @@ pub enum SubmissionError {
     AlreadySubmitted,
     #[msg("Submission is not for the specified bounty")]
     WrongBounty,
+    #[msg("Submission has already been awarded a prize tier")]
+    SubmissionAlreadyAwarded,
+    #[msg("Submission is not the earliest for this bounty")]
+    NotEarliestSubmission,
+    #[msg("User does not have enough reputation for this bounty")]
+    InsufficientReputation,
+    #[msg("Submission account is already up to date")]
+    SubmissionAlreadyMigrated,
+    #[msg("Submission has been picked or rejected and can no longer change")]
+    SubmissionLocked,
+    #[msg("The earliest submission to a guaranteed bounty can't be withdrawn while others are pending")]
+    EarliestSubmissionPending,
+    #[msg("Submissions can only be shortlisted, sent back for changes or rejected")]
+    InvalidReviewStatus,
+    #[msg("Submission feedback is too long")]
+    FeedbackTooLong,
+    #[msg("The earliest submission to a guaranteed bounty can't be rejected")]
+    EarliestSubmissionGuaranteed,
+    #[msg("Clients can't submit to their own bounties")]
+    CannotSubmitToOwnBounty,
+    #[msg("Submission has been rejected")]
+    SubmissionRejected,
 }
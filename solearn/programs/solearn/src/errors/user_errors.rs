@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-#[error_code]
+#[error_code(offset = 6100)]
 pub enum UserError {
     #[msg("User already exists")]
     UserAlreadyExists,
@@ -22,4 +22,12 @@ pub enum UserError {
     NoSubmissions,
     #[msg("Invalid bio format")]
     InvalidBioFormat,
+    #[msg("Username already taken")]
+    UsernameAlreadyTaken,
+    #[msg("Invalid social links")]
+    InvalidSocials,
+    #[msg("User account is already up to date")]
+    UserAlreadyMigrated,
+    #[msg("User profile has been deleted")]
+    UserDeleted,
 }
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ApplicationSubmitted {
+    pub application: Pubkey,
+    pub project: Pubkey,
+    pub user_wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ApplicationApproved {
+    pub application: Pubkey,
+    pub project: Pubkey,
+    pub user_wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ApplicationRejected {
+    pub application: Pubkey,
+    pub project: Pubkey,
+    pub user_wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct WorkSubmitted {
+    pub application: Pubkey,
+    pub project: Pubkey,
+    pub work_link: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct WorkApproved {
+    pub application: Pubkey,
+    pub project: Pubkey,
+    pub user_wallet: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct WorkRejected {
+    pub application: Pubkey,
+    pub project: Pubkey,
+    pub timestamp: u64,
+}
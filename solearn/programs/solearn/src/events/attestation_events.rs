@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::states::AttestationSource;
+
+#[event]
+pub struct IssuerApproved {
+    pub wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct IssuerRemoved {
+    pub wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct AttestationIssued {
+    pub attestation: Pubkey,
+    pub user_wallet: Pubkey,
+    pub issuer: Pubkey,
+    pub source: AttestationSource,
+    pub skill: String,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct AttestationRevoked {
+    pub attestation: Pubkey,
+    pub revoked_by: Pubkey,
+    pub timestamp: u64,
+}
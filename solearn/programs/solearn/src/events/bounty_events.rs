@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::states::{PayoutPolicy, SkillGate};
+
+// Amounts are in lamports, or in the reward mint's base units for token bounties
+
+#[event]
+pub struct BountyCreated {
+    pub bounty: Pubkey,
+    pub bounty_id: u64,
+    pub client: Pubkey,
+    pub creator: Pubkey,
+    pub title: String,
+    // Whole SOL or whole tokens, as stored on the bounty
+    pub reward: u64,
+    pub reward_mint: Pubkey,
+    pub deadline: u64,
+    pub payout_policy: PayoutPolicy,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountyUpdated {
+    pub bounty: Pubkey,
+    pub title: String,
+    pub deadline: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountyDeleted {
+    pub bounty: Pubkey,
+    pub client: Pubkey,
+    pub refunded: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountyMigrated {
+    pub bounty: Pubkey,
+    pub bounty_id: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountyExpired {
+    pub bounty: Pubkey,
+    pub cranker: Pubkey,
+    pub refunded: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountyArbitersSet {
+    pub bounty: Pubkey,
+    pub arbiters: Vec<Pubkey>,
+    pub arbiter_fee_bps: u16,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountySkillGateSet {
+    pub bounty: Pubkey,
+    pub skill_gate: SkillGate,
+    pub attested_skills: bool,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BountyMinReputationSet {
+    pub bounty: Pubkey,
+    pub min_reputation: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PrizeTierAwarded {
+    pub bounty: Pubkey,
+    pub tier_index: u8,
+    pub submission: Pubkey,
+    pub winner: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PrizeTierForfeited {
+    pub bounty: Pubkey,
+    pub tier_index: u8,
+    pub refunded: u64,
+    pub timestamp: u64,
+}
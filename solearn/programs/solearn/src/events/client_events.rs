@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClientCreated {
+    pub client: Pubkey,
+    pub authority: Pubkey,
+    pub company_name: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ClientUpdated {
+    pub client: Pubkey,
+    pub authority: Pubkey,
+    pub company_name: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ClientDeleted {
+    pub client: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ClientMigrated {
+    pub client: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: u64,
+}
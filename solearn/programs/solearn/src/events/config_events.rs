@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::states::PauseGroup;
+
+#[event]
+pub struct ConfigInitialized {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    pub min_reward: u64,
+    pub max_deadline_duration: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    pub min_reward: u64,
+    pub max_deadline_duration: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct GuardianUpdated {
+    pub previous_guardian: Pubkey,
+    pub new_guardian: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct PauseStateChanged {
+    pub guardian: Pubkey,
+    // None when the global pause flag changed
+    pub group: Option<PauseGroup>,
+    pub paused: bool,
+    pub timestamp: u64,
+}
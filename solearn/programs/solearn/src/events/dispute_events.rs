@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::states::DisputeRuling;
+
+#[event]
+pub struct DisputeOpened {
+    pub dispute: Pubkey,
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub opened_by: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct EvidenceSubmitted {
+    pub dispute: Pubkey,
+    pub submitted_by: Pubkey,
+    pub evidence_link: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct RulingCast {
+    pub dispute: Pubkey,
+    pub arbiter: Pubkey,
+    pub ruling: DisputeRuling,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub dispute: Pubkey,
+    pub bounty: Pubkey,
+    pub ruling: DisputeRuling,
+    pub contributor_amount: u64,
+    pub client_amount: u64,
+    pub arbiter_fees: u64,
+    pub platform_fee: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct DisputeLapsed {
+    pub dispute: Pubkey,
+    pub bounty: Pubkey,
+    pub timestamp: u64,
+}
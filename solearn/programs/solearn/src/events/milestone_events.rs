@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ContributorEngaged {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub contributor: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MilestoneSubmitted {
+    pub bounty: Pubkey,
+    pub milestone_index: u8,
+    pub work_url: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MilestoneReviewed {
+    pub bounty: Pubkey,
+    pub milestone_index: u8,
+    pub approved: bool,
+    // Lamports released to the contributor, zero when rejected
+    pub amount: u64,
+    pub platform_fee: u64,
+    // Released by the contributor after the client let the review period lapse
+    pub auto_released: bool,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct MilestoneEngagementEnded {
+    pub bounty: Pubkey,
+    pub refunded: u64,
+    pub timestamp: u64,
+}
@@ -0,0 +1,23 @@
+pub mod user_events;
+pub mod client_events;
+pub mod bounty_events;
+pub mod submission_events;
+pub mod milestone_events;
+pub mod dispute_events;
+pub mod project_events;
+pub mod application_events;
+pub mod config_events;
+pub mod attestation_events;
+pub mod review_events;
+
+pub use user_events::*;
+pub use client_events::*;
+pub use bounty_events::*;
+pub use submission_events::*;
+pub use milestone_events::*;
+pub use dispute_events::*;
+pub use project_events::*;
+pub use application_events::*;
+pub use config_events::*;
+pub use attestation_events::*;
+pub use review_events::*;
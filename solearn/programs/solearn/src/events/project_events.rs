@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProjectCreated {
+    pub project: Pubkey,
+    pub project_id: u64,
+    pub client: Pubkey,
+    pub creator: Pubkey,
+    pub title: String,
+    pub reward: u64,
+    pub deadline: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ProjectUpdated {
+    pub project: Pubkey,
+    pub deadline: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ProjectClosed {
+    pub project: Pubkey,
+    pub refunded: u64,
+    pub timestamp: u64,
+}
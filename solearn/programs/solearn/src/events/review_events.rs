@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use crate::states::ReviewerRole;
+
+#[event]
+pub struct ReviewSubmitted {
+    pub review: Pubkey,
+    pub bounty: Pubkey,
+    pub reviewer: Pubkey,
+    pub reviewee: Pubkey,
+    pub reviewer_role: ReviewerRole,
+    pub score: u8,
+    pub timestamp: u64,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::states::SubmissionStatus;
+
+#[event]
+pub struct SubmissionCreated {
+    pub submission: Pubkey,
+    pub bounty: Pubkey,
+    pub user_wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SubmissionSelected {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub winner: Pubkey,
+    pub reward_mint: Pubkey,
+    // Paid to the winner, after the platform fee
+    pub amount: u64,
+    pub platform_fee: u64,
+    // Set when a cranker released the payout, rather than the client at selection
+    pub auto_released: bool,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SelectionHeld {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub winner: Pubkey,
+    // When the payout can be released, unless the pick is disputed
+    pub releases_at: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SubmissionMigrated {
+    pub submission: Pubkey,
+    pub bounty: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SubmissionUpdated {
+    pub submission: Pubkey,
+    pub bounty: Pubkey,
+    pub work_url: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SubmissionWithdrawn {
+    pub submission: Pubkey,
+    pub bounty: Pubkey,
+    pub user_wallet: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct SubmissionReviewed {
+    pub submission: Pubkey,
+    pub bounty: Pubkey,
+    pub status: SubmissionStatus,
+    pub feedback: String,
+    pub timestamp: u64,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct UserCreated {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct UserUpdated {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct UserDeleted {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct UserMigrated {
+    pub user: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: u64,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::states::{Application, ApplicationStatus, Project, ProjectStatus, User, WorkStatus};
+use crate::errors::{ApplicationError, ProjectError, UserError};
+use crate::events::ApplicationSubmitted;
+
+pub fn apply_to_project(
+    ctx: Context<ApplyToProject>,
+    description: String,
+    link: String,
+) -> Result<()> {
+    let application = &mut ctx.accounts.application;
+    let project = &mut ctx.accounts.project;
+    let user = &ctx.accounts.user;
+    let clock = Clock::get()?;
+
+    require!(!user.is_deleted(), UserError::UserDeleted);
+    require!(
+        clock.unix_timestamp as u64 <= project.deadline,
+        ApplicationError::ApplicationDeadlinePassed
+    );
+    require!(
+        project.approved_application == Pubkey::default(),
+        ProjectError::ProjectNotAcceptingApplications
+    );
+    require!(description.len() <= 500, ApplicationError::ApplicationDescriptionTooLong);
+    require!(!link.is_empty(), ApplicationError::InvalidApplicationLink);
+    require!(link.len() <= 280, ApplicationError::InvalidApplicationLink);
+
+    application.user_wallet_key = ctx.accounts.authority.key();
+    application.user_key = user.key();
+    application.project_key = project.key();
+    application.description = description;
+    application.link = link;
+    application.status = ApplicationStatus::Pending;
+    application.applied_at = clock.unix_timestamp as u64;
+    application.work_description = String::new();
+    application.work_link = String::new();
+    application.additional_files_link = String::new();
+    application.work_status = WorkStatus::NotSubmitted;
+    application.work_submitted_at = 0;
+    application.bump = ctx.bumps.application;
+
+    project.no_of_applications = project.no_of_applications.checked_add(1).unwrap();
+
+    emit!(ApplicationSubmitted {
+        application: application.key(),
+        project: project.key(),
+        user_wallet: application.user_wallet_key,
+        timestamp: application.applied_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyToProject<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), project.creator_wallet_key.as_ref()],
+        bump = project.bump,
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectNotAcceptingApplications,
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Application::INIT_SPACE,
+        seeds = [b"application", authority.key().as_ref(), project.key().as_ref()],
+        bump,
+    )]
+    pub application: Account<'info, Application>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub mod apply_to_project;
+pub mod review_application;
+pub mod review_work;
+pub mod submit_work;
+
+pub use apply_to_project::*;
+pub use review_application::*;
+pub use review_work::*;
+pub use submit_work::*;
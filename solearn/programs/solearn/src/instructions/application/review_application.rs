@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::states::{Application, ApplicationStatus, Client, Project, ProjectStatus, WorkStatus};
+use crate::errors::{ApplicationError, ProjectError};
+use crate::events::{ApplicationApproved, ApplicationRejected};
+
+pub fn approve_application(ctx: Context<ReviewApplication>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let application = &mut ctx.accounts.application;
+    let clock = Clock::get()?;
+
+    require!(
+        application.status == ApplicationStatus::Pending,
+        ApplicationError::CannotApproveNonPending
+    );
+    // A project is contracted to a single applicant
+    require!(
+        project.approved_application == Pubkey::default(),
+        ProjectError::ProjectHasApprovedApplication
+    );
+
+    application.status = ApplicationStatus::Approved;
+    project.approved_application = application.key();
+    project.approved_user_wallet_key = application.user_wallet_key;
+
+    emit!(ApplicationApproved {
+        application: application.key(),
+        project: project.key(),
+        user_wallet: application.user_wallet_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+pub fn reject_application(ctx: Context<ReviewApplication>) -> Result<()> {
+    let application = &mut ctx.accounts.application;
+
+    require!(
+        application.status == ApplicationStatus::Pending,
+        ApplicationError::CannotRejectNonPending
+    );
+    let clock = Clock::get()?;
+
+    application.status = ApplicationStatus::Rejected;
+
+    emit!(ApplicationRejected {
+        application: application.key(),
+        project: application.project_key,
+        user_wallet: application.user_wallet_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+// Frees a project whose approved applicant let the deadline pass without delivering,
+// so the client can pick someone else or close it and recover the escrow
+pub fn unapprove_application(ctx: Context<ReviewApplication>) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let application = &mut ctx.accounts.application;
+    let clock = Clock::get()?;
+
+    require!(
+        project.approved_application == application.key(),
+        ApplicationError::InvalidApplicationStatus
+    );
+    require!(
+        clock.unix_timestamp as u64 > project.deadline,
+        ProjectError::ProjectDeadlineNotReached
+    );
+    // Delivered work has to be reviewed first
+    require!(
+        application.work_status != WorkStatus::Submitted,
+        ApplicationError::WorkAlreadySubmitted
+    );
+
+    application.status = ApplicationStatus::Rejected;
+    project.approved_application = Pubkey::default();
+    project.approved_user_wallet_key = Pubkey::default();
+
+    emit!(ApplicationRejected {
+        application: application.key(),
+        project: project.key(),
+        user_wallet: application.user_wallet_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewApplication<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), project.creator_wallet_key.as_ref()],
+        bump = project.bump,
+        constraint = project.client_key == client.key(),
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectAlreadyClosed,
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"application", application.user_wallet_key.as_ref(), project.key().as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+}
@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use crate::states::{Application, ApplicationStatus, Client, PauseGroup, PlatformConfig, Project, ProjectStatus, User, WorkStatus};
+use crate::errors::{ApplicationError, ConfigError, ProjectError};
+use crate::events::{WorkApproved, WorkRejected};
+
+pub fn approve_work(ctx: Context<ApproveWork>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let project = &mut ctx.accounts.project;
+    let client = &mut ctx.accounts.client;
+    let applicant = &mut ctx.accounts.applicant;
+    let application = &mut ctx.accounts.application;
+    let clock = Clock::get()?;
+
+    match application.work_status {
+        WorkStatus::Submitted => {}
+        WorkStatus::Approved => return err!(ApplicationError::WorkAlreadyApproved),
+        WorkStatus::Rejected => return err!(ApplicationError::WorkAlreadyRejected),
+        WorkStatus::NotSubmitted => return err!(ApplicationError::WorkNotSubmitted),
+    }
+
+    let reward_lamports = project
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(ProjectError::InvalidRewardAmount)?;
+
+    // Transfer all SOL from escrow to the applicant, except the rent-exempt minimum
+    let rent = &Rent::get()?;
+    let rent_exempt_minimum = rent.minimum_balance(0);
+    let escrow_balance = ctx.accounts.escrow_account.to_account_info().lamports();
+    let transfer_amount = escrow_balance.saturating_sub(rent_exempt_minimum);
+
+    // Platform fee rate was fixed when the project was posted
+    let fee_lamports = project.platform_fee(reward_lamports).min(transfer_amount);
+
+    // The project closes with the approval, so the escrow's rent goes back to the client
+    **ctx.accounts.escrow_account.to_account_info().try_borrow_mut_lamports()? = 0;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += fee_lamports;
+    **ctx.accounts.applicant_wallet.to_account_info().try_borrow_mut_lamports()? += transfer_amount - fee_lamports;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += escrow_balance - transfer_amount;
+
+    application.work_status = WorkStatus::Approved;
+
+    project.status = ProjectStatus::Closed;
+    client.project_closed();
+    project.project_rewarded = true;
+
+    applicant.earned += reward_lamports - fee_lamports;
+    client.rewarded += reward_lamports;
+
+    emit!(WorkApproved {
+        application: application.key(),
+        project: project.key(),
+        user_wallet: application.user_wallet_key,
+        amount: transfer_amount - fee_lamports,
+        platform_fee: fee_lamports,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+pub fn reject_work(ctx: Context<RejectWork>) -> Result<()> {
+    let application = &mut ctx.accounts.application;
+    let clock = Clock::get()?;
+
+    match application.work_status {
+        WorkStatus::Submitted => {}
+        WorkStatus::Approved => return err!(ApplicationError::WorkAlreadyApproved),
+        WorkStatus::Rejected => return err!(ApplicationError::WorkAlreadyRejected),
+        WorkStatus::NotSubmitted => return err!(ApplicationError::WorkNotSubmitted),
+    }
+
+    application.work_status = WorkStatus::Rejected;
+
+    emit!(WorkRejected {
+        application: application.key(),
+        project: application.project_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveWork<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), project.creator_wallet_key.as_ref()],
+        bump = project.bump,
+        constraint = project.client_key == client.key(),
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectAlreadyClosed,
+        constraint = project.approved_application == application.key() @ ApplicationError::InvalidApplicationStatus,
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"application", application.user_wallet_key.as_ref(), project.key().as_ref()],
+        bump = application.bump,
+        constraint = application.status == ApplicationStatus::Approved @ ApplicationError::InvalidApplicationStatus,
+    )]
+    pub application: Account<'info, Application>,
+    #[account(
+        mut,
+        seeds = [b"user", application.user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub applicant: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the project reward
+    #[account(
+        mut,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the applicant's wallet that will receive the reward
+    #[account(mut, address = application.user_wallet_key)]
+    pub applicant_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RejectWork<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), project.creator_wallet_key.as_ref()],
+        bump = project.bump,
+        constraint = project.client_key == client.key(),
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectAlreadyClosed,
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"application", application.user_wallet_key.as_ref(), project.key().as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::states::{Application, ApplicationStatus, Project, ProjectStatus, WorkStatus};
+use crate::errors::{ApplicationError, ProjectError};
+use crate::events::WorkSubmitted;
+
+pub fn submit_work(
+    ctx: Context<SubmitWork>,
+    work_description: String,
+    work_link: String,
+    additional_files_link: String,
+) -> Result<()> {
+    let application = &mut ctx.accounts.application;
+    let clock = Clock::get()?;
+
+    require!(
+        application.status == ApplicationStatus::Approved,
+        ApplicationError::CannotSubmitWorkForNonApprovedApplication
+    );
+    match application.work_status {
+        WorkStatus::Submitted => return err!(ApplicationError::WorkAlreadySubmitted),
+        WorkStatus::Approved => return err!(ApplicationError::WorkAlreadyApproved),
+        // Rejected work can be reworked and resubmitted
+        WorkStatus::NotSubmitted | WorkStatus::Rejected => {}
+    }
+
+    require!(work_description.len() <= 500, ApplicationError::WorkDescriptionTooLong);
+    require!(!work_link.is_empty(), ApplicationError::InvalidWorkLink);
+    require!(work_link.len() <= 280, ApplicationError::InvalidWorkLink);
+    require!(
+        additional_files_link.len() <= 280,
+        ApplicationError::InvalidAdditionalFilesLink
+    );
+
+    application.work_description = work_description;
+    application.work_link = work_link;
+    application.additional_files_link = additional_files_link;
+    application.work_status = WorkStatus::Submitted;
+    application.work_submitted_at = clock.unix_timestamp as u64;
+
+    emit!(WorkSubmitted {
+        application: application.key(),
+        project: application.project_key,
+        work_link: application.work_link.clone(),
+        timestamp: application.work_submitted_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitWork<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), project.creator_wallet_key.as_ref()],
+        bump = project.bump,
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectAlreadyClosed,
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        mut,
+        seeds = [b"application", authority.key().as_ref(), project.key().as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+}
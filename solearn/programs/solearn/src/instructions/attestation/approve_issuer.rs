@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::states::{Client, PlatformConfig, TrustedIssuer};
+use crate::errors::ConfigError;
+use crate::events::IssuerApproved;
+
+pub fn approve_issuer(ctx: Context<ApproveIssuer>) -> Result<()> {
+    let issuer = &mut ctx.accounts.trusted_issuer;
+    let clock = Clock::get()?;
+
+    issuer.wallet = ctx.accounts.client.authority;
+    issuer.approved_at = clock.unix_timestamp as u64;
+    issuer.bump = ctx.bumps.trusted_issuer;
+
+    emit!(IssuerApproved {
+        wallet: issuer.wallet,
+        timestamp: issuer.approved_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveIssuer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ConfigError::NotPlatformAuthority,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    // Only clients can be approved as issuers
+    #[account(
+        seeds = [b"client", client.authority.as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TrustedIssuer::INIT_SPACE,
+        seeds = [b"issuer", client.authority.as_ref()],
+        bump,
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+    pub system_program: Program<'info, System>,
+}
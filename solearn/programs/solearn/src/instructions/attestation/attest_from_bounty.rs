@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::states::{normalize_skill, skill_hash, Attestation, AttestationSource, Bounty, Submission};
+use crate::errors::{AttestationError, BountyError};
+use crate::events::AttestationIssued;
+
+// Lets a bounty winner record one of the bounty's required skills. The bounty itself
+// is the issuer, so these never expire.
+pub fn attest_from_bounty(ctx: Context<AttestFromBounty>, skill: String) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let submission = ctx.accounts.submission.key();
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    require!(
+        bounty.rewarded_submission(submission),
+        AttestationError::NotBountyWinner
+    );
+
+    let skill = normalize_skill(&skill);
+    require!(!skill.is_empty() && skill.len() <= 32, AttestationError::InvalidSkill);
+    require!(
+        bounty
+            .required_skills
+            .iter()
+            .any(|required| normalize_skill(required) == skill),
+        AttestationError::SkillNotRequiredByBounty
+    );
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.user_wallet = ctx.accounts.authority.key();
+    attestation.issuer = bounty.key();
+    attestation.source = AttestationSource::Bounty;
+    attestation.skill = skill;
+    attestation.issued_at = now;
+    attestation.expires_at = 0;
+    attestation.revoked_at = 0;
+    attestation.bump = ctx.bumps.attestation;
+
+    emit!(AttestationIssued {
+        attestation: attestation.key(),
+        user_wallet: attestation.user_wallet,
+        issuer: attestation.issuer,
+        source: AttestationSource::Bounty,
+        skill: attestation.skill.clone(),
+        expires_at: 0,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(skill: String)]
+pub struct AttestFromBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        // A token bounty can be posted in a worthless mint, so its wins prove nothing
+        constraint = bounty.reward_mint == Pubkey::default() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        seeds = [b"submission", authority.key().as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Attestation::INIT_SPACE,
+        seeds = [
+            b"attestation",
+            authority.key().as_ref(),
+            skill_hash(&skill).as_ref(),
+            bounty.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::states::{normalize_skill, skill_hash, Attestation, AttestationSource, PlatformConfig, TrustedIssuer};
+use crate::errors::AttestationError;
+use crate::events::AttestationIssued;
+
+pub fn issue_attestation(
+    ctx: Context<IssueAttestation>,
+    skill: String,
+    expires_at: u64,
+) -> Result<()> {
+    let issuer = ctx.accounts.issuer.key();
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // The platform authority attests directly; anyone else needs an approval record
+    let source = if issuer == ctx.accounts.config.authority {
+        AttestationSource::Platform
+    } else {
+        let record = &ctx.accounts.issuer_record;
+        require!(
+            record.owner == &crate::ID && !record.data_is_empty(),
+            AttestationError::NotAnIssuer
+        );
+        let approved = TrustedIssuer::try_deserialize(&mut &record.try_borrow_data()?[..])?;
+        require!(approved.wallet == issuer, AttestationError::NotAnIssuer);
+        AttestationSource::Client
+    };
+
+    let skill = normalize_skill(&skill);
+    require!(!skill.is_empty() && skill.len() <= 32, AttestationError::InvalidSkill);
+    require!(expires_at == 0 || expires_at > now, AttestationError::InvalidExpiry);
+
+    let attestation = &mut ctx.accounts.attestation;
+    attestation.user_wallet = ctx.accounts.user_wallet.key();
+    attestation.issuer = issuer;
+    attestation.source = source;
+    attestation.skill = skill;
+    attestation.issued_at = now;
+    attestation.expires_at = expires_at;
+    attestation.revoked_at = 0;
+    attestation.bump = ctx.bumps.attestation;
+
+    emit!(AttestationIssued {
+        attestation: attestation.key(),
+        user_wallet: attestation.user_wallet,
+        issuer,
+        source,
+        skill: attestation.skill.clone(),
+        expires_at,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(skill: String)]
+pub struct IssueAttestation<'info> {
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: Issuer approval, decoded in the handler; not needed for the platform authority
+    #[account(
+        seeds = [b"issuer", issuer.key().as_ref()],
+        bump,
+    )]
+    pub issuer_record: UncheckedAccount<'info>,
+    /// CHECK: Wallet of the contributor being attested; only its key is recorded
+    pub user_wallet: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = issuer,
+        space = 8 + Attestation::INIT_SPACE,
+        seeds = [
+            b"attestation",
+            user_wallet.key().as_ref(),
+            skill_hash(&skill).as_ref(),
+            issuer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,11 @@
+pub mod approve_issuer;
+pub mod attest_from_bounty;
+pub mod issue_attestation;
+pub mod remove_issuer;
+pub mod revoke_attestation;
+
+pub use approve_issuer::*;
+pub use attest_from_bounty::*;
+pub use issue_attestation::*;
+pub use remove_issuer::*;
+pub use revoke_attestation::*;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::states::{PlatformConfig, TrustedIssuer};
+use crate::errors::ConfigError;
+use crate::events::IssuerRemoved;
+
+// Stops the wallet issuing new attestations. Ones it already issued stand until
+// revoked, which the platform authority can do.
+pub fn remove_issuer(ctx: Context<RemoveIssuer>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    emit!(IssuerRemoved {
+        wallet: ctx.accounts.trusted_issuer.wallet,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveIssuer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ConfigError::NotPlatformAuthority,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [b"issuer", trusted_issuer.wallet.as_ref()],
+        bump = trusted_issuer.bump,
+        close = authority,
+    )]
+    pub trusted_issuer: Account<'info, TrustedIssuer>,
+}
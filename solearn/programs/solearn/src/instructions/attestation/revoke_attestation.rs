@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::states::{skill_hash, Attestation, AttestationSource, PlatformConfig};
+use crate::errors::AttestationError;
+use crate::events::AttestationRevoked;
+
+// The issuing wallet can revoke its own attestations, and the platform authority
+// can revoke any of them, including those earned from bounties.
+pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+    let attestation = &mut ctx.accounts.attestation;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    let is_issuer = attestation.source != AttestationSource::Bounty && attestation.issuer == authority;
+    require!(
+        is_issuer || authority == ctx.accounts.config.authority,
+        AttestationError::NotAuthorizedToRevoke
+    );
+    require!(attestation.revoked_at == 0, AttestationError::AttestationAlreadyRevoked);
+
+    attestation.revoked_at = clock.unix_timestamp as u64;
+
+    emit!(AttestationRevoked {
+        attestation: attestation.key(),
+        revoked_by: authority,
+        timestamp: attestation.revoked_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        seeds = [
+            b"attestation",
+            attestation.user_wallet.as_ref(),
+            skill_hash(&attestation.skill).as_ref(),
+            attestation.issuer.as_ref(),
+        ],
+        bump = attestation.bump,
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PayoutPolicy, PlatformConfig, Submission, SubmissionStatus, User, BOUNTY_WON_POINTS};
+use crate::errors::{BountyError, ConfigError, DisputeError, SubmissionError};
+use crate::events::SubmissionSelected;
+
+pub fn auto_release_bounty(ctx: Context<AutoReleaseBounty>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let selected_user = &mut ctx.accounts.selected_user;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    let releases_at = bounty.deadline.saturating_add(bounty.review_period);
+    require!(
+        clock.unix_timestamp as u64 > releases_at,
+        BountyError::ReviewWindowNotElapsed
+    );
+    require!(
+        submission.key() == bounty.first_submission,
+        SubmissionError::NotEarliestSubmission
+    );
+
+    let reward_lamports = bounty
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::PayoutOverflow)?;
+
+    // Same payout as select_submission: everything but the escrow's rent-exempt minimum
+    let rent = &Rent::get()?;
+    let rent_exempt_minimum = rent.minimum_balance(0);
+    let escrow_balance = ctx.accounts.escrow_account.to_account_info().lamports();
+    let transfer_amount = escrow_balance.saturating_sub(rent_exempt_minimum);
+
+    let fee_lamports = bounty.platform_fee(reward_lamports);
+    let fee_lamports = fee_lamports.min(transfer_amount);
+
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let winner = ctx.accounts.selected_user_wallet.to_account_info();
+    **escrow.try_borrow_mut_lamports()? = escrow_balance
+        .checked_sub(transfer_amount)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **treasury.try_borrow_mut_lamports()? = treasury.lamports()
+        .checked_add(fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **winner.try_borrow_mut_lamports()? = winner.lamports()
+        .checked_add(transfer_amount - fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+
+    selected_user.earned = selected_user.earned
+        .checked_add(reward_lamports - fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    selected_user.bounties_completed += 1;
+    // The client never picked a winner, so only the contributor gains standing
+    selected_user.reputation.adjust(BOUNTY_WON_POINTS, clock.unix_timestamp as u64);
+
+    bounty.selected_submission = submission.key();
+    submission.status = SubmissionStatus::Winner;
+    bounty.selected_user_wallet_key = submission.user_wallet_key;
+    bounty.bounty_rewarded = true;
+    bounty.live = false;
+    client.bounty_closed(bounty);
+    bounty.close_reason = BountyCloseReason::Rewarded;
+
+    client.rewarded = client.rewarded
+        .checked_add(reward_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+
+    emit!(SubmissionSelected {
+        bounty: bounty.key(),
+        submission: submission.key(),
+        winner: submission.user_wallet_key,
+        reward_mint: bounty.reward_mint,
+        amount: transfer_amount - fee_lamports,
+        platform_fee: fee_lamports,
+        auto_released: true,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AutoReleaseBounty<'info> {
+    // Anyone can crank a guaranteed payout once the review period lapses
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = bounty.payout_policy == PayoutPolicy::GuaranteedToEarliest @ BountyError::NotGuaranteedPayout,
+        constraint = bounty.reward_mint == Pubkey::default() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", submission.user_wallet_key.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"user", submission.user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub selected_user: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the user's wallet that will receive the reward
+    #[account(mut, address = submission.user_wallet_key)]
+    pub selected_user_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PlatformConfig, PrizeTierStatus, Submission, SubmissionStatus, User, BOUNTY_PAID_POINTS, BOUNTY_WON_POINTS};
+use crate::errors::{BountyError, ConfigError, SubmissionError};
+use crate::events::PrizeTierAwarded;
+
+pub fn award_prize_tier(ctx: Context<AwardPrizeTier>, tier_index: u8) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let selected_user = &mut ctx.accounts.selected_user;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    require!(
+        submission.user_wallet_key == selected_user.authority,
+        SubmissionError::InvalidSubmission
+    );
+    require!(
+        submission.status != SubmissionStatus::Rejected,
+        SubmissionError::SubmissionRejected
+    );
+
+    let tier_index = tier_index as usize;
+    require!(
+        tier_index < bounty.prize_tiers.len(),
+        BountyError::PrizeTierNotFound
+    );
+    require!(
+        bounty.prize_tiers[tier_index].status == PrizeTierStatus::Open,
+        BountyError::PrizeTierAlreadySettled
+    );
+
+    // Each submission can win at most one tier
+    let submission_key = submission.key();
+    require!(
+        !bounty.prize_tiers.iter().any(|tier| {
+            tier.status == PrizeTierStatus::Awarded && tier.submission == submission_key
+        }),
+        SubmissionError::SubmissionAlreadyAwarded
+    );
+
+    let reward_lamports = bounty.prize_tiers[tier_index]
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+
+    let fee_lamports = bounty.platform_fee(reward_lamports);
+
+    // Transfer the tier's share of the escrow to the winner, less the platform fee
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let escrow_balance = escrow.lamports();
+    **escrow.try_borrow_mut_lamports()? = escrow_balance
+        .checked_sub(reward_lamports)
+        .ok_or(BountyError::InvalidEscrowAccount)?;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += fee_lamports;
+    **ctx.accounts.selected_user_wallet.to_account_info().try_borrow_mut_lamports()? += reward_lamports - fee_lamports;
+
+    let tier = &mut bounty.prize_tiers[tier_index];
+    tier.status = PrizeTierStatus::Awarded;
+    tier.submission = submission_key;
+    submission.status = SubmissionStatus::Winner;
+
+    // The first place winner is recorded as the bounty's selected submission
+    if tier_index == 0 {
+        bounty.selected_submission = submission_key;
+        bounty.selected_user_wallet_key = selected_user.authority;
+    }
+    bounty.bounty_rewarded = true;
+    if bounty.all_prize_tiers_settled() {
+        // Nothing is left to pay out, so the client gets the escrow's rent back
+        let rent_lamports = escrow.lamports();
+        **escrow.try_borrow_mut_lamports()? = 0;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? = ctx.accounts.authority.lamports()
+            .checked_add(rent_lamports)
+            .unwrap();
+        bounty.live = false;
+        client.bounty_closed(bounty);
+        bounty.close_reason = BountyCloseReason::Rewarded;
+    }
+
+    selected_user.earned += reward_lamports - fee_lamports;
+    selected_user.bounties_completed += 1;
+    selected_user.reputation.adjust(BOUNTY_WON_POINTS, clock.unix_timestamp as u64);
+
+    client.rewarded += reward_lamports;
+    client.reputation.adjust(BOUNTY_PAID_POINTS, clock.unix_timestamp as u64);
+
+    emit!(PrizeTierAwarded {
+        bounty: bounty.key(),
+        tier_index: tier_index as u8,
+        submission: submission_key,
+        winner: selected_user.authority,
+        amount: reward_lamports - fee_lamports,
+        platform_fee: fee_lamports,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AwardPrizeTier<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.prize_tiers.is_empty() @ BountyError::NotATieredBounty,
+        constraint = bounty.reward_mint == Pubkey::default() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", selected_user.authority.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"user", submission.user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub selected_user: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the user's wallet that will receive the reward
+    #[account(mut, address = submission.user_wallet_key)]
+    pub selected_user_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,9 @@
 use anchor_lang::{prelude::*};
-use crate::states::{Bounty, Client};
-use crate::errors::BountyError;
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PayoutPolicy, PlatformConfig, SkillGate, Versioned};
+use crate::errors::{BountyError, ClientError};
+use crate::events::BountyCreated;
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_bounty(
     ctx: Context<CreateBounty>,
     title: String,
@@ -9,15 +11,31 @@ pub fn create_bounty(
     reward: u64,
     deadline: u64,
     required_skills: Vec<String>,
+    payout_policy: PayoutPolicy,
+    review_period: u64,
 ) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Deposits)?;
+
     let bounty = &mut ctx.accounts.bounty;
     let client = &mut ctx.accounts.client;
+    let config = &ctx.accounts.config;
     let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+    require!(
+        !title.is_empty() && title.len() <= 32,
+        BountyError::InvalidTitle
+    );
 
     // Validate reward amount
     require!(reward > 0, BountyError::InvalidRewardAmount);
     let reward_lamports = reward * 1_000_000_000;
 
+    // Validate reward and deadline against platform policy
+    config.check_min_reward(reward)?;
+    config.apply_bounty_policy(bounty, deadline, now)?;
+
     // Check if authority has sufficient SOL
     require!(
         ctx.accounts.authority.lamports() >= reward_lamports,
@@ -45,23 +63,59 @@ pub fn create_bounty(
     bounty.description = description;
     bounty.reward = reward;
     bounty.live = true;
+    bounty.close_reason = BountyCloseReason::None;
     bounty.created_at = clock.unix_timestamp as u64;
     bounty.deadline = deadline;
     bounty.required_skills = required_skills;
     bounty.no_of_submissions = 0;
+    bounty.first_submission = Pubkey::default();
     bounty.selected_submission = Pubkey::default();
     bounty.selected_user_wallet_key = Pubkey::default();
     bounty.escrow_account = ctx.accounts.escrow_account.key();
+    bounty.reward_mint = Pubkey::default();
     bounty.bounty_rewarded = false;
+    bounty.payout_policy = payout_policy;
+    bounty.review_period = match payout_policy {
+        PayoutPolicy::ClientSelects => 0,
+        PayoutPolicy::GuaranteedToEarliest => review_period,
+    };
+    bounty.prize_tiers = Vec::new();
+    bounty.milestones = Vec::new();
+    bounty.arbiters = Vec::new();
+    bounty.arbiter_fee_bps = 0;
+    bounty.disputed = false;
     bounty.bump = ctx.bumps.bounty;
+    bounty.version = Bounty::LAYOUT_VERSION;
+    bounty.counted_live = true;
+    bounty.payout_held = false;
+    bounty.selected_at = 0;
+    bounty.bounty_id = client.next_bounty_id;
+    bounty.legacy_title_seed = String::new();
+    bounty.skill_gate = SkillGate::Open;
+    bounty.attested_skills = false;
+    bounty.min_reputation = 0;
     client.bounties_posted = client.bounties_posted.checked_add(1).unwrap();
+    client.live_bounties = client.live_bounties.checked_add(1).unwrap();
+    client.next_bounty_id = client.next_bounty_id.checked_add(1).unwrap();
+
+    emit!(BountyCreated {
+        bounty: bounty.key(),
+        bounty_id: bounty.bounty_id,
+        client: bounty.client_key,
+        creator: bounty.creator_wallet_key,
+        title: bounty.title.clone(),
+        reward: bounty.reward,
+        reward_mint: bounty.reward_mint,
+        deadline: bounty.deadline,
+        payout_policy: bounty.payout_policy,
+        timestamp: bounty.created_at,
+    });
 
     Ok(())
 }
 
 
 #[derive(Accounts)]
-#[instruction(title: String, reward: u64)]
 pub struct CreateBounty<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -71,11 +125,16 @@ pub struct CreateBounty<'info> {
         bump,
     )]
     pub client: Account<'info, Client>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
     #[account(
         init,
         payer = authority,
         space = 8 + Bounty::INIT_SPACE,
-        seeds = [b"bounty", title.as_bytes(), authority.key().as_ref()],
+        seeds = [b"bounty", client.next_bounty_id.to_le_bytes().as_ref(), authority.key().as_ref()],
         bump,
     )]
     pub bounty: Account<'info, Bounty>,
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
-use crate::states::{Bounty, User, Submission};
-use crate::errors::{BountyError, SubmissionError};
+use crate::states::{Bounty, User, Submission, SubmissionStatus, Versioned};
+use crate::errors::{BountyError, DisputeError, SubmissionError, UserError};
+use crate::events::SubmissionCreated;
+use crate::utils::attested_skills;
 
 pub fn create_submission(
     ctx: Context<CreateSubmission>,
@@ -12,11 +14,42 @@ pub fn create_submission(
     let user = &mut ctx.accounts.user;
     let clock = Clock::get()?;
 
+    require!(!user.is_deleted(), UserError::UserDeleted);
     require!(
         clock.unix_timestamp as u64 <= bounty.deadline,
         BountyError::BountyDeadlinePassed
     );
 
+    require!(
+        ctx.accounts.authority.key() != bounty.creator_wallet_key,
+        SubmissionError::CannotSubmitToOwnBounty
+    );
+    require!(
+        !bounty.arbiters.contains(&ctx.accounts.authority.key()),
+        DisputeError::ArbiterCannotSubmit
+    );
+
+    // Attested skills are passed as remaining accounts
+    let attested;
+    let skills = if bounty.attested_skills {
+        attested = attested_skills(
+            ctx.remaining_accounts,
+            &ctx.accounts.authority.key(),
+            clock.unix_timestamp as u64,
+        )?;
+        &attested
+    } else {
+        &user.skills
+    };
+    require!(
+        bounty.accepts_skills(skills),
+        SubmissionError::InsufficientSkills
+    );
+    require!(
+        user.reputation.current(clock.unix_timestamp as u64) >= bounty.min_reputation,
+        SubmissionError::InsufficientReputation
+    );
+
     let user_key = user.key();
     let bounty_key = bounty.key();
 
@@ -25,12 +58,26 @@ pub fn create_submission(
     submission.bounty_key = bounty_key;
     submission.description = description;
     submission.work_url = work_url;
+    submission.submitted_at = clock.unix_timestamp as u64;
     submission.bump = ctx.bumps.submission;
+    submission.status = SubmissionStatus::Pending;
+    submission.version = Submission::LAYOUT_VERSION;
 
+    // Guaranteed payouts go to the earliest submission
+    if bounty.no_of_submissions == 0 {
+        bounty.first_submission = submission.key();
+    }
     bounty.no_of_submissions = bounty.no_of_submissions.checked_add(1).unwrap();
 
     user.bounties_submitted = user.bounties_submitted.checked_add(1).unwrap();
 
+    emit!(SubmissionCreated {
+        submission: submission.key(),
+        bounty: bounty_key,
+        user_wallet: submission.user_wallet_key,
+        timestamp: submission.submitted_at,
+    });
+
     Ok(())
 }
 
@@ -46,9 +93,9 @@ pub struct CreateSubmission<'info> {
     pub user: Account<'info, User>,
     #[account(
         mut,
-        seeds = [b"bounty", bounty.title.as_bytes(), bounty.creator_wallet_key.as_ref()],
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
         bump = bounty.bump,
-        constraint = bounty.live == true,
+        constraint = bounty.live,
     )]
     pub bounty: Account<'info, Bounty>,
     #[account(
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PayoutPolicy, PlatformConfig, SkillGate, Versioned};
+use crate::errors::{BountyError, ClientError};
+use crate::events::BountyCreated;
+
+pub fn create_token_bounty(
+    ctx: Context<CreateTokenBounty>,
+    title: String,
+    description: String,
+    reward: u64,
+    deadline: u64,
+    required_skills: Vec<String>,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Deposits)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let mint = &ctx.accounts.reward_mint;
+    let clock = Clock::get()?;
+
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+    require!(
+        !title.is_empty() && title.len() <= 32,
+        BountyError::InvalidTitle
+    );
+
+    // Validate reward amount
+    require!(reward > 0, BountyError::InvalidRewardAmount);
+    let unit = 10u64
+        .checked_pow(mint.decimals as u32)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+    let reward_amount = reward
+        .checked_mul(unit)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+
+    // Validate the deadline against platform policy; the SOL minimum doesn't apply
+    ctx.accounts.config.apply_bounty_policy(bounty, deadline, clock.unix_timestamp as u64)?;
+
+    // Check if authority has sufficient tokens
+    require!(
+        ctx.accounts.authority_token_account.amount >= reward_amount,
+        BountyError::InsufficientTokenBalance
+    );
+
+    // Transfer tokens from authority to escrow token account
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.authority_token_account.to_account_info(),
+        mint: mint.to_account_info(),
+        to: ctx.accounts.escrow_token_account.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts);
+    token_interface::transfer_checked(cpi_ctx, reward_amount, mint.decimals)?;
+
+    // Initialize bounty
+    bounty.creator_wallet_key = ctx.accounts.authority.key();
+    bounty.client_key = client.key();
+    bounty.title = title;
+    bounty.description = description;
+    bounty.reward = reward;
+    bounty.live = true;
+    bounty.close_reason = BountyCloseReason::None;
+    bounty.created_at = clock.unix_timestamp as u64;
+    bounty.deadline = deadline;
+    bounty.required_skills = required_skills;
+    bounty.no_of_submissions = 0;
+    bounty.first_submission = Pubkey::default();
+    bounty.selected_submission = Pubkey::default();
+    bounty.selected_user_wallet_key = Pubkey::default();
+    bounty.escrow_account = ctx.accounts.escrow_account.key();
+    bounty.reward_mint = mint.key();
+    bounty.bounty_rewarded = false;
+    bounty.payout_policy = PayoutPolicy::ClientSelects;
+    bounty.review_period = 0;
+    bounty.prize_tiers = Vec::new();
+    bounty.milestones = Vec::new();
+    bounty.arbiters = Vec::new();
+    bounty.arbiter_fee_bps = 0;
+    bounty.disputed = false;
+    bounty.bump = ctx.bumps.bounty;
+    bounty.version = Bounty::LAYOUT_VERSION;
+    bounty.counted_live = true;
+    bounty.payout_held = false;
+    bounty.selected_at = 0;
+    bounty.bounty_id = client.next_bounty_id;
+    bounty.legacy_title_seed = String::new();
+    bounty.skill_gate = SkillGate::Open;
+    bounty.attested_skills = false;
+    bounty.min_reputation = 0;
+    client.bounties_posted = client.bounties_posted.checked_add(1).unwrap();
+    client.live_bounties = client.live_bounties.checked_add(1).unwrap();
+    client.next_bounty_id = client.next_bounty_id.checked_add(1).unwrap();
+
+    emit!(BountyCreated {
+        bounty: bounty.key(),
+        bounty_id: bounty.bounty_id,
+        client: bounty.client_key,
+        creator: bounty.creator_wallet_key,
+        title: bounty.title.clone(),
+        reward: bounty.reward,
+        reward_mint: bounty.reward_mint,
+        deadline: bounty.deadline,
+        payout_policy: bounty.payout_policy,
+        timestamp: bounty.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateTokenBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Bounty::INIT_SPACE,
+        seeds = [b"bounty", client.next_bounty_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow PDA that owns the bounty's token account
+    #[account(
+        init,
+        payer = authority,
+        space = 0,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"escrow_token", bounty.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = escrow_account,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
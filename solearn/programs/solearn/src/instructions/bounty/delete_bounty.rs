@@ -1,9 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::states::{Bounty, Client};
+use crate::states::{Bounty, Client, PauseGroup, PlatformConfig};
 use crate::errors::BountyError;
+use crate::events::BountyDeleted;
 
 #[derive(Accounts)]
-#[instruction(title: String)]
 pub struct DeleteBounty<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -16,10 +16,11 @@ pub struct DeleteBounty<'info> {
     #[account(
         mut,
         close = authority,
-        seeds = [b"bounty", title.as_bytes(), authority.key().as_ref()],
+        seeds = [b"bounty", bounty.address_seed().as_ref(), authority.key().as_ref()],
         bump = bounty.bump,
         constraint = bounty.client_key == client.key(),
-        constraint = bounty.live == true,
+        constraint = bounty.live,
+        constraint = bounty.reward_mint == Pubkey::default() @ BountyError::InvalidRewardMint,
     )]
     pub bounty: Account<'info, Bounty>,
     /// CHECK: This is the escrow account that holds SOL for the bounty reward
@@ -29,15 +30,23 @@ pub struct DeleteBounty<'info> {
         bump,
     )]
     pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn delete_bounty(ctx: Context<DeleteBounty>,_title: String) -> Result<()> {
+pub fn delete_bounty(ctx: Context<DeleteBounty>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
     let bounty = &ctx.accounts.bounty;
     let client = &mut ctx.accounts.client;
+    let clock = Clock::get()?;
     
     require!(
-        bounty.live == true,
+        bounty.live,
         BountyError::BountyAlreadyClosed
     );
     
@@ -55,6 +64,14 @@ pub fn delete_bounty(ctx: Context<DeleteBounty>,_title: String) -> Result<()> {
     }
 
     client.bounties_posted = client.bounties_posted.checked_sub(1).unwrap();
+    client.bounty_closed(bounty);
+
+    emit!(BountyDeleted {
+        bounty: bounty.key(),
+        client: client.key(),
+        refunded: escrow_balance,
+        timestamp: clock.unix_timestamp as u64,
+    });
 
     Ok(())
 }
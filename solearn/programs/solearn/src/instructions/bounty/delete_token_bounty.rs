@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::states::{Bounty, Client, PauseGroup, PlatformConfig};
+use crate::errors::{BountyError, DisputeError};
+use crate::events::BountyDeleted;
+
+#[derive(Accounts)]
+pub struct DeleteTokenBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), authority.key().as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = bounty.reward_mint == reward_mint.key() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow PDA that owns the bounty's token account
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow_token", bounty.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = escrow_account,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = authority,
+        token::token_program = token_program,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn delete_token_bounty(ctx: Context<DeleteTokenBounty>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
+    let bounty = &ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    require!(
+        bounty.live,
+        BountyError::BountyAlreadyClosed
+    );
+
+    require!(
+        bounty.no_of_submissions == 0,
+        BountyError::CannotDeleteWithSubmissions
+    );
+
+    let bounty_key = bounty.key();
+    let escrow_seeds: &[&[u8]] = &[b"escrow", bounty_key.as_ref(), &[ctx.bumps.escrow_account]];
+    let signer_seeds = &[escrow_seeds];
+
+    // Refund the escrowed tokens to the client
+    let refund_amount = ctx.accounts.escrow_token_account.amount;
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.reward_mint.to_account_info(),
+        to: ctx.accounts.authority_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.reward_mint.decimals)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow_token_account.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: ctx.accounts.escrow_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::close_account(cpi_ctx)?;
+
+    // Return the escrow PDA's rent
+    let escrow_balance = ctx.accounts.escrow_account.lamports();
+    if escrow_balance > 0 {
+        **ctx.accounts.escrow_account.try_borrow_mut_lamports()? = 0;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? = ctx.accounts.authority.lamports()
+            .checked_add(escrow_balance)
+            .unwrap();
+    }
+
+    let client = &mut ctx.accounts.client;
+    client.bounties_posted = client.bounties_posted.checked_sub(1).unwrap();
+    client.bounty_closed(bounty);
+
+    emit!(BountyDeleted {
+        bounty: bounty_key,
+        client: client.key(),
+        refunded: refund_amount,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
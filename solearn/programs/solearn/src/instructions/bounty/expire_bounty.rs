@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use crate::states::{
+    Bounty, BountyCloseReason, MilestoneStatus, PauseGroup, PayoutPolicy, PlatformConfig,
+    PrizeTierStatus, BOUNTY_EXPIRED_POINTS, REVIEW_WINDOW_SECONDS,
+};
+use crate::errors::{BountyError, DisputeError};
+use crate::events::BountyExpired;
+use crate::utils::update_client_profile;
+
+pub fn expire_bounty(ctx: Context<ExpireBounty>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    let expires_at = bounty.deadline.saturating_add(REVIEW_WINDOW_SECONDS);
+    require!(
+        clock.unix_timestamp as u64 > expires_at,
+        BountyError::ReviewWindowNotElapsed
+    );
+
+    // Guaranteed bounties with submissions must pay out instead of refunding
+    require!(
+        bounty.payout_policy == PayoutPolicy::ClientSelects || bounty.no_of_submissions == 0,
+        BountyError::GuaranteedPayoutPending
+    );
+
+    // An engaged milestone contributor is paid through the engagement, not the deadline
+    require!(
+        bounty.milestones.is_empty() || bounty.selected_submission == Pubkey::default(),
+        BountyError::BountyHasEngagedContributor
+    );
+
+    // Refund whatever is left in escrow, including its rent, to the creator
+    let escrow_balance = ctx.accounts.escrow_account.lamports();
+    if escrow_balance > 0 {
+        **ctx.accounts.escrow_account.try_borrow_mut_lamports()? = 0;
+        **ctx.accounts.creator_wallet.try_borrow_mut_lamports()? = ctx.accounts.creator_wallet.lamports()
+            .checked_add(escrow_balance)
+            .unwrap();
+    }
+
+    for tier in bounty.prize_tiers.iter_mut() {
+        if tier.status == PrizeTierStatus::Open {
+            tier.status = PrizeTierStatus::Forfeited;
+        }
+    }
+    for milestone in bounty.milestones.iter_mut() {
+        if milestone.status == MilestoneStatus::Pending {
+            milestone.status = MilestoneStatus::Refunded;
+        }
+    }
+
+    // Letting submissions lapse without picking a winner costs the client standing
+    let lapsed = bounty.no_of_submissions > 0 && !bounty.bounty_rewarded;
+    update_client_profile(&ctx.accounts.client, |client| {
+        if lapsed {
+            client.reputation.adjust(BOUNTY_EXPIRED_POINTS, clock.unix_timestamp as u64);
+        }
+        client.bounty_closed(bounty);
+    })?;
+
+    bounty.live = false;
+    bounty.close_reason = BountyCloseReason::Expired;
+
+    emit!(BountyExpired {
+        bounty: bounty.key(),
+        cranker: ctx.accounts.cranker.key(),
+        refunded: escrow_balance,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireBounty<'info> {
+    // Anyone can crank an expired bounty
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = bounty.reward_mint == Pubkey::default() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the bounty creator's wallet that receives the refund
+    #[account(mut, address = bounty.creator_wallet_key)]
+    pub creator_wallet: UncheckedAccount<'info>,
+    /// CHECK: This is the creator's client profile, which may since have been closed
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub client: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub system_program: Program<'info, System>,
+}
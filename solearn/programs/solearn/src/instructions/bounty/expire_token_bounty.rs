@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::states::{Bounty, BountyCloseReason, PauseGroup, PlatformConfig, BOUNTY_EXPIRED_POINTS, REVIEW_WINDOW_SECONDS};
+use crate::errors::{BountyError, DisputeError};
+use crate::events::BountyExpired;
+use crate::utils::update_client_profile;
+
+pub fn expire_token_bounty(ctx: Context<ExpireTokenBounty>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
+    let clock = Clock::get()?;
+
+    let expires_at = ctx.accounts.bounty.deadline.saturating_add(REVIEW_WINDOW_SECONDS);
+    require!(
+        clock.unix_timestamp as u64 > expires_at,
+        BountyError::ReviewWindowNotElapsed
+    );
+
+    let bounty_key = ctx.accounts.bounty.key();
+    let escrow_seeds: &[&[u8]] = &[b"escrow", bounty_key.as_ref(), &[ctx.bumps.escrow_account]];
+    let signer_seeds = &[escrow_seeds];
+
+    // Refund the escrowed tokens to the creator
+    let refund_amount = ctx.accounts.escrow_token_account.amount;
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.reward_mint.to_account_info(),
+        to: ctx.accounts.creator_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.reward_mint.decimals)?;
+
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow_token_account.to_account_info(),
+        destination: ctx.accounts.creator_wallet.to_account_info(),
+        authority: ctx.accounts.escrow_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::close_account(cpi_ctx)?;
+
+    // Return the escrow PDA's rent
+    let escrow_balance = ctx.accounts.escrow_account.lamports();
+    if escrow_balance > 0 {
+        **ctx.accounts.escrow_account.try_borrow_mut_lamports()? = 0;
+        **ctx.accounts.creator_wallet.try_borrow_mut_lamports()? = ctx.accounts.creator_wallet.lamports()
+            .checked_add(escrow_balance)
+            .unwrap();
+    }
+
+    let bounty = &mut ctx.accounts.bounty;
+    // Letting submissions lapse without picking a winner costs the client standing
+    let lapsed = bounty.no_of_submissions > 0 && !bounty.bounty_rewarded;
+    update_client_profile(&ctx.accounts.client, |client| {
+        if lapsed {
+            client.reputation.adjust(BOUNTY_EXPIRED_POINTS, clock.unix_timestamp as u64);
+        }
+        client.bounty_closed(bounty);
+    })?;
+
+    bounty.live = false;
+    bounty.close_reason = BountyCloseReason::Expired;
+
+    emit!(BountyExpired {
+        bounty: bounty_key,
+        cranker: ctx.accounts.cranker.key(),
+        refunded: refund_amount,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireTokenBounty<'info> {
+    // Anyone can crank an expired bounty
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = bounty.reward_mint == reward_mint.key() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow PDA that owns the bounty's token account
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow_token", bounty.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = escrow_account,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = bounty.creator_wallet_key,
+        token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: This is the bounty creator's wallet that receives the reclaimed rent
+    #[account(mut, address = bounty.creator_wallet_key)]
+    pub creator_wallet: UncheckedAccount<'info>,
+    /// CHECK: This is the creator's client profile, which may since have been closed
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub client: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PlatformConfig, PrizeTierStatus};
+use crate::errors::BountyError;
+use crate::events::PrizeTierForfeited;
+
+pub fn forfeit_prize_tier(ctx: Context<ForfeitPrizeTier>, tier_index: u8) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    // Contributors get the whole submission window before a tier can be pulled
+    require!(
+        clock.unix_timestamp as u64 > bounty.deadline,
+        BountyError::DeadlineNotReached
+    );
+
+    let tier_index = tier_index as usize;
+    require!(
+        tier_index < bounty.prize_tiers.len(),
+        BountyError::PrizeTierNotFound
+    );
+    require!(
+        bounty.prize_tiers[tier_index].status == PrizeTierStatus::Open,
+        BountyError::PrizeTierAlreadySettled
+    );
+
+    let tier_lamports = bounty.prize_tiers[tier_index]
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+
+    bounty.prize_tiers[tier_index].status = PrizeTierStatus::Forfeited;
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let escrow_balance = escrow.lamports();
+    require!(
+        escrow_balance >= tier_lamports,
+        BountyError::InvalidEscrowAccount
+    );
+
+    // Return the tier's share of the escrow to the client, and close the escrow
+    // with its rent once the last tier is settled
+    let refund_lamports = if bounty.all_prize_tiers_settled() {
+        escrow_balance
+    } else {
+        tier_lamports
+    };
+    **escrow.try_borrow_mut_lamports()? = escrow_balance - refund_lamports;
+    **ctx.accounts.authority.try_borrow_mut_lamports()? = ctx.accounts.authority.lamports()
+        .checked_add(refund_lamports)
+        .unwrap();
+
+    if bounty.all_prize_tiers_settled() {
+        bounty.live = false;
+        ctx.accounts.client.bounty_closed(bounty);
+        bounty.close_reason = if bounty.bounty_rewarded {
+            BountyCloseReason::Rewarded
+        } else {
+            BountyCloseReason::Cancelled
+        };
+    }
+
+    emit!(PrizeTierForfeited {
+        bounty: bounty.key(),
+        tier_index: tier_index as u8,
+        refunded: refund_lamports,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ForfeitPrizeTier<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.prize_tiers.is_empty() @ BountyError::NotATieredBounty,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client};
+use crate::errors::{BountyError, ClientError};
+use crate::events::BountyMigrated;
+use crate::utils::migrate_layout;
+
+// Assigns an id to a bounty posted under a title-derived address. The bounty keeps
+// its address, so its escrow, submissions and disputes carry on untouched.
+pub fn migrate_bounty(ctx: Context<MigrateBounty>, title: String) -> Result<()> {
+    let client = &mut ctx.accounts.client;
+    let info = ctx.accounts.bounty.to_account_info();
+    let clock = Clock::get()?;
+
+    // Bounties from before ids are still in the layout they were posted with
+    let (mut bounty, _) = migrate_layout::<Bounty>(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    require!(bounty.client_key == client.key(), ClientError::NotAuthorizedForBounty);
+    require!(bounty.legacy_title_seed.is_empty(), BountyError::BountyAlreadyMigrated);
+
+    bounty.bounty_id = client.next_bounty_id;
+    bounty.legacy_title_seed = title;
+    bounty.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    client.next_bounty_id = client.next_bounty_id.checked_add(1).unwrap();
+
+    emit!(BountyMigrated {
+        bounty: info.key(),
+        bounty_id: bounty.bounty_id,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(title: String)]
+pub struct MigrateBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    /// CHECK: Bounty account in its previous layout, which `Account` cannot deserialize yet
+    #[account(
+        mut,
+        seeds = [b"bounty", title.as_bytes(), authority.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub bounty: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Versioned};
+use crate::errors::{BountyError, ClientError};
+use crate::events::BountyMigrated;
+use crate::utils::migrate_layout;
+
+// Brings a bounty written under an earlier layout up to the current one. Bounties
+// still addressed by their title go through `migrate_bounty` instead.
+pub fn migrate_bounty_layout(ctx: Context<MigrateBountyLayout>) -> Result<()> {
+    let info = ctx.accounts.bounty.to_account_info();
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    let (bounty, found) = migrate_layout::<Bounty>(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    require!(found < Bounty::LAYOUT_VERSION, BountyError::BountyLayoutCurrent);
+    require!(bounty.creator_wallet_key == authority, ClientError::NotAuthorizedForBounty);
+
+    // A bounty without an id or title seed would otherwise be read as bounty 0
+    let address = Pubkey::create_program_address(
+        &[b"bounty", bounty.address_seed().as_ref(), authority.as_ref(), &[bounty.bump]],
+        &crate::ID,
+    );
+    require!(address == Ok(info.key()), BountyError::BountyNeedsId);
+
+    emit!(BountyMigrated {
+        bounty: info.key(),
+        bounty_id: bounty.bounty_id,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateBountyLayout<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Bounty account in its previous layout, which `Account` cannot deserialize
+    /// yet. Its creator and address are checked once it is upgraded.
+    #[account(mut, owner = crate::ID)]
+    pub bounty: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::states::{Submission, Versioned};
+use crate::errors::SubmissionError;
+use crate::events::SubmissionMigrated;
+use crate::utils::migrate_layout;
+
+// Brings a submission written under an earlier layout up to the current one
+pub fn migrate_submission(ctx: Context<MigrateSubmission>) -> Result<()> {
+    let info = ctx.accounts.submission.to_account_info();
+    let clock = Clock::get()?;
+
+    let (submission, found) = migrate_layout::<Submission>(
+        &info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    require!(
+        found < Submission::LAYOUT_VERSION,
+        SubmissionError::SubmissionAlreadyMigrated
+    );
+
+    emit!(SubmissionMigrated {
+        submission: info.key(),
+        bounty: submission.bounty_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateSubmission<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Only used to derive the submission's address
+    pub bounty: UncheckedAccount<'info>,
+    /// CHECK: Submission account in its previous layout, which `Account` cannot deserialize yet
+    #[account(
+        mut,
+        seeds = [b"submission", authority.key().as_ref(), bounty.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub submission: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,11 +1,49 @@
+pub mod auto_release_bounty;
+pub mod award_prize_tier;
 pub mod create_bounty;
 pub mod create_submission;
+pub mod create_tiered_bounty;
+pub mod create_token_bounty;
 pub mod delete_bounty;
+pub mod delete_token_bounty;
+pub mod expire_bounty;
+pub mod expire_token_bounty;
+pub mod forfeit_prize_tier;
+pub mod migrate_bounty;
+pub mod migrate_bounty_layout;
+pub mod migrate_submission;
+pub mod release_held_payout;
+pub mod review_submission;
 pub mod select_submission;
+pub mod select_token_submission;
+pub mod set_bounty_arbiters;
+pub mod set_bounty_min_reputation;
+pub mod set_bounty_skill_gate;
 pub mod update_bounty;
+pub mod update_submission;
+pub mod withdraw_submission;
 
+pub use auto_release_bounty::*;
+pub use award_prize_tier::*;
 pub use create_bounty::*;
 pub use create_submission::*;
+pub use create_tiered_bounty::*;
+pub use create_token_bounty::*;
 pub use delete_bounty::*;
+pub use delete_token_bounty::*;
+pub use expire_bounty::*;
+pub use expire_token_bounty::*;
+pub use forfeit_prize_tier::*;
+pub use migrate_bounty::*;
+pub use migrate_bounty_layout::*;
+pub use migrate_submission::*;
+pub use release_held_payout::*;
+pub use review_submission::*;
 pub use select_submission::*;
+pub use select_token_submission::*;
+pub use set_bounty_arbiters::*;
+pub use set_bounty_min_reputation::*;
+pub use set_bounty_skill_gate::*;
 pub use update_bounty::*;
+pub use update_submission::*;
+pub use withdraw_submission::*;
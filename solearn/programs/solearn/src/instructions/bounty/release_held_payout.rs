@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PlatformConfig, Submission, SubmissionStatus, User, BOUNTY_PAID_POINTS, BOUNTY_WON_POINTS, SELECTION_DISPUTE_SECONDS};
+use crate::errors::{BountyError, ConfigError, DisputeError};
+use crate::events::SubmissionSelected;
+
+// Pays a paneled bounty's selected winner once nobody disputed the pick in time.
+// Whatever a dispute over the pick awarded to someone else has already left the escrow.
+pub fn release_held_payout(ctx: Context<ReleaseHeldPayout>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let selected_user = &mut ctx.accounts.selected_user;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    let releases_at = bounty.selected_at.saturating_add(SELECTION_DISPUTE_SECONDS);
+    require!(
+        clock.unix_timestamp as u64 > releases_at,
+        BountyError::DisputeWindowOpen
+    );
+
+    // Everything but the escrow's rent-exempt minimum
+    let rent = &Rent::get()?;
+    let rent_exempt_minimum = rent.minimum_balance(0);
+    let escrow_balance = ctx.accounts.escrow_account.to_account_info().lamports();
+    let transfer_amount = escrow_balance.saturating_sub(rent_exempt_minimum);
+    let fee_lamports = bounty.platform_fee(transfer_amount);
+    let winner_amount = transfer_amount - fee_lamports;
+
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let winner = ctx.accounts.selected_user_wallet.to_account_info();
+    **escrow.try_borrow_mut_lamports()? = escrow_balance
+        .checked_sub(transfer_amount)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **treasury.try_borrow_mut_lamports()? = treasury.lamports()
+        .checked_add(fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **winner.try_borrow_mut_lamports()? = winner.lamports()
+        .checked_add(winner_amount)
+        .ok_or(BountyError::PayoutOverflow)?;
+
+    let now = clock.unix_timestamp as u64;
+    selected_user.earned = selected_user.earned
+        .checked_add(winner_amount)
+        .ok_or(BountyError::PayoutOverflow)?;
+    selected_user.bounties_completed += 1;
+    selected_user.reputation.adjust(BOUNTY_WON_POINTS, now);
+    client.rewarded = client.rewarded
+        .checked_add(transfer_amount)
+        .ok_or(BountyError::PayoutOverflow)?;
+    client.reputation.adjust(BOUNTY_PAID_POINTS, now);
+
+    submission.status = SubmissionStatus::Winner;
+    bounty.payout_held = false;
+    bounty.bounty_rewarded = true;
+    bounty.close_reason = BountyCloseReason::Rewarded;
+
+    emit!(SubmissionSelected {
+        bounty: bounty.key(),
+        submission: submission.key(),
+        winner: submission.user_wallet_key,
+        reward_mint: bounty.reward_mint,
+        amount: winner_amount,
+        platform_fee: fee_lamports,
+        auto_released: true,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseHeldPayout<'info> {
+    // Anyone can crank a held payout once the dispute window closes
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.payout_held @ BountyError::NoPayoutHeld,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(mut, address = bounty.selected_submission)]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"user", bounty.selected_user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub selected_user: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the winner's wallet that will receive the reward
+    #[account(mut, address = bounty.selected_user_wallet_key)]
+    pub selected_user_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, PayoutPolicy, Submission, SubmissionStatus, MAX_SUBMISSION_FEEDBACK_LEN};
+use crate::errors::{BountyError, ClientError, DisputeError, SubmissionError};
+use crate::events::SubmissionReviewed;
+
+pub fn review_submission(
+    ctx: Context<ReviewSubmission>,
+    status: SubmissionStatus,
+    feedback: String,
+) -> Result<()> {
+    let submission = &mut ctx.accounts.submission;
+    let bounty = &ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    // Winners are only ever marked by the payout instructions
+    require!(
+        matches!(
+            status,
+            SubmissionStatus::Shortlisted | SubmissionStatus::ChangesRequested | SubmissionStatus::Rejected
+        ),
+        SubmissionError::InvalidReviewStatus
+    );
+    require!(
+        feedback.len() <= MAX_SUBMISSION_FEEDBACK_LEN,
+        SubmissionError::FeedbackTooLong
+    );
+    require!(
+        !bounty.picked_submission(submission.key()),
+        SubmissionError::SubmissionLocked
+    );
+    // Rejecting it would let the client dodge the guarantee
+    require!(
+        status != SubmissionStatus::Rejected
+            || bounty.payout_policy == PayoutPolicy::ClientSelects
+            || bounty.first_submission != submission.key(),
+        SubmissionError::EarliestSubmissionGuaranteed
+    );
+
+    submission.status = status;
+    submission.feedback = feedback;
+    submission.reviewed_at = clock.unix_timestamp as u64;
+
+    emit!(SubmissionReviewed {
+        submission: submission.key(),
+        bounty: bounty.key(),
+        status,
+        feedback: submission.feedback.clone(),
+        timestamp: submission.reviewed_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewSubmission<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.creator_wallet_key == authority.key() @ ClientError::NotAuthorizedForBounty,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", submission.user_wallet_key.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+}
@@ -1,19 +1,52 @@
 use anchor_lang::prelude::*;
-use crate::states::{Bounty, Client, Submission, User};
-use crate::errors::SubmissionError;
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PlatformConfig, Submission, SubmissionStatus, User, BOUNTY_PAID_POINTS, BOUNTY_WON_POINTS, SELECTION_DISPUTE_SECONDS};
+use crate::errors::{BountyError, ConfigError, DisputeError, MilestoneError, SubmissionError};
+use crate::events::{SelectionHeld, SubmissionSelected};
 
 pub fn select_submission(ctx: Context<SelectSubmission>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
     let bounty = &mut ctx.accounts.bounty;
     let client = &mut ctx.accounts.client;
     let selected_user = &mut ctx.accounts.selected_user;
-    let submission = &ctx.accounts.submission;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
 
     require!(
         submission.user_wallet_key == selected_user.authority,
         SubmissionError::InvalidSubmission
     );
+    require!(
+        submission.status != SubmissionStatus::Rejected,
+        SubmissionError::SubmissionRejected
+    );
+
+    // A paneled bounty holds the payout, so contributors passed over can still take
+    // the pick to the panel
+    if !bounty.arbiters.is_empty() {
+        let now = clock.unix_timestamp as u64;
+        bounty.selected_submission = submission.key();
+        bounty.selected_user_wallet_key = selected_user.authority;
+        bounty.payout_held = true;
+        bounty.selected_at = now;
+        bounty.live = false;
+        client.bounty_closed(bounty);
+
+        emit!(SelectionHeld {
+            bounty: bounty.key(),
+            submission: submission.key(),
+            winner: selected_user.authority,
+            releases_at: now.saturating_add(SELECTION_DISPUTE_SECONDS),
+            timestamp: now,
+        });
+
+        return Ok(());
+    }
 
-    let reward_lamports = bounty.reward * 1_000_000_000;
+    let reward_lamports = bounty
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::PayoutOverflow)?;
     
     // Calculate rent-exempt minimum for the escrow account
     let rent = &Rent::get()?;
@@ -23,25 +56,59 @@ pub fn select_submission(ctx: Context<SelectSubmission>) -> Result<()> {
     let escrow_balance = ctx.accounts.escrow_account.to_account_info().lamports();
     let transfer_amount = escrow_balance.saturating_sub(rent_exempt_minimum);
     
+    // Platform fee rate was fixed when the bounty was posted
+    let fee_lamports = bounty.platform_fee(reward_lamports);
+    let fee_lamports = fee_lamports.min(transfer_amount);
+
     // Transfer SOL from escrow to user by directly manipulating lamports
     // This avoids the system program transfer restriction for accounts with data
-    **ctx.accounts.escrow_account.to_account_info().try_borrow_mut_lamports()? -= transfer_amount;
-    **ctx.accounts.selected_user_wallet.to_account_info().try_borrow_mut_lamports()? += transfer_amount;
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let treasury = ctx.accounts.treasury.to_account_info();
+    let winner = ctx.accounts.selected_user_wallet.to_account_info();
+    **escrow.try_borrow_mut_lamports()? = escrow_balance
+        .checked_sub(transfer_amount)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **treasury.try_borrow_mut_lamports()? = treasury.lamports()
+        .checked_add(fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **winner.try_borrow_mut_lamports()? = winner.lamports()
+        .checked_add(transfer_amount - fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
     
     // Update user stats with the actual reward amount (not the transfer amount)
-    selected_user.earned += reward_lamports;
+    selected_user.earned = selected_user.earned
+        .checked_add(reward_lamports - fee_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
 
     // Update bounty status
     bounty.selected_submission = submission.key();
+    submission.status = SubmissionStatus::Winner;
     bounty.selected_user_wallet_key = selected_user.authority;
     bounty.bounty_rewarded = true;
     bounty.live = false;
+    client.bounty_closed(bounty);
+    bounty.close_reason = BountyCloseReason::Rewarded;
 
     // Update user stats (already added earned above)
     selected_user.bounties_completed += 1;
+    selected_user.reputation.adjust(BOUNTY_WON_POINTS, clock.unix_timestamp as u64);
 
     // Update client stats
-    client.rewarded += reward_lamports;
+    client.rewarded = client.rewarded
+        .checked_add(reward_lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    client.reputation.adjust(BOUNTY_PAID_POINTS, clock.unix_timestamp as u64);
+
+    emit!(SubmissionSelected {
+        bounty: bounty.key(),
+        submission: submission.key(),
+        winner: selected_user.authority,
+        reward_mint: bounty.reward_mint,
+        amount: transfer_amount - fee_lamports,
+        platform_fee: fee_lamports,
+        auto_released: false,
+        timestamp: clock.unix_timestamp as u64,
+    });
 
     Ok(())
 }
@@ -61,8 +128,14 @@ pub struct SelectSubmission<'info> {
     pub client: Account<'info, Client>,
     #[account(
         mut,
-        seeds = [b"bounty", bounty.title.as_bytes(), bounty.creator_wallet_key.as_ref()],
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
         bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = bounty.reward_mint == Pubkey::default() @ BountyError::InvalidRewardMint,
+        constraint = bounty.prize_tiers.is_empty() @ BountyError::TieredBountyRequiresTierAward,
+        constraint = bounty.milestones.is_empty() @ MilestoneError::MilestoneBountyRequiresApproval,
     )]
     pub bounty: Account<'info, Bounty>,
     #[account(
@@ -85,8 +158,16 @@ pub struct SelectSubmission<'info> {
     )]
     pub escrow_account: UncheckedAccount<'info>,
     /// CHECK: This is the user's wallet that will receive the reward
-    #[account(mut)]
+    #[account(mut, address = submission.user_wallet_key)]
     pub selected_user_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
 
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::states::{Bounty, BountyCloseReason, Client, PauseGroup, PlatformConfig, Submission, SubmissionStatus, User};
+use crate::errors::{BountyError, DisputeError, SubmissionError};
+use crate::events::SubmissionSelected;
+
+pub fn select_token_submission(ctx: Context<SelectTokenSubmission>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let selected_user = &mut ctx.accounts.selected_user;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    require!(
+        submission.bounty_key == bounty.key(),
+        SubmissionError::InvalidSubmission
+    );
+    require!(
+        submission.user_wallet_key == selected_user.authority,
+        SubmissionError::InvalidSubmission
+    );
+    require!(
+        submission.status != SubmissionStatus::Rejected,
+        SubmissionError::SubmissionRejected
+    );
+
+    let bounty_key = bounty.key();
+    let escrow_seeds: &[&[u8]] = &[b"escrow", bounty_key.as_ref(), &[ctx.bumps.escrow_account]];
+    let signer_seeds = &[escrow_seeds];
+
+    // Release the whole escrow token balance, less the platform fee, to the winner
+    let transfer_amount = ctx.accounts.escrow_token_account.amount;
+    let fee_amount = bounty.platform_fee(transfer_amount);
+    if fee_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            mint: ctx.accounts.reward_mint.to_account_info(),
+            to: ctx.accounts.treasury_token_account.to_account_info(),
+            authority: ctx.accounts.escrow_account.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, fee_amount, ctx.accounts.reward_mint.decimals)?;
+    }
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.reward_mint.to_account_info(),
+        to: ctx.accounts.selected_user_token_account.to_account_info(),
+        authority: ctx.accounts.escrow_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::transfer_checked(
+        cpi_ctx,
+        transfer_amount - fee_amount,
+        ctx.accounts.reward_mint.decimals,
+    )?;
+
+    // Close the now empty escrow token account, returning its rent to the client
+    let cpi_accounts = CloseAccount {
+        account: ctx.accounts.escrow_token_account.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
+        authority: ctx.accounts.escrow_account.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token_interface::close_account(cpi_ctx)?;
+
+    // Update bounty status
+    bounty.selected_submission = submission.key();
+    submission.status = SubmissionStatus::Winner;
+    bounty.selected_user_wallet_key = selected_user.authority;
+    bounty.bounty_rewarded = true;
+    bounty.live = false;
+    ctx.accounts.client.bounty_closed(bounty);
+    bounty.close_reason = BountyCloseReason::Rewarded;
+
+    // `earned` and `rewarded` are tracked in lamports, so token payouts only count completions.
+    // No reputation points either: anyone can mint a worthless token and pay themselves with it.
+    selected_user.bounties_completed += 1;
+
+    emit!(SubmissionSelected {
+        bounty: bounty_key,
+        submission: submission.key(),
+        winner: selected_user.authority,
+        reward_mint: bounty.reward_mint,
+        amount: transfer_amount - fee_amount,
+        platform_fee: fee_amount,
+        auto_released: false,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SelectTokenSubmission<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = bounty.reward_mint == reward_mint.key() @ BountyError::InvalidRewardMint,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", selected_user.authority.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"user", submission.user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub selected_user: Account<'info, User>,
+    /// CHECK: This is the escrow PDA that owns the bounty's token account
+    #[account(
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        seeds = [b"escrow_token", bounty.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = escrow_account,
+        token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = submission.user_wallet_key,
+        token::token_program = token_program,
+    )]
+    pub selected_user_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = config.treasury,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
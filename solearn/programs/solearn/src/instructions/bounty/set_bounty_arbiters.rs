@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client, MAX_ARBITERS, MAX_ARBITER_FEE_BPS};
+use crate::errors::{BountyError, DisputeError};
+use crate::events::BountyArbitersSet;
+
+pub fn set_bounty_arbiters(
+    ctx: Context<SetBountyArbiters>,
+    arbiters: Vec<Pubkey>,
+    arbiter_fee_bps: u16,
+) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    // Contributors must know who arbitrates before they submit
+    require!(
+        bounty.no_of_submissions == 0,
+        DisputeError::ArbitersLocked
+    );
+    require!(arbiters.len() <= MAX_ARBITERS, DisputeError::InvalidArbiters);
+    require!(
+        arbiter_fee_bps <= MAX_ARBITER_FEE_BPS,
+        DisputeError::ArbiterFeeTooHigh
+    );
+
+    // Neither party can sit on the panel, and each arbiter only gets one seat
+    for (i, arbiter) in arbiters.iter().enumerate() {
+        require!(
+            *arbiter != Pubkey::default() && *arbiter != bounty.creator_wallet_key,
+            DisputeError::InvalidArbiters
+        );
+        require!(
+            !arbiters[..i].contains(arbiter),
+            DisputeError::InvalidArbiters
+        );
+    }
+
+    bounty.arbiters = arbiters;
+    bounty.arbiter_fee_bps = arbiter_fee_bps;
+
+    emit!(BountyArbitersSet {
+        bounty: bounty.key(),
+        arbiters: bounty.arbiters.clone(),
+        arbiter_fee_bps,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBountyArbiters<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
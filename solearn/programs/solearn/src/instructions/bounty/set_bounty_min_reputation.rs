@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client};
+use crate::errors::BountyError;
+use crate::events::BountyMinReputationSet;
+
+pub fn set_bounty_min_reputation(ctx: Context<SetBountyMinReputation>, min_reputation: u64) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    // Earlier submitters would not have been held to the new minimum
+    require!(
+        bounty.no_of_submissions == 0,
+        BountyError::CannotUpdateWithSubmissions
+    );
+
+    bounty.min_reputation = min_reputation;
+
+    emit!(BountyMinReputationSet {
+        bounty: bounty.key(),
+        min_reputation,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBountyMinReputation<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
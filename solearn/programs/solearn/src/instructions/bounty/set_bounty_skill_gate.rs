@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client, SkillGate};
+use crate::errors::BountyError;
+use crate::events::BountySkillGateSet;
+
+pub fn set_bounty_skill_gate(
+    ctx: Context<SetBountySkillGate>,
+    skill_gate: SkillGate,
+    attested_skills: bool,
+) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    // Earlier submitters would not have been held to the new gate
+    require!(
+        bounty.no_of_submissions == 0,
+        BountyError::CannotUpdateWithSubmissions
+    );
+
+    let required = bounty.required_skills.len();
+    let valid = match skill_gate {
+        SkillGate::Open => true,
+        SkillGate::Any | SkillGate::All => required > 0,
+        SkillGate::AtLeast(count) => count > 0 && count as usize <= required,
+    };
+    require!(valid, BountyError::InvalidSkillGate);
+    require!(
+        !attested_skills || skill_gate != SkillGate::Open,
+        BountyError::InvalidSkillGate
+    );
+
+    bounty.skill_gate = skill_gate;
+    bounty.attested_skills = attested_skills;
+
+    emit!(BountySkillGateSet {
+        bounty: bounty.key(),
+        skill_gate,
+        attested_skills,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBountySkillGate<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
@@ -1,17 +1,19 @@
 use anchor_lang::prelude::*;
-use crate::states::{Bounty, Client};
+use crate::states::{Bounty, Client, PlatformConfig};
 use crate::errors::BountyError;
+use crate::events::BountyUpdated;
 
 pub fn update_bounty(
     ctx: Context<UpdateBounty>,
-    _title: String,
+    title: String,
     description: String,
     deadline: u64,
 ) -> Result<()> {
     let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
     
     require!(
-        bounty.live == true,
+        bounty.live,
         BountyError::BountyAlreadyClosed
     );
     
@@ -20,14 +22,30 @@ pub fn update_bounty(
         BountyError::CannotUpdateWithSubmissions
     );
 
+    require!(
+        !title.is_empty() && title.len() <= 32,
+        BountyError::InvalidTitle
+    );
+
+    // A new deadline is held to the same policy as one set at creation
+    ctx.accounts.config.check_deadline(deadline, clock.unix_timestamp as u64)?;
+
+    // The title is plain data; the bounty stays at the same address
+    bounty.title = title;
     bounty.description = description;
     bounty.deadline = deadline;
 
+    emit!(BountyUpdated {
+        bounty: bounty.key(),
+        title: bounty.title.clone(),
+        deadline,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(title: String)]
 pub struct UpdateBounty<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -39,12 +57,17 @@ pub struct UpdateBounty<'info> {
     pub client: Account<'info, Client>,
     #[account(
         mut,
-        seeds = [b"bounty", title.as_bytes(), bounty.creator_wallet_key.as_ref()],
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
         bump = bounty.bump,
         constraint = bounty.client_key == client.key(),
-        constraint = bounty.live == true,
+        constraint = bounty.live,
     )]
     pub bounty: Account<'info, Bounty>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
 }
 
 
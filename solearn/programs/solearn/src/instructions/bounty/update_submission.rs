@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Submission, SubmissionStatus, REVIEW_WINDOW_SECONDS};
+use crate::errors::{BountyError, DisputeError, SubmissionError};
+use crate::events::SubmissionUpdated;
+
+pub fn update_submission(
+    ctx: Context<UpdateSubmission>,
+    description: String,
+    work_url: String,
+) -> Result<()> {
+    let submission = &mut ctx.accounts.submission;
+    let bounty = &ctx.accounts.bounty;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    // Clients review after the deadline, so work sent back for changes stays
+    // open to revision until the review window ends
+    let closes_at = if submission.status == SubmissionStatus::ChangesRequested {
+        bounty.deadline.saturating_add(REVIEW_WINDOW_SECONDS)
+    } else {
+        bounty.deadline
+    };
+    require!(now <= closes_at, BountyError::BountyDeadlinePassed);
+    require!(
+        !bounty.picked_submission(submission.key())
+            && submission.status != SubmissionStatus::Rejected,
+        SubmissionError::SubmissionLocked
+    );
+    require!(description.len() <= 500, SubmissionError::DescriptionTooLong);
+    require!(
+        !work_url.is_empty() && work_url.len() <= 280,
+        SubmissionError::InvalidSubmissionLink
+    );
+
+    submission.description = description;
+    if submission.work_url != work_url {
+        submission.revise(work_url, now);
+    }
+    // Revising answers a request for changes
+    if submission.status == SubmissionStatus::ChangesRequested {
+        submission.status = SubmissionStatus::Pending;
+    }
+
+    emit!(SubmissionUpdated {
+        submission: submission.key(),
+        bounty: bounty.key(),
+        work_url: submission.work_url.clone(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSubmission<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", authority.key().as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+}
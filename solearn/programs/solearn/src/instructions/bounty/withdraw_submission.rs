@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, PayoutPolicy, Submission, SubmissionStatus, User};
+use crate::errors::{BountyError, DisputeError, SubmissionError};
+use crate::events::SubmissionWithdrawn;
+
+pub fn withdraw_submission(ctx: Context<WithdrawSubmission>) -> Result<()> {
+    let submission = &ctx.accounts.submission;
+    let bounty = &mut ctx.accounts.bounty;
+    let user = &mut ctx.accounts.user;
+    let clock = Clock::get()?;
+
+    // Rejected submissions can be withdrawn for their rent even once the bounty closes,
+    // but not while the wallet could still submit again
+    if submission.status != SubmissionStatus::Rejected {
+        require!(bounty.live, BountyError::BountyAlreadyClosed);
+        require!(
+            clock.unix_timestamp as u64 <= bounty.deadline,
+            BountyError::BountyDeadlinePassed
+        );
+    } else {
+        require!(
+            !bounty.live || clock.unix_timestamp as u64 > bounty.deadline,
+            SubmissionError::SubmissionRejected
+        );
+    }
+    let submission_key = submission.key();
+    require!(
+        !bounty.picked_submission(submission_key),
+        SubmissionError::SubmissionLocked
+    );
+
+    // A closed bounty's tallies are its final record, so only a live one gives the slot back
+    if bounty.live {
+        bounty.no_of_submissions = bounty.no_of_submissions.checked_sub(1).unwrap();
+        // A guaranteed payout goes to the earliest submission, so it can only leave
+        // once nobody else is waiting on the bounty
+        if bounty.first_submission == submission_key {
+            require!(
+                bounty.payout_policy == PayoutPolicy::ClientSelects || bounty.no_of_submissions == 0,
+                SubmissionError::EarliestSubmissionPending
+            );
+            bounty.first_submission = Pubkey::default();
+        }
+    }
+
+    user.bounties_submitted = user.bounties_submitted.saturating_sub(1);
+
+    emit!(SubmissionWithdrawn {
+        submission: submission_key,
+        bounty: bounty.key(),
+        user_wallet: submission.user_wallet_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSubmission<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
+    )]
+    pub user: Account<'info, User>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", authority.key().as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+        close = authority,
+    )]
+    pub submission: Account<'info, Submission>,
+}
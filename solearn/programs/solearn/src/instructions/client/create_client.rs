@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::states::{Client, Social, Country, Industries};
+use crate::states::{handle_hash, Client, Country, HandleRecord, Industries, Reputation, Social, Versioned, COMPANY_NAME_SEED};
 use crate::errors::ClientError;
+use crate::events::ClientCreated;
 
 
 pub fn create_client(ctx: Context<CreateClient>, company_name: String, company_email: String, company_link: String) -> Result<()> {
     let client = &mut ctx.accounts.client;
     let clock = Clock::get()?;
 
+    // A deleted profile is re-created over its tombstone
+    let restoring = client.authority != Pubkey::default();
+    require!(!restoring || client.is_deleted(), ClientError::ClientAlreadyExists);
+
     // Validate input
     require!(!company_name.is_empty(), ClientError::InvalidCompanyNameFormat);
     require!(!company_email.is_empty(), ClientError::InvalidCompanyEmailFormat);
@@ -15,16 +20,45 @@ pub fn create_client(ctx: Context<CreateClient>, company_name: String, company_e
     require!(company_email.len() <= 100, ClientError::InvalidCompanyEmailFormat);
     require!(company_link.len() <= 100, ClientError::InvalidCompanyLinkFormat);
 
+    // Reserve the company handle so no other client can take it
+    let client_key = client.key();
+    require!(
+        ctx.accounts.name_record.claim(ctx.accounts.authority.key(), client_key, ctx.bumps.name_record),
+        ClientError::CompanyUsernameAlreadyTaken
+    );
+
     client.authority = ctx.accounts.authority.key();
     client.company_name = company_name.clone();
     client.company_email = company_email;
     client.company_avatar = process_avatar(&company_name);
     client.company_link = company_link;
     client.company_bio = "Hi I'm a new client".to_string();
-    client.rewarded = 0;
-    client.bounties_posted = 0;
-    client.joined_at = clock.unix_timestamp as u64;
-    client.bump = ctx.bumps.client;
+    client.industry = Industries::default();
+    client.country = Country::default();
+    client.socials = Social::default();
+    client.deleted_at = 0;
+    // Ratings and reputation survive a re-creation, and bounty and project ids keep
+    // counting up so new ones can't land on the addresses of old ones
+    if !restoring {
+        client.rewarded = 0;
+        client.bounties_posted = 0;
+        client.joined_at = clock.unix_timestamp as u64;
+        client.bump = ctx.bumps.client;
+        client.version = Client::LAYOUT_VERSION;
+        client.next_bounty_id = 0;
+        client.next_project_id = 0;
+        client.rating_sum = 0;
+        client.rating_count = 0;
+        client.reputation = Reputation::default();
+    }
+
+    emit!(ClientCreated {
+        client: client.key(),
+        authority: client.authority,
+        company_name: client.company_name.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
     Ok(())
 }
 
@@ -41,16 +75,25 @@ fn process_avatar(input: &str) -> String {
 }
 
 #[derive(Accounts)]
+#[instruction(company_name: String)]
 pub struct CreateClient<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         space = 8 + Client::INIT_SPACE,
         seeds = [b"client", authority.key().as_ref()],
         bump
     )]
     pub client: Account<'info, Client>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HandleRecord::INIT_SPACE,
+        seeds = [COMPANY_NAME_SEED, handle_hash(&company_name).as_ref()],
+        bump
+    )]
+    pub name_record: Account<'info, HandleRecord>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -1,8 +1,38 @@
 use anchor_lang::prelude::*;
-use crate::states::{Client};
+use crate::states::{handle_hash, Client, COMPANY_NAME_SEED};
+use crate::utils::release_handle;
+use crate::errors::ClientError;
+use crate::events::ClientDeleted;
 
 
-pub fn delete_client(_ctx: Context<DeleteClient>) -> Result<()> {
+pub fn delete_client(ctx: Context<DeleteClient>) -> Result<()> {
+    let clock = Clock::get()?;
+    let client = &ctx.accounts.client;
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+    // Escrowed rewards must be paid out, refunded or expired first
+    require!(
+        client.live_bounties == 0 && client.live_projects == 0,
+        ClientError::HasLiveListings
+    );
+
+    // Free the company handle for other clients
+    release_handle(&ctx.accounts.name_record, &ctx.accounts.authority.to_account_info())?;
+
+    // Leave a tombstone rather than closing so past bounties, reviews and ratings
+    // still resolve to a client. The company name stays for the same reason.
+    let client = &mut ctx.accounts.client;
+    client.company_email.clear();
+    client.company_link.clear();
+    client.company_bio.clear();
+    client.socials = Default::default();
+    client.deleted_at = clock.unix_timestamp as u64;
+
+    emit!(ClientDeleted {
+        client: ctx.accounts.client.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
     Ok(())
 }
 
@@ -14,9 +44,15 @@ pub struct DeleteClient<'info> {
     #[account(
         mut,
         seeds = [b"client", authority.key().as_ref()],
-        bump,
-        close = authority
+        bump
     )]
     pub client: Account<'info, Client>,
+    /// CHECK: Record of the client's company handle, released here. Absent for clients created before the registry.
+    #[account(
+        mut,
+        seeds = [COMPANY_NAME_SEED, handle_hash(&client.company_name).as_ref()],
+        bump
+    )]
+    pub name_record: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
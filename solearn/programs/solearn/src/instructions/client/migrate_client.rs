@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::states::{Client, Versioned};
+use crate::errors::ClientError;
+use crate::events::ClientMigrated;
+use crate::utils::migrate_layout;
+
+// Brings a client created under an earlier layout up to the current one
+pub fn migrate_client(ctx: Context<MigrateClient>) -> Result<()> {
+    let client = ctx.accounts.client.to_account_info();
+    let clock = Clock::get()?;
+
+    // Fields appended before versioning, such as `next_bounty_id`, start at zero
+    let (_, found) = migrate_layout::<Client>(
+        &client,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    require!(found < Client::LAYOUT_VERSION, ClientError::ClientAlreadyMigrated);
+
+    emit!(ClientMigrated {
+        client: client.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateClient<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: Client account in its previous layout, which `Account` cannot deserialize yet
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub client: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,11 @@
 pub mod create_client;
 pub mod update_client;
 pub mod delete_client;
+pub mod migrate_client;
+pub mod set_client_details;
 
 pub use create_client::*;
 pub use update_client::*;
-pub use delete_client::*;
\ No newline at end of file
+pub use delete_client::*;
+pub use migrate_client::*;
+pub use set_client_details::*;
\ No newline at end of file
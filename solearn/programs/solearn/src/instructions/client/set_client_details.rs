@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::states::{Client, Country, Industries, Social};
+use crate::errors::ClientError;
+use crate::events::ClientUpdated;
+
+pub fn set_client_details(
+    ctx: Context<SetClientDetails>,
+    industry: Industries,
+    country: Country,
+    socials: Social,
+) -> Result<()> {
+    let client = &mut ctx.accounts.client;
+    let clock = Clock::get()?;
+
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+    require!(socials.is_valid(), ClientError::InvalidSocials);
+
+    client.industry = industry;
+    client.country = country;
+    client.socials = socials;
+
+    emit!(ClientUpdated {
+        client: client.key(),
+        authority: client.authority,
+        company_name: client.company_name.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetClientDetails<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump
+    )]
+    pub client: Account<'info, Client>,
+    pub system_program: Program<'info, System>,
+}
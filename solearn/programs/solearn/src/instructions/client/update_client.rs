@@ -1,11 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::states::{Client, Social, Country, Industries};
+use crate::states::{handle_hash, Client, HandleRecord, COMPANY_NAME_SEED};
+use crate::utils::release_handle;
 use crate::errors::ClientError;
+use crate::events::ClientUpdated;
 
 
 pub fn update_client(ctx: Context<UpdateClient>, company_name: String, company_email: String, company_link: String, company_bio: String) -> Result<()> {    
     let client = &mut ctx.accounts.client;
+    let clock = Clock::get()?;
     
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+
     // Validate input
     require!(!company_name.is_empty(), ClientError::InvalidCompanyNameFormat);
     require!(!company_email.is_empty(), ClientError::InvalidCompanyEmailFormat);
@@ -14,13 +19,31 @@ pub fn update_client(ctx: Context<UpdateClient>, company_name: String, company_e
     require!(company_email.len() <= 100, ClientError::InvalidCompanyEmailFormat);
     require!(company_link.len() <= 100, ClientError::InvalidCompanyLinkFormat);
     require!(company_bio.len() <= 280, ClientError::InvalidCompanyNameFormat);
-    
+
+    // Move the company handle reservation when the name changes
+    let client_key = client.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    require!(
+        ctx.accounts.name_record.claim(authority.key(), client_key, ctx.bumps.name_record),
+        ClientError::CompanyUsernameAlreadyTaken
+    );
+    if ctx.accounts.current_name_record.key() != ctx.accounts.name_record.key() {
+        release_handle(&ctx.accounts.current_name_record, &authority)?;
+    }
+
     client.authority = ctx.accounts.authority.key();
     client.company_name = company_name.clone();
     client.company_email = company_email;
     client.company_avatar = process_avatar(&company_name);
     client.company_link = company_link;
     client.company_bio = company_bio;
+
+    emit!(ClientUpdated {
+        client: client.key(),
+        authority: client.authority,
+        company_name: client.company_name.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
     Ok(())
 }
 
@@ -36,17 +59,30 @@ fn process_avatar(input: &str) -> String {
     }
 }
 #[derive(Accounts)]
+#[instruction(company_name: String)]
 pub struct UpdateClient<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
         mut,
         seeds = [b"client", authority.key().as_ref()],
-        realloc = 8 + Client::INIT_SPACE,
-        realloc::payer = authority,
-        realloc::zero = true,
         bump
     )]
     pub client: Account<'info, Client>,
+    /// CHECK: Record of the client's current company handle, released if the name changes. Absent for clients created before the registry.
+    #[account(
+        mut,
+        seeds = [COMPANY_NAME_SEED, handle_hash(&client.company_name).as_ref()],
+        bump
+    )]
+    pub current_name_record: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HandleRecord::INIT_SPACE,
+        seeds = [COMPANY_NAME_SEED, handle_hash(&company_name).as_ref()],
+        bump
+    )]
+    pub name_record: Account<'info, HandleRecord>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
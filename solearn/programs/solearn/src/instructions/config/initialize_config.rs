@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::program::Solearn;
+use crate::states::{PlatformConfig, MAX_PLATFORM_FEE_BPS};
+use crate::errors::ConfigError;
+use crate::events::ConfigInitialized;
+
+pub fn initialize_config(
+    ctx: Context<InitializeConfig>,
+    fee_bps: u16,
+    treasury: Pubkey,
+    min_reward: u64,
+    max_deadline_duration: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    require!(fee_bps <= MAX_PLATFORM_FEE_BPS, ConfigError::PlatformFeeTooHigh);
+    require!(treasury != Pubkey::default(), ConfigError::InvalidTreasury);
+    require!(max_deadline_duration > 0, ConfigError::InvalidMaxDeadlineDuration);
+
+    config.authority = ctx.accounts.authority.key();
+    config.treasury = treasury;
+    config.fee_bps = fee_bps;
+    config.min_reward = min_reward;
+    config.max_deadline_duration = max_deadline_duration;
+    // The upgrade authority guards the program until a dedicated guardian is set
+    config.guardian = ctx.accounts.authority.key();
+    config.paused = false;
+    config.deposits_paused = false;
+    config.payouts_paused = false;
+    config.refunds_paused = false;
+    config.bump = ctx.bumps.config;
+
+    emit!(ConfigInitialized {
+        authority: config.authority,
+        treasury,
+        fee_bps,
+        min_reward,
+        max_deadline_duration,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PlatformConfig::INIT_SPACE,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, Solearn>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ConfigError::NotUpgradeAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub mod initialize_config;
+pub mod pause;
+pub mod set_guardian;
+pub mod update_config;
+
+pub use initialize_config::*;
+pub use pause::*;
+pub use set_guardian::*;
+pub use update_config::*;
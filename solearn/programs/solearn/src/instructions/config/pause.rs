@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::states::{PauseGroup, PlatformConfig};
+use crate::errors::ConfigError;
+use crate::events::PauseStateChanged;
+
+pub fn pause(ctx: Context<SetPause>, group: Option<PauseGroup>) -> Result<()> {
+    set_pause(ctx, group, true)
+}
+
+pub fn unpause(ctx: Context<SetPause>, group: Option<PauseGroup>) -> Result<()> {
+    set_pause(ctx, group, false)
+}
+
+// No group means the global flag, which halts every fund-moving instruction
+fn set_pause(ctx: Context<SetPause>, group: Option<PauseGroup>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    match group {
+        None => {
+            require!(config.paused != paused, ConfigError::PauseStateUnchanged);
+            config.paused = paused;
+        }
+        Some(group) => {
+            require!(config.group_paused(group) != paused, ConfigError::PauseStateUnchanged);
+            config.set_group_paused(group, paused);
+        }
+    }
+
+    emit!(PauseStateChanged {
+        guardian: ctx.accounts.guardian.key(),
+        group,
+        paused,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPause<'info> {
+    pub guardian: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = guardian @ ConfigError::NotGuardian,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::states::PlatformConfig;
+use crate::errors::ConfigError;
+use crate::events::GuardianUpdated;
+
+pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    require!(guardian != Pubkey::default(), ConfigError::InvalidGuardian);
+
+    let previous_guardian = config.guardian;
+    config.guardian = guardian;
+
+    emit!(GuardianUpdated {
+        previous_guardian,
+        new_guardian: guardian,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ConfigError::NotPlatformAuthority,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+}
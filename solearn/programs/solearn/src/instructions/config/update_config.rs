@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::states::{PlatformConfig, MAX_PLATFORM_FEE_BPS};
+use crate::errors::ConfigError;
+use crate::events::ConfigUpdated;
+
+pub fn update_config(
+    ctx: Context<UpdateConfig>,
+    new_authority: Pubkey,
+    fee_bps: u16,
+    treasury: Pubkey,
+    min_reward: u64,
+    max_deadline_duration: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    // Handing the config to the default key would lock it for good
+    require!(new_authority != Pubkey::default(), ConfigError::InvalidAuthority);
+    require!(fee_bps <= MAX_PLATFORM_FEE_BPS, ConfigError::PlatformFeeTooHigh);
+    require!(treasury != Pubkey::default(), ConfigError::InvalidTreasury);
+    require!(max_deadline_duration > 0, ConfigError::InvalidMaxDeadlineDuration);
+
+    // Fee changes only apply to bounties posted from now on
+    config.authority = new_authority;
+    config.treasury = treasury;
+    config.fee_bps = fee_bps;
+    config.min_reward = min_reward;
+    config.max_deadline_duration = max_deadline_duration;
+
+    emit!(ConfigUpdated {
+        authority: new_authority,
+        treasury,
+        fee_bps,
+        min_reward,
+        max_deadline_duration,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ ConfigError::NotPlatformAuthority,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+}
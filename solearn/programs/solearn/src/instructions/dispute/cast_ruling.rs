@@ -0,0 +1,278 @@
+use anchor_lang::prelude::*;
+use crate::states::{
+    Bounty, BountyCloseReason, Client, Dispute, DisputeRuling, DisputeStatus, DisputeVote, PauseGroup,
+    PlatformConfig, Submission, SubmissionStatus, User, BOUNTY_WON_POINTS, DISPUTE_LOST_POINTS,
+};
+use crate::errors::{BountyError, ConfigError, DisputeError};
+use crate::events::{DisputeResolved, RulingCast};
+
+// Remaining accounts: the wallets of the arbiters who voted for the winning ruling,
+// in panel order, once this vote completes a majority.
+pub fn cast_ruling<'info>(
+    ctx: Context<'_, '_, '_, 'info, CastRuling<'info>>,
+    ruling: DisputeRuling,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let dispute = &mut ctx.accounts.dispute;
+    let arbiter = ctx.accounts.arbiter.key();
+    let clock = Clock::get()?;
+
+    if let DisputeRuling::Split { contributor_bps } = ruling {
+        require!(contributor_bps <= 10_000, DisputeError::InvalidSplit);
+    }
+    require!(dispute.arbiters.contains(&arbiter), DisputeError::NotArbiter);
+    require!(
+        !dispute.votes.iter().any(|vote| vote.arbiter == arbiter),
+        DisputeError::AlreadyVoted
+    );
+
+    dispute.votes.push(DisputeVote { arbiter, ruling });
+
+    emit!(RulingCast {
+        dispute: dispute.key(),
+        arbiter,
+        ruling,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    let majority_arbiters: Vec<Pubkey> = dispute
+        .arbiters
+        .iter()
+        .filter(|panelist| {
+            dispute
+                .votes
+                .iter()
+                .any(|vote| vote.arbiter == **panelist && vote.ruling == ruling)
+        })
+        .copied()
+        .collect();
+    if majority_arbiters.len() < dispute.arbiters.len() / 2 + 1 {
+        return Ok(());
+    }
+
+    let arbiter_wallets = ctx.remaining_accounts;
+    require!(
+        arbiter_wallets.len() == majority_arbiters.len(),
+        DisputeError::InvalidArbiterWallets
+    );
+    for (wallet, expected) in arbiter_wallets.iter().zip(majority_arbiters.iter()) {
+        require!(
+            wallet.key() == *expected && wallet.is_writable,
+            DisputeError::InvalidArbiterWallets
+        );
+    }
+
+    let now = clock.unix_timestamp as u64;
+    settle_dispute(
+        dispute,
+        &mut ctx.accounts.bounty,
+        &mut ctx.accounts.submission,
+        &mut ctx.accounts.client,
+        &mut ctx.accounts.contributor,
+        &ctx.accounts.escrow_account,
+        &ctx.accounts.client_wallet,
+        &ctx.accounts.contributor_wallet,
+        &ctx.accounts.treasury,
+        arbiter_wallets,
+        ruling,
+        now,
+    )
+}
+
+// Pays out the escrow per `ruling`, splitting the arbiter fee between `arbiter_wallets`,
+// and closes the bounty. A dispute over a held selection only decides what the disputing
+// contributor gets; the pick stands for the rest, which stays held for its winner.
+#[allow(clippy::too_many_arguments)]
+fn settle_dispute<'info>(
+    dispute: &mut Account<'info, Dispute>,
+    bounty: &mut Account<'info, Bounty>,
+    submission: &mut Account<'info, Submission>,
+    client: &mut Account<'info, Client>,
+    contributor: &mut Account<'info, User>,
+    escrow: &AccountInfo<'info>,
+    client_wallet: &AccountInfo<'info>,
+    contributor_wallet: &AccountInfo<'info>,
+    treasury: &AccountInfo<'info>,
+    arbiter_wallets: &[AccountInfo<'info>],
+    ruling: DisputeRuling,
+    now: u64,
+) -> Result<()> {
+    // Everything but the escrow's rent-exempt minimum is up for distribution
+    let rent = &Rent::get()?;
+    let rent_exempt_minimum = rent.minimum_balance(0);
+    let payable = escrow.lamports().saturating_sub(rent_exempt_minimum);
+
+    // Arbiters who never ruled earn nothing
+    let fee_total = (payable as u128 * dispute.arbiter_fee_bps as u128 / 10_000) as u64;
+    let fee_per_arbiter = fee_total
+        .checked_div(arbiter_wallets.len() as u64)
+        .unwrap_or(0);
+    let arbiter_fees = fee_per_arbiter
+        .checked_mul(arbiter_wallets.len() as u64)
+        .ok_or(BountyError::PayoutOverflow)?;
+    let remaining = payable
+        .checked_sub(arbiter_fees)
+        .ok_or(BountyError::PayoutOverflow)?;
+    let contributor_amount = match ruling {
+        DisputeRuling::PayContributor => remaining,
+        DisputeRuling::Split { contributor_bps } => {
+            (remaining as u128 * contributor_bps as u128 / 10_000) as u64
+        }
+        DisputeRuling::Refund => 0,
+    };
+    let client_amount = if bounty.payout_held {
+        0
+    } else {
+        remaining - contributor_amount
+    };
+    // The platform takes its cut of whatever the contributor is awarded
+    let fee_lamports = bounty.platform_fee(contributor_amount);
+    let released = arbiter_fees
+        .checked_add(contributor_amount)
+        .and_then(|total| total.checked_add(client_amount))
+        .ok_or(BountyError::PayoutOverflow)?;
+
+    debit(escrow, released)?;
+    for wallet in arbiter_wallets.iter() {
+        credit(wallet, fee_per_arbiter)?;
+    }
+    credit(treasury, fee_lamports)?;
+    credit(contributor_wallet, contributor_amount - fee_lamports)?;
+    credit(client_wallet, client_amount)?;
+
+    // Whoever the panel ruled against outright loses standing
+    match ruling {
+        DisputeRuling::PayContributor => client.reputation.adjust(DISPUTE_LOST_POINTS, now),
+        DisputeRuling::Refund => contributor.reputation.adjust(DISPUTE_LOST_POINTS, now),
+        DisputeRuling::Split { .. } => {}
+    }
+
+    if contributor_amount > 0 {
+        contributor.earned = contributor.earned
+            .checked_add(contributor_amount - fee_lamports)
+            .ok_or(BountyError::PayoutOverflow)?;
+        client.rewarded = client.rewarded
+            .checked_add(contributor_amount)
+            .ok_or(BountyError::PayoutOverflow)?;
+    }
+    // A held pick is only overturned when the panel awards the disputing contributor everything
+    let contributor_wins =
+        contributor_amount > 0 && (!bounty.payout_held || contributor_amount == remaining);
+    if contributor_wins {
+        // A milestone contributor may already have been credited for an earlier milestone
+        if !bounty.bounty_rewarded {
+            contributor.bounties_completed += 1;
+            contributor.reputation.adjust(BOUNTY_WON_POINTS, now);
+        }
+
+        bounty.selected_submission = dispute.submission;
+        bounty.selected_user_wallet_key = dispute.contributor_wallet;
+        bounty.bounty_rewarded = true;
+        submission.status = SubmissionStatus::Winner;
+    }
+    if bounty.payout_held {
+        // The bounty already closed when the pick was made
+        if contributor_wins {
+            bounty.payout_held = false;
+            bounty.close_reason = BountyCloseReason::Rewarded;
+        }
+    } else {
+        bounty.close_reason = if bounty.bounty_rewarded {
+            BountyCloseReason::Rewarded
+        } else {
+            BountyCloseReason::Cancelled
+        };
+        bounty.live = false;
+        client.bounty_closed(bounty);
+    }
+    bounty.disputed = false;
+
+    dispute.status = DisputeStatus::Resolved;
+    dispute.ruling = Some(ruling);
+    dispute.resolved_at = now;
+
+    emit!(DisputeResolved {
+        dispute: dispute.key(),
+        bounty: bounty.key(),
+        ruling,
+        contributor_amount: contributor_amount - fee_lamports,
+        client_amount,
+        arbiter_fees,
+        platform_fee: fee_lamports,
+        timestamp: dispute.resolved_at,
+    });
+
+    Ok(())
+}
+
+fn debit(account: &AccountInfo, lamports: u64) -> Result<()> {
+    let balance = account.lamports()
+        .checked_sub(lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **account.try_borrow_mut_lamports()? = balance;
+    Ok(())
+}
+
+fn credit(account: &AccountInfo, lamports: u64) -> Result<()> {
+    let balance = account.lamports()
+        .checked_add(lamports)
+        .ok_or(BountyError::PayoutOverflow)?;
+    **account.try_borrow_mut_lamports()? = balance;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CastRuling<'info> {
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ DisputeError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(mut, address = dispute.submission)]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"user", dispute.contributor_wallet.as_ref()],
+        bump,
+    )]
+    pub contributor: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the client's wallet that receives any refund
+    #[account(mut, address = dispute.client_wallet)]
+    pub client_wallet: UncheckedAccount<'info>,
+    /// CHECK: This is the contributor's wallet that receives any payout
+    #[account(mut, address = dispute.contributor_wallet)]
+    pub contributor_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,9 @@
+pub mod cast_ruling;
+pub mod open_dispute;
+pub mod resolve_expired_dispute;
+pub mod submit_evidence;
+
+pub use cast_ruling::*;
+pub use open_dispute::*;
+pub use resolve_expired_dispute::*;
+pub use submit_evidence::*;
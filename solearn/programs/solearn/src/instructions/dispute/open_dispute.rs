@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Dispute, DisputeStatus, PayoutPolicy, Submission, SELECTION_DISPUTE_SECONDS};
+use crate::errors::{BountyError, DisputeError, MilestoneError};
+use crate::events::DisputeOpened;
+
+pub fn open_dispute(ctx: Context<OpenDispute>, evidence_link: String) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let bounty = &mut ctx.accounts.bounty;
+    let submission = &ctx.accounts.submission;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(!bounty.arbiters.is_empty(), DisputeError::NoArbitersDesignated);
+    require!(
+        bounty.reward_mint == Pubkey::default() && bounty.prize_tiers.is_empty(),
+        DisputeError::DisputeNotSupported
+    );
+    // A milestone bounty's dispute covers the engaged contributor's unreleased milestones
+    require!(
+        bounty.milestones.is_empty() || bounty.selected_submission == submission.key(),
+        MilestoneError::NotEngagedContributor
+    );
+
+    let is_client = authority == bounty.creator_wallet_key;
+    let is_contributor = authority == submission.user_wallet_key;
+    require!(is_client || is_contributor, DisputeError::NotDisputeParty);
+    // Once a winner is picked, only a contributor passed over can contest it, and
+    // only while the payout is held
+    if bounty.payout_held {
+        require!(
+            is_contributor && submission.key() != bounty.selected_submission,
+            DisputeError::NotPassedOver
+        );
+        require!(
+            clock.unix_timestamp as u64 <= bounty.selected_at.saturating_add(SELECTION_DISPUTE_SECONDS),
+            DisputeError::DisputeWindowClosed
+        );
+    } else {
+        require!(bounty.live, BountyError::BountyAlreadyClosed);
+    }
+    // Past the deadline the earliest submission is owed its payout, which the client
+    // could otherwise hold up by disputing it
+    require!(
+        !(is_client
+            && bounty.payout_policy == PayoutPolicy::GuaranteedToEarliest
+            && clock.unix_timestamp as u64 > bounty.deadline),
+        DisputeError::GuaranteedPayoutLocked
+    );
+
+    require!(!evidence_link.is_empty(), DisputeError::InvalidEvidenceLink);
+    require!(evidence_link.len() <= 200, DisputeError::InvalidEvidenceLink);
+
+    dispute.bounty = bounty.key();
+    dispute.submission = submission.key();
+    dispute.client_wallet = bounty.creator_wallet_key;
+    dispute.contributor_wallet = submission.user_wallet_key;
+    dispute.opened_by = authority;
+    dispute.arbiters = bounty.arbiters.clone();
+    dispute.votes = Vec::new();
+    dispute.arbiter_fee_bps = bounty.arbiter_fee_bps;
+    if is_client {
+        dispute.client_evidence = vec![evidence_link];
+        dispute.contributor_evidence = Vec::new();
+    } else {
+        dispute.client_evidence = Vec::new();
+        dispute.contributor_evidence = vec![evidence_link];
+    }
+    dispute.status = DisputeStatus::Open;
+    dispute.ruling = None;
+    dispute.opened_at = clock.unix_timestamp as u64;
+    dispute.resolved_at = 0;
+    dispute.bump = ctx.bumps.dispute;
+
+    // Freeze the escrow until the panel rules
+    bounty.disputed = true;
+
+    emit!(DisputeOpened {
+        dispute: dispute.key(),
+        bounty: dispute.bounty,
+        submission: dispute.submission,
+        opened_by: authority,
+        timestamp: dispute.opened_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        seeds = [b"submission", submission.user_wallet_key.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump,
+    )]
+    pub dispute: Account<'info, Dispute>,
+    pub system_program: Program<'info, System>,
+}
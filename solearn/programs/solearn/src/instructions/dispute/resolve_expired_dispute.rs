@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Dispute, DisputeStatus, DISPUTE_RULING_SECONDS};
+use crate::errors::DisputeError;
+use crate::events::DisputeLapsed;
+
+// A panel that never reaches a majority can't keep the escrow frozen forever. Nobody
+// ruled, so nothing is paid out: the freeze lifts and the bounty carries on as it was.
+pub fn resolve_expired_dispute(ctx: Context<ResolveExpiredDispute>) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp as u64;
+
+    let ruling_deadline = dispute.opened_at.saturating_add(DISPUTE_RULING_SECONDS);
+    require!(now > ruling_deadline, DisputeError::RulingPeriodNotElapsed);
+
+    bounty.disputed = false;
+    dispute.status = DisputeStatus::Resolved;
+    dispute.ruling = None;
+    dispute.resolved_at = now;
+
+    emit!(DisputeLapsed {
+        dispute: dispute.key(),
+        bounty: bounty.key(),
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveExpiredDispute<'info> {
+    // Anyone can lift the freeze once the panel's deadline passes
+    pub cranker: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"dispute", bounty.key().as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ DisputeError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
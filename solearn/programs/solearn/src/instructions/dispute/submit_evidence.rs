@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::states::{Dispute, DisputeStatus, MAX_EVIDENCE};
+use crate::errors::DisputeError;
+use crate::events::EvidenceSubmitted;
+
+pub fn submit_evidence(ctx: Context<SubmitEvidence>, evidence_link: String) -> Result<()> {
+    let dispute = &mut ctx.accounts.dispute;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    require!(!evidence_link.is_empty(), DisputeError::InvalidEvidenceLink);
+    require!(evidence_link.len() <= 200, DisputeError::InvalidEvidenceLink);
+
+    let evidence = if authority == dispute.client_wallet {
+        &mut dispute.client_evidence
+    } else if authority == dispute.contributor_wallet {
+        &mut dispute.contributor_evidence
+    } else {
+        return err!(DisputeError::NotDisputeParty);
+    };
+
+    require!(evidence.len() < MAX_EVIDENCE, DisputeError::EvidenceLimitReached);
+    evidence.push(evidence_link.clone());
+
+    emit!(EvidenceSubmitted {
+        dispute: dispute.key(),
+        submitted_by: authority,
+        evidence_link,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitEvidence<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"dispute", dispute.bounty.as_ref()],
+        bump = dispute.bump,
+        constraint = dispute.status == DisputeStatus::Open @ DisputeError::DisputeAlreadyResolved,
+    )]
+    pub dispute: Account<'info, Dispute>,
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, Milestone, MilestoneParams, MilestoneStatus, PauseGroup, PayoutPolicy, PlatformConfig, SkillGate, Versioned, MAX_MILESTONES};
+use crate::errors::{BountyError, ClientError, MilestoneError};
+use crate::events::BountyCreated;
+
+pub fn create_milestone_bounty(
+    ctx: Context<CreateMilestoneBounty>,
+    title: String,
+    description: String,
+    milestones: Vec<MilestoneParams>,
+    deadline: u64,
+    required_skills: Vec<String>,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Deposits)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let clock = Clock::get()?;
+
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+    require!(
+        !title.is_empty() && title.len() <= 32,
+        BountyError::InvalidTitle
+    );
+
+    // Validate milestones
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES,
+        MilestoneError::InvalidMilestones
+    );
+    for milestone in milestones.iter() {
+        require!(milestone.reward > 0, BountyError::InvalidRewardAmount);
+        require!(!milestone.deliverable.is_empty(), MilestoneError::InvalidDeliverable);
+        require!(milestone.deliverable.len() <= 100, MilestoneError::InvalidDeliverable);
+    }
+
+    let reward = milestones
+        .iter()
+        .try_fold(0u64, |total, milestone| total.checked_add(milestone.reward))
+        .ok_or(BountyError::InvalidRewardAmount)?;
+    let reward_lamports = reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+
+    // Validate reward and deadline against platform policy
+    ctx.accounts.config.check_min_reward(reward)?;
+    ctx.accounts.config.apply_bounty_policy(bounty, deadline, clock.unix_timestamp as u64)?;
+
+    // Check if authority has sufficient SOL
+    require!(
+        ctx.accounts.authority.lamports() >= reward_lamports,
+        BountyError::InsufficientSolBalance
+    );
+
+    // Transfer SOL from authority to escrow account
+    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.escrow_account.key(),
+        reward_lamports,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &transfer_instruction,
+        &[
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.escrow_account.to_account_info(),
+        ],
+    )?;
+
+    // Initialize bounty
+    bounty.creator_wallet_key = ctx.accounts.authority.key();
+    bounty.client_key = client.key();
+    bounty.title = title;
+    bounty.description = description;
+    bounty.reward = reward;
+    bounty.live = true;
+    bounty.close_reason = BountyCloseReason::None;
+    bounty.created_at = clock.unix_timestamp as u64;
+    bounty.deadline = deadline;
+    bounty.required_skills = required_skills;
+    bounty.no_of_submissions = 0;
+    bounty.first_submission = Pubkey::default();
+    bounty.selected_submission = Pubkey::default();
+    bounty.selected_user_wallet_key = Pubkey::default();
+    bounty.escrow_account = ctx.accounts.escrow_account.key();
+    bounty.reward_mint = Pubkey::default();
+    bounty.bounty_rewarded = false;
+    bounty.payout_policy = PayoutPolicy::ClientSelects;
+    bounty.review_period = 0;
+    bounty.prize_tiers = Vec::new();
+    bounty.milestones = milestones
+        .into_iter()
+        .map(|milestone| Milestone {
+            reward: milestone.reward,
+            deliverable: milestone.deliverable,
+            work_url: String::new(),
+            status: MilestoneStatus::Pending,
+            submitted_at: 0,
+        })
+        .collect();
+    bounty.arbiters = Vec::new();
+    bounty.arbiter_fee_bps = 0;
+    bounty.disputed = false;
+    bounty.bump = ctx.bumps.bounty;
+    bounty.version = Bounty::LAYOUT_VERSION;
+    bounty.counted_live = true;
+    bounty.payout_held = false;
+    bounty.selected_at = 0;
+    bounty.bounty_id = client.next_bounty_id;
+    bounty.legacy_title_seed = String::new();
+    bounty.skill_gate = SkillGate::Open;
+    bounty.attested_skills = false;
+    bounty.min_reputation = 0;
+    client.bounties_posted = client.bounties_posted.checked_add(1).unwrap();
+    client.live_bounties = client.live_bounties.checked_add(1).unwrap();
+    client.next_bounty_id = client.next_bounty_id.checked_add(1).unwrap();
+
+    emit!(BountyCreated {
+        bounty: bounty.key(),
+        bounty_id: bounty.bounty_id,
+        client: bounty.client_key,
+        creator: bounty.creator_wallet_key,
+        title: bounty.title.clone(),
+        reward: bounty.reward,
+        reward_mint: bounty.reward_mint,
+        deadline: bounty.deadline,
+        payout_policy: bounty.payout_policy,
+        timestamp: bounty.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateMilestoneBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Bounty::INIT_SPACE,
+        seeds = [b"bounty", client.next_bounty_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        init,
+        payer = authority,
+        space = 0,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, MilestoneStatus, PauseGroup, PlatformConfig};
+use crate::errors::{BountyError, DisputeError, MilestoneError};
+use crate::events::MilestoneEngagementEnded;
+
+pub fn end_milestone_engagement(ctx: Context<EndMilestoneEngagement>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    // As with deleting a bounty, contributors who submitted can't be dropped before
+    // the deadline
+    require!(
+        bounty.no_of_submissions == 0 || clock.unix_timestamp as u64 > bounty.deadline,
+        BountyError::DeadlineNotReached
+    );
+    // Submitted work has to be reviewed before the client can walk away
+    require!(
+        !bounty
+            .milestones
+            .iter()
+            .any(|milestone| milestone.status == MilestoneStatus::Submitted),
+        MilestoneError::MilestoneAwaitingReview
+    );
+
+    let mut refund_lamports: u64 = 0;
+    for milestone in bounty.milestones.iter_mut() {
+        if milestone.status == MilestoneStatus::Pending {
+            refund_lamports = milestone
+                .reward
+                .checked_mul(1_000_000_000)
+                .and_then(|lamports| refund_lamports.checked_add(lamports))
+                .ok_or(BountyError::InvalidRewardAmount)?;
+            milestone.status = MilestoneStatus::Refunded;
+        }
+    }
+
+    // Return the unreleased remainder of the escrow to the client and close it
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let escrow_balance = escrow.lamports();
+    require!(escrow_balance >= refund_lamports, BountyError::InvalidEscrowAccount);
+    **escrow.try_borrow_mut_lamports()? = 0;
+    **ctx.accounts.authority.try_borrow_mut_lamports()? = ctx.accounts.authority.lamports()
+        .checked_add(escrow_balance)
+        .unwrap();
+
+    bounty.live = false;
+    ctx.accounts.client.bounty_closed(bounty);
+    bounty.close_reason = if bounty.bounty_rewarded {
+        BountyCloseReason::Rewarded
+    } else {
+        BountyCloseReason::Cancelled
+    };
+
+    emit!(MilestoneEngagementEnded {
+        bounty: bounty.key(),
+        refunded: refund_lamports,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EndMilestoneEngagement<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.milestones.is_empty() @ MilestoneError::NotAMilestoneBounty,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub system_program: Program<'info, System>,
+}
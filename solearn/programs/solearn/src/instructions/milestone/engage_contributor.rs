@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client, Submission, SubmissionStatus};
+use crate::errors::{BountyError, MilestoneError, SubmissionError};
+use crate::events::ContributorEngaged;
+
+pub fn engage_contributor(ctx: Context<EngageContributor>) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let submission = &mut ctx.accounts.submission;
+    let clock = Clock::get()?;
+
+    require!(
+        bounty.selected_submission == Pubkey::default(),
+        MilestoneError::ContributorAlreadyEngaged
+    );
+    require!(
+        submission.status != SubmissionStatus::Rejected,
+        SubmissionError::SubmissionRejected
+    );
+
+    // No funds move here; each milestone is released on approval
+    bounty.selected_submission = submission.key();
+    submission.status = SubmissionStatus::Winner;
+    bounty.selected_user_wallet_key = submission.user_wallet_key;
+
+    emit!(ContributorEngaged {
+        bounty: bounty.key(),
+        submission: submission.key(),
+        contributor: submission.user_wallet_key,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EngageContributor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.milestones.is_empty() @ MilestoneError::NotAMilestoneBounty,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"submission", submission.user_wallet_key.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+}
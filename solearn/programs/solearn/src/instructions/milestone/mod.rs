@@ -0,0 +1,13 @@
+pub mod create_milestone_bounty;
+pub mod end_milestone_engagement;
+pub mod engage_contributor;
+pub mod release_milestone;
+pub mod review_milestone;
+pub mod submit_milestone;
+
+pub use create_milestone_bounty::*;
+pub use end_milestone_engagement::*;
+pub use engage_contributor::*;
+pub use release_milestone::*;
+pub use review_milestone::*;
+pub use submit_milestone::*;
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, MilestoneStatus, PauseGroup, PlatformConfig, User, BOUNTY_WON_POINTS, MILESTONE_REVIEW_SECONDS};
+use crate::errors::{BountyError, ConfigError, DisputeError, MilestoneError};
+use crate::events::MilestoneReviewed;
+
+pub fn release_milestone(ctx: Context<ReleaseMilestone>, milestone_index: u8) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let selected_user = &mut ctx.accounts.selected_user;
+    let clock = Clock::get()?;
+
+    let milestone_index = milestone_index as usize;
+    require!(
+        milestone_index < bounty.milestones.len(),
+        MilestoneError::MilestoneNotFound
+    );
+    let milestone = &bounty.milestones[milestone_index];
+    require!(
+        milestone.status == MilestoneStatus::Submitted,
+        MilestoneError::MilestoneNotSubmitted
+    );
+
+    // The client gets a review period per delivery; silence counts as approval
+    let releases_at = milestone.submitted_at.saturating_add(MILESTONE_REVIEW_SECONDS);
+    require!(
+        clock.unix_timestamp as u64 > releases_at,
+        MilestoneError::MilestoneReviewPeriodNotElapsed
+    );
+
+    let reward_lamports = milestone
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+    let fee_lamports = bounty.platform_fee(reward_lamports);
+
+    // Same payout as an approving review_milestone
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let escrow_balance = escrow.lamports();
+    **escrow.try_borrow_mut_lamports()? = escrow_balance
+        .checked_sub(reward_lamports)
+        .ok_or(BountyError::InvalidEscrowAccount)?;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += fee_lamports;
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += reward_lamports - fee_lamports;
+
+    bounty.milestones[milestone_index].status = MilestoneStatus::Approved;
+
+    // The client never reviewed, so only the contributor gains standing
+    if !bounty.bounty_rewarded {
+        bounty.bounty_rewarded = true;
+        selected_user.bounties_completed += 1;
+        selected_user.reputation.adjust(BOUNTY_WON_POINTS, clock.unix_timestamp as u64);
+    }
+    if bounty.all_milestones_settled() {
+        bounty.live = false;
+        client.bounty_closed(bounty);
+        bounty.close_reason = BountyCloseReason::Rewarded;
+    }
+
+    selected_user.earned += reward_lamports - fee_lamports;
+    client.rewarded += reward_lamports;
+
+    emit!(MilestoneReviewed {
+        bounty: bounty.key(),
+        milestone_index: milestone_index as u8,
+        approved: true,
+        amount: reward_lamports - fee_lamports,
+        platform_fee: fee_lamports,
+        auto_released: true,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    // The engaged contributor, who also receives the milestone reward
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+        constraint = !bounty.milestones.is_empty() @ MilestoneError::NotAMilestoneBounty,
+        constraint = bounty.selected_user_wallet_key == authority.key() @ MilestoneError::NotEngagedContributor,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
+    )]
+    pub selected_user: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
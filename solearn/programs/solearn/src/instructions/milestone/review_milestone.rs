@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, BountyCloseReason, Client, MilestoneStatus, PauseGroup, PlatformConfig, User, BOUNTY_PAID_POINTS, BOUNTY_WON_POINTS};
+use crate::errors::{BountyError, ConfigError, DisputeError, MilestoneError};
+use crate::events::MilestoneReviewed;
+
+pub fn review_milestone(
+    ctx: Context<ReviewMilestone>,
+    milestone_index: u8,
+    approved: bool,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Payouts)?;
+
+    let bounty = &mut ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let selected_user = &mut ctx.accounts.selected_user;
+    let clock = Clock::get()?;
+
+    let milestone_index = milestone_index as usize;
+    require!(
+        milestone_index < bounty.milestones.len(),
+        MilestoneError::MilestoneNotFound
+    );
+    require!(
+        bounty.milestones[milestone_index].status == MilestoneStatus::Submitted,
+        MilestoneError::MilestoneNotSubmitted
+    );
+
+    // Rejected work goes back to the contributor for another attempt
+    if !approved {
+        let milestone = &mut bounty.milestones[milestone_index];
+        milestone.status = MilestoneStatus::Pending;
+        milestone.work_url = String::new();
+        milestone.submitted_at = 0;
+
+        emit!(MilestoneReviewed {
+            bounty: bounty.key(),
+            milestone_index: milestone_index as u8,
+            approved: false,
+            amount: 0,
+            platform_fee: 0,
+            auto_released: false,
+            timestamp: clock.unix_timestamp as u64,
+        });
+        return Ok(());
+    }
+
+    let reward_lamports = bounty.milestones[milestone_index]
+        .reward
+        .checked_mul(1_000_000_000)
+        .ok_or(BountyError::InvalidRewardAmount)?;
+
+    let fee_lamports = bounty.platform_fee(reward_lamports);
+
+    // Release this milestone's slice of the escrow to the contributor, less the platform fee
+    let escrow = ctx.accounts.escrow_account.to_account_info();
+    let escrow_balance = escrow.lamports();
+    **escrow.try_borrow_mut_lamports()? = escrow_balance
+        .checked_sub(reward_lamports)
+        .ok_or(BountyError::InvalidEscrowAccount)?;
+    **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += fee_lamports;
+    **ctx.accounts.selected_user_wallet.to_account_info().try_borrow_mut_lamports()? += reward_lamports - fee_lamports;
+
+    bounty.milestones[milestone_index].status = MilestoneStatus::Approved;
+
+    // The first approved milestone counts the bounty as completed for the contributor
+    if !bounty.bounty_rewarded {
+        bounty.bounty_rewarded = true;
+        selected_user.bounties_completed += 1;
+        selected_user.reputation.adjust(BOUNTY_WON_POINTS, clock.unix_timestamp as u64);
+        client.reputation.adjust(BOUNTY_PAID_POINTS, clock.unix_timestamp as u64);
+    }
+    if bounty.all_milestones_settled() {
+        bounty.live = false;
+        client.bounty_closed(bounty);
+        bounty.close_reason = if bounty.bounty_rewarded {
+            BountyCloseReason::Rewarded
+        } else {
+            BountyCloseReason::Cancelled
+        };
+    }
+
+    selected_user.earned += reward_lamports - fee_lamports;
+    client.rewarded += reward_lamports;
+
+    emit!(MilestoneReviewed {
+        bounty: bounty.key(),
+        milestone_index: milestone_index as u8,
+        approved: true,
+        amount: reward_lamports - fee_lamports,
+        platform_fee: fee_lamports,
+        auto_released: false,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewMilestone<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.milestones.is_empty() @ MilestoneError::NotAMilestoneBounty,
+        constraint = !bounty.disputed @ DisputeError::EscrowFrozen,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        mut,
+        seeds = [b"user", bounty.selected_user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub selected_user: Account<'info, User>,
+    /// CHECK: This is the escrow account that holds SOL for the bounty reward
+    #[account(
+        mut,
+        seeds = [b"escrow", bounty.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    /// CHECK: This is the contributor's wallet that will receive the milestone reward
+    #[account(mut, address = bounty.selected_user_wallet_key)]
+    pub selected_user_wallet: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    /// CHECK: This is the platform treasury that receives the fee
+    #[account(mut, address = config.treasury @ ConfigError::InvalidTreasury)]
+    pub treasury: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
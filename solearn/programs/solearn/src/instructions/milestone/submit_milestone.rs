@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, MilestoneStatus};
+use crate::errors::{BountyError, MilestoneError};
+use crate::events::MilestoneSubmitted;
+
+pub fn submit_milestone(
+    ctx: Context<SubmitMilestone>,
+    milestone_index: u8,
+    work_url: String,
+) -> Result<()> {
+    let bounty = &mut ctx.accounts.bounty;
+    let clock = Clock::get()?;
+
+    require!(!work_url.is_empty(), MilestoneError::InvalidWorkLink);
+    require!(work_url.len() <= 200, MilestoneError::InvalidWorkLink);
+
+    let milestone_index = milestone_index as usize;
+    require!(
+        milestone_index < bounty.milestones.len(),
+        MilestoneError::MilestoneNotFound
+    );
+
+    // Work can be resubmitted until the client reviews it
+    let milestone = &mut bounty.milestones[milestone_index];
+    require!(
+        matches!(milestone.status, MilestoneStatus::Pending | MilestoneStatus::Submitted),
+        MilestoneError::MilestoneNotPending
+    );
+
+    milestone.work_url = work_url;
+    milestone.status = MilestoneStatus::Submitted;
+    milestone.submitted_at = clock.unix_timestamp as u64;
+
+    emit!(MilestoneSubmitted {
+        bounty: bounty.key(),
+        milestone_index: milestone_index as u8,
+        work_url: bounty.milestones[milestone_index].work_url.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SubmitMilestone<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.live @ BountyError::BountyAlreadyClosed,
+        constraint = !bounty.milestones.is_empty() @ MilestoneError::NotAMilestoneBounty,
+        constraint = bounty.selected_user_wallet_key == authority.key() @ MilestoneError::NotEngagedContributor,
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
@@ -1,7 +1,21 @@
 pub mod user;
 pub mod client;
 pub mod bounty;
+pub mod milestone;
+pub mod dispute;
+pub mod project;
+pub mod application;
+pub mod config;
+pub mod attestation;
+pub mod review;
 
 pub use user::*;
 pub use client::*;
-pub use bounty::*;
\ No newline at end of file
+pub use bounty::*;
+pub use milestone::*;
+pub use dispute::*;
+pub use project::*;
+pub use application::*;
+pub use config::*;
+pub use attestation::*;
+pub use review::*;
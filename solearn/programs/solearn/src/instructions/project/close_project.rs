@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::states::{Client, PauseGroup, PlatformConfig, Project, ProjectStatus};
+use crate::errors::ProjectError;
+use crate::events::ProjectClosed;
+
+#[derive(Accounts)]
+pub struct CloseProject<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = project.bump,
+        constraint = project.client_key == client.key(),
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectAlreadyClosed,
+    )]
+    pub project: Account<'info, Project>,
+    /// CHECK: This is the escrow account that holds SOL for the project reward
+    #[account(
+        mut,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn close_project(ctx: Context<CloseProject>) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Refunds)?;
+
+    let project = &ctx.accounts.project;
+    let clock = Clock::get()?;
+
+    // Once an applicant is approved the escrow is committed to them
+    require!(
+        project.approved_application == Pubkey::default(),
+        ProjectError::ProjectHasApprovedApplication
+    );
+
+    let escrow_balance = ctx.accounts.escrow_account.lamports();
+    if escrow_balance > 0 {
+        **ctx.accounts.escrow_account.try_borrow_mut_lamports()? = 0;
+        **ctx.accounts.authority.try_borrow_mut_lamports()? = ctx.accounts.authority.lamports()
+            .checked_add(escrow_balance)
+            .unwrap();
+    }
+    ctx.accounts.client.project_closed();
+
+    emit!(ProjectClosed {
+        project: project.key(),
+        refunded: escrow_balance,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
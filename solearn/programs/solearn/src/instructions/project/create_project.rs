@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::states::{Client, PauseGroup, PlatformConfig, Project, ProjectStatus};
+use crate::errors::{ClientError, ProjectError};
+use crate::events::ProjectCreated;
+
+pub fn create_project(
+    ctx: Context<CreateProject>,
+    title: String,
+    description: String,
+    reward: u64,
+    deadline: u64,
+    required_skills: Vec<String>,
+) -> Result<()> {
+    ctx.accounts.config.require_not_paused(PauseGroup::Deposits)?;
+
+    let project = &mut ctx.accounts.project;
+    let client = &mut ctx.accounts.client;
+    let clock = Clock::get()?;
+
+    require!(!client.is_deleted(), ClientError::ClientDeleted);
+
+    // Validate input
+    require!(
+        !title.is_empty() && title.len() <= 32,
+        ProjectError::InvalidProjectTitle
+    );
+    require!(!description.is_empty(), ProjectError::InvalidProjectDescription);
+    require!(reward > 0, ProjectError::InvalidRewardAmount);
+    require!(
+        deadline > clock.unix_timestamp as u64,
+        ProjectError::InvalidDeadline
+    );
+
+    // Projects are held to the same platform policy as SOL bounties
+    let config = &ctx.accounts.config;
+    config.check_min_reward(reward)?;
+    config.check_deadline(deadline, clock.unix_timestamp as u64)?;
+    let reward_lamports = reward
+        .checked_mul(1_000_000_000)
+        .ok_or(ProjectError::InvalidRewardAmount)?;
+
+    // Check if authority has sufficient SOL
+    require!(
+        ctx.accounts.authority.lamports() >= reward_lamports,
+        ProjectError::InsufficientSolBalance
+    );
+
+    // Transfer SOL from authority to escrow account
+    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.authority.key(),
+        &ctx.accounts.escrow_account.key(),
+        reward_lamports,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &transfer_instruction,
+        &[
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.escrow_account.to_account_info(),
+        ],
+    )?;
+
+    // Initialize project
+    project.creator_wallet_key = ctx.accounts.authority.key();
+    project.client_key = client.key();
+    project.project_id = client.next_project_id;
+    project.title = title;
+    project.description = description;
+    project.reward = reward;
+    project.status = ProjectStatus::Open;
+    project.created_at = clock.unix_timestamp as u64;
+    project.deadline = deadline;
+    project.required_skills = required_skills;
+    project.no_of_applications = 0;
+    project.approved_application = Pubkey::default();
+    project.approved_user_wallet_key = Pubkey::default();
+    project.escrow_account = ctx.accounts.escrow_account.key();
+    project.project_rewarded = false;
+    project.platform_fee_bps = config.fee_bps;
+    project.bump = ctx.bumps.project;
+    client.next_project_id = client.next_project_id.checked_add(1).unwrap();
+    client.live_projects = client.live_projects.checked_add(1).unwrap();
+
+    emit!(ProjectCreated {
+        project: project.key(),
+        project_id: project.project_id,
+        client: project.client_key,
+        creator: project.creator_wallet_key,
+        title: project.title.clone(),
+        reward: project.reward,
+        deadline: project.deadline,
+        timestamp: project.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateProject<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Project::INIT_SPACE,
+        seeds = [b"project", client.next_project_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub project: Account<'info, Project>,
+    /// CHECK: This is the escrow account that holds SOL for the project reward
+    #[account(
+        init,
+        payer = authority,
+        space = 0,
+        seeds = [b"escrow", project.key().as_ref()],
+        bump,
+    )]
+    pub escrow_account: UncheckedAccount<'info>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,7 @@
+pub mod close_project;
+pub mod create_project;
+pub mod update_project;
+
+pub use close_project::*;
+pub use create_project::*;
+pub use update_project::*;
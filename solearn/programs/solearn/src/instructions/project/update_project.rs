@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::states::{Client, PlatformConfig, Project, ProjectStatus};
+use crate::errors::ProjectError;
+use crate::events::ProjectUpdated;
+
+pub fn update_project(
+    ctx: Context<UpdateProject>,
+    description: String,
+    deadline: u64,
+) -> Result<()> {
+    let project = &mut ctx.accounts.project;
+    let clock = Clock::get()?;
+
+    require!(
+        project.no_of_applications == 0,
+        ProjectError::CannotUpdateWithApplications
+    );
+    require!(!description.is_empty(), ProjectError::InvalidProjectDescription);
+    require!(
+        deadline > clock.unix_timestamp as u64,
+        ProjectError::InvalidDeadline
+    );
+    ctx.accounts.config.check_deadline(deadline, clock.unix_timestamp as u64)?;
+
+    project.description = description;
+    project.deadline = deadline;
+
+    emit!(ProjectUpdated {
+        project: project.key(),
+        deadline,
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProject<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        mut,
+        seeds = [b"project", project.project_id.to_le_bytes().as_ref(), authority.key().as_ref()],
+        bump = project.bump,
+        constraint = project.client_key == client.key(),
+        constraint = project.status == ProjectStatus::Open @ ProjectError::ProjectAlreadyClosed,
+    )]
+    pub project: Account<'info, Project>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, PlatformConfig>,
+}
@@ -0,0 +1,5 @@
+pub mod review_client;
+pub mod review_contributor;
+
+pub use review_client::*;
+pub use review_contributor::*;
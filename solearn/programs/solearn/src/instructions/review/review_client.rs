@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client, Review, ReviewerRole, Submission, review_points};
+use crate::errors::ReviewError;
+use crate::events::ReviewSubmitted;
+
+pub fn review_client(ctx: Context<ReviewClient>, score: u8, comment: String) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let client = &mut ctx.accounts.client;
+    let clock = Clock::get()?;
+
+    require!(
+        !bounty.live && bounty.bounty_rewarded,
+        ReviewError::BountyNotCompleted
+    );
+    require!(
+        bounty.rewarded_submission(ctx.accounts.submission.key()),
+        ReviewError::NotBountyWinner
+    );
+    Review::validate(score, &comment)?;
+
+    let review = &mut ctx.accounts.review;
+    review.bounty = bounty.key();
+    review.reviewer = ctx.accounts.authority.key();
+    review.reviewee = client.authority;
+    review.reviewer_role = ReviewerRole::Contributor;
+    review.score = score;
+    review.comment = comment;
+    review.created_at = clock.unix_timestamp as u64;
+    review.bump = ctx.bumps.review;
+
+    client.rating_sum = client.rating_sum.checked_add(score as u64).unwrap();
+    client.rating_count = client.rating_count.checked_add(1).unwrap();
+    client.reputation.adjust(review_points(score), clock.unix_timestamp as u64);
+
+    emit!(ReviewSubmitted {
+        review: review.key(),
+        bounty: review.bounty,
+        reviewer: review.reviewer,
+        reviewee: review.reviewee,
+        reviewer_role: ReviewerRole::Contributor,
+        score,
+        timestamp: review.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewClient<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        seeds = [b"submission", authority.key().as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"client", bounty.creator_wallet_key.as_ref()],
+        bump,
+        constraint = bounty.client_key == client.key(),
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Review::INIT_SPACE,
+        seeds = [b"review", bounty.key().as_ref(), authority.key().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump,
+    )]
+    pub review: Account<'info, Review>,
+    pub system_program: Program<'info, System>,
+}
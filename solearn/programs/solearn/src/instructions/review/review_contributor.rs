@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::states::{Bounty, Client, Review, ReviewerRole, Submission, User, review_points};
+use crate::errors::ReviewError;
+use crate::events::ReviewSubmitted;
+
+pub fn review_contributor(ctx: Context<ReviewContributor>, score: u8, comment: String) -> Result<()> {
+    let bounty = &ctx.accounts.bounty;
+    let contributor = &mut ctx.accounts.contributor;
+    let clock = Clock::get()?;
+
+    require!(
+        !bounty.live && bounty.bounty_rewarded,
+        ReviewError::BountyNotCompleted
+    );
+    require!(
+        bounty.rewarded_submission(ctx.accounts.submission.key()),
+        ReviewError::NotBountyWinner
+    );
+    Review::validate(score, &comment)?;
+
+    let review = &mut ctx.accounts.review;
+    review.bounty = bounty.key();
+    review.reviewer = ctx.accounts.authority.key();
+    review.reviewee = contributor.authority;
+    review.reviewer_role = ReviewerRole::Client;
+    review.score = score;
+    review.comment = comment;
+    review.created_at = clock.unix_timestamp as u64;
+    review.bump = ctx.bumps.review;
+
+    contributor.rating_sum = contributor.rating_sum.checked_add(score as u64).unwrap();
+    contributor.rating_count = contributor.rating_count.checked_add(1).unwrap();
+    contributor.reputation.adjust(review_points(score), clock.unix_timestamp as u64);
+
+    emit!(ReviewSubmitted {
+        review: review.key(),
+        bounty: review.bounty,
+        reviewer: review.reviewer,
+        reviewee: review.reviewee,
+        reviewer_role: ReviewerRole::Client,
+        score,
+        timestamp: review.created_at,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReviewContributor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"client", authority.key().as_ref()],
+        bump,
+    )]
+    pub client: Account<'info, Client>,
+    #[account(
+        seeds = [b"bounty", bounty.address_seed().as_ref(), bounty.creator_wallet_key.as_ref()],
+        bump = bounty.bump,
+        constraint = bounty.client_key == client.key(),
+    )]
+    pub bounty: Account<'info, Bounty>,
+    #[account(
+        seeds = [b"submission", submission.user_wallet_key.as_ref(), bounty.key().as_ref()],
+        bump = submission.bump,
+    )]
+    pub submission: Account<'info, Submission>,
+    #[account(
+        mut,
+        seeds = [b"user", submission.user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub contributor: Account<'info, User>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Review::INIT_SPACE,
+        seeds = [b"review", bounty.key().as_ref(), authority.key().as_ref(), submission.user_wallet_key.as_ref()],
+        bump,
+    )]
+    pub review: Account<'info, Review>,
+    pub system_program: Program<'info, System>,
+}
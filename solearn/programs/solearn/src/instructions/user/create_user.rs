@@ -1,29 +1,64 @@
 use anchor_lang::prelude::*;
-use crate::states::User;
+use crate::states::{handle_hash, Country, HandleRecord, Reputation, Social, User, Versioned, USER_EMAIL_SEED, USER_NAME_SEED};
 use crate::errors::UserError;
+use crate::events::UserCreated;
 
 
 pub fn create_user(ctx: Context<CreateUser>, name: String, email: String, skills: Vec<String>) -> Result<()> {
     let user = &mut ctx.accounts.user;
     let clock = Clock::get()?;
 
+    // A deleted profile is re-created over its tombstone
+    let restoring = user.authority != Pubkey::default();
+    require!(!restoring || user.is_deleted(), UserError::UserAlreadyExists);
+
     // Validate input
     require!(!name.is_empty(), UserError::InvalidNameFormat);
     require!(!email.is_empty(), UserError::InvalidEmailFormat);
     require!(name.len() <= 32, UserError::InvalidNameFormat);
     require!(email.len() <= 100, UserError::InvalidEmailFormat);
 
+    // Reserve the username and email so no other profile can take them
+    let user_key = user.key();
+    let authority = ctx.accounts.authority.key();
+    require!(
+        ctx.accounts.name_record.claim(authority, user_key, ctx.bumps.name_record),
+        UserError::UsernameAlreadyTaken
+    );
+    require!(
+        ctx.accounts.email_record.claim(authority, user_key, ctx.bumps.email_record),
+        UserError::EmailAlreadyTaken
+    );
+
     user.authority = ctx.accounts.authority.key();
     user.name = name.clone();
     user.email = email;
     user.avatar = process_avatar(&name);
     user.bio = "Hi I'm a new user".to_string();
     user.skills = skills;
-    user.earned = 0;
-    user.bounties_submitted = 0;
-    user.bounties_completed = 0;
-    user.joined_at = clock.unix_timestamp as u64;
-    user.bump = ctx.bumps.user;
+    user.country = Country::default();
+    user.socials = Social::default();
+    user.deleted_at = 0;
+    // Earnings, ratings and reputation survive a re-creation, so deleting can't wipe a record
+    if !restoring {
+        user.earned = 0;
+        user.bounties_submitted = 0;
+        user.bounties_completed = 0;
+        user.joined_at = clock.unix_timestamp as u64;
+        user.bump = ctx.bumps.user;
+        user.version = User::LAYOUT_VERSION;
+        user.rating_sum = 0;
+        user.rating_count = 0;
+        user.reputation = Reputation::default();
+    }
+
+    emit!(UserCreated {
+        user: user.key(),
+        authority: user.authority,
+        name: user.name.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
     Ok(())
 }
 fn process_avatar(input: &str) -> String {
@@ -39,16 +74,33 @@ fn process_avatar(input: &str) -> String {
 }
 
 #[derive(Accounts)]
+#[instruction(name: String, email: String)]
 pub struct CreateUser<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
-        init,
+        init_if_needed,
         payer = authority,
         space = 8 + User::INIT_SPACE,
         seeds = [b"user", authority.key().as_ref()],
         bump
     )]
     pub user: Account<'info, User>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HandleRecord::INIT_SPACE,
+        seeds = [USER_NAME_SEED, handle_hash(&name).as_ref()],
+        bump
+    )]
+    pub name_record: Account<'info, HandleRecord>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HandleRecord::INIT_SPACE,
+        seeds = [USER_EMAIL_SEED, handle_hash(&email).as_ref()],
+        bump
+    )]
+    pub email_record: Account<'info, HandleRecord>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
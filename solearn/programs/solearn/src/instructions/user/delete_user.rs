@@ -1,8 +1,34 @@
 use anchor_lang::prelude::*;
-use crate::states::{User};
+use crate::states::{handle_hash, User, USER_EMAIL_SEED, USER_NAME_SEED};
+use crate::utils::release_handle;
+use crate::errors::UserError;
+use crate::events::UserDeleted;
 
 
-pub fn delete_user(_ctx: Context<DeleteUser>) -> Result<()> {
+pub fn delete_user(ctx: Context<DeleteUser>) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(!ctx.accounts.user.is_deleted(), UserError::UserDeleted);
+
+    // Free the username and email for other profiles
+    let authority = ctx.accounts.authority.to_account_info();
+    release_handle(&ctx.accounts.name_record, &authority)?;
+    release_handle(&ctx.accounts.email_record, &authority)?;
+
+    // Leave a tombstone rather than closing: bounties still hold this profile's
+    // submissions and payouts look it up. The name stays so history reads sensibly.
+    let user = &mut ctx.accounts.user;
+    user.email.clear();
+    user.bio.clear();
+    user.skills.clear();
+    user.socials = Default::default();
+    user.deleted_at = clock.unix_timestamp as u64;
+
+    emit!(UserDeleted {
+        user: ctx.accounts.user.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
     Ok(())
 }
 
@@ -14,9 +40,22 @@ pub struct DeleteUser<'info> {
     #[account(
         mut,
         seeds = [b"user", authority.key().as_ref()],
-        bump,
-        close = authority
+        bump
     )]
     pub user: Account<'info, User>,
+    /// CHECK: Record of the profile's username, released here. Absent for profiles created before the registry.
+    #[account(
+        mut,
+        seeds = [USER_NAME_SEED, handle_hash(&user.name).as_ref()],
+        bump
+    )]
+    pub name_record: UncheckedAccount<'info>,
+    /// CHECK: Record of the profile's email, released here. Absent for profiles created before the registry.
+    #[account(
+        mut,
+        seeds = [USER_EMAIL_SEED, handle_hash(&user.email).as_ref()],
+        bump
+    )]
+    pub email_record: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::states::{User, Versioned};
+use crate::errors::UserError;
+use crate::events::UserMigrated;
+use crate::utils::migrate_layout;
+
+// Brings a contributor profile written under an earlier layout up to the current one
+pub fn migrate_user(ctx: Context<MigrateUser>) -> Result<()> {
+    let user = ctx.accounts.user.to_account_info();
+    let clock = Clock::get()?;
+
+    let (_, found) = migrate_layout::<User>(
+        &user,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+    require!(found < User::LAYOUT_VERSION, UserError::UserAlreadyMigrated);
+
+    emit!(UserMigrated {
+        user: user.key(),
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateUser<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: User account in its previous layout, which `Account` cannot deserialize yet
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub user: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,11 @@
 pub mod create_user;
 pub mod update_user;
 pub mod delete_user;
+pub mod set_user_details;
+pub mod migrate_user;
 
 pub use create_user::*;
 pub use update_user::*;
 pub use delete_user::*;
+pub use set_user_details::*;
+pub use migrate_user::*;
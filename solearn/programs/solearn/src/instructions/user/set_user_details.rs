@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::states::{Country, Social, User};
+use crate::errors::UserError;
+use crate::events::UserUpdated;
+
+pub fn set_user_details(ctx: Context<SetUserDetails>, country: Country, socials: Social) -> Result<()> {
+    let user = &mut ctx.accounts.user;
+    let clock = Clock::get()?;
+
+    require!(!user.is_deleted(), UserError::UserDeleted);
+    require!(socials.is_valid(), UserError::InvalidSocials);
+
+    user.country = country;
+    user.socials = socials;
+
+    emit!(UserUpdated {
+        user: user.key(),
+        authority: user.authority,
+        name: user.name.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetUserDetails<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump
+    )]
+    pub user: Account<'info, User>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,22 +1,53 @@
 use anchor_lang::prelude::*;
-use crate::states::User;
+use crate::states::{handle_hash, HandleRecord, User, USER_EMAIL_SEED, USER_NAME_SEED};
+use crate::utils::release_handle;
 use crate::errors::UserError;
+use crate::events::UserUpdated;
 
 
 pub fn update_user(ctx: Context<UpdateUser>, name: String, email: String, bio: String, skills: Vec<String>) -> Result<()> {
     let user = &mut ctx.accounts.user;
+    let clock = Clock::get()?;
     
+    require!(!user.is_deleted(), UserError::UserDeleted);
+
     // Validate input
     require!(!name.is_empty(), UserError::InvalidNameFormat);
     require!(!email.is_empty(), UserError::InvalidEmailFormat);
     require!(name.len() <= 32, UserError::InvalidNameFormat);
+    require!(email.len() <= 100, UserError::InvalidEmailFormat);
     require!(bio.len() <= 280, UserError::InvalidBioFormat);
-    
+
+    // Move the username and email reservations when they change
+    let user_key = user.key();
+    let authority = ctx.accounts.authority.to_account_info();
+    require!(
+        ctx.accounts.name_record.claim(authority.key(), user_key, ctx.bumps.name_record),
+        UserError::UsernameAlreadyTaken
+    );
+    require!(
+        ctx.accounts.email_record.claim(authority.key(), user_key, ctx.bumps.email_record),
+        UserError::EmailAlreadyTaken
+    );
+    if ctx.accounts.current_name_record.key() != ctx.accounts.name_record.key() {
+        release_handle(&ctx.accounts.current_name_record, &authority)?;
+    }
+    if ctx.accounts.current_email_record.key() != ctx.accounts.email_record.key() {
+        release_handle(&ctx.accounts.current_email_record, &authority)?;
+    }
+
     user.name = name.clone();
     user.email = email;
     user.avatar = process_avatar(&name);
     user.bio = bio;
     user.skills = skills;
+
+    emit!(UserUpdated {
+        user: user.key(),
+        authority: user.authority,
+        name: user.name.clone(),
+        timestamp: clock.unix_timestamp as u64,
+    });
     Ok(())
 }
 
@@ -33,17 +64,45 @@ fn process_avatar(input: &str) -> String {
 }
 
 #[derive(Accounts)]
+#[instruction(name: String, email: String)]
 pub struct UpdateUser<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
         mut,
         seeds = [b"user", authority.key().as_ref()],
-        realloc = 8 + User::INIT_SPACE,
-        realloc::payer = authority,
-        realloc::zero = true,
         bump
     )]
     pub user: Account<'info, User>,
+    /// CHECK: Record of the profile's current username, released if the name changes. Absent for profiles created before the registry.
+    #[account(
+        mut,
+        seeds = [USER_NAME_SEED, handle_hash(&user.name).as_ref()],
+        bump
+    )]
+    pub current_name_record: UncheckedAccount<'info>,
+    /// CHECK: Record of the profile's current email, released if the email changes. Absent for profiles created before the registry.
+    #[account(
+        mut,
+        seeds = [USER_EMAIL_SEED, handle_hash(&user.email).as_ref()],
+        bump
+    )]
+    pub current_email_record: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HandleRecord::INIT_SPACE,
+        seeds = [USER_NAME_SEED, handle_hash(&name).as_ref()],
+        bump
+    )]
+    pub name_record: Account<'info, HandleRecord>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + HandleRecord::INIT_SPACE,
+        seeds = [USER_EMAIL_SEED, handle_hash(&email).as_ref()],
+        bump
+    )]
+    pub email_record: Account<'info, HandleRecord>,
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
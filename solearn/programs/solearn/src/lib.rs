@@ -3,9 +3,11 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod states;
 pub mod errors;
+pub mod events;
+pub mod utils;
 
 use instructions::*;
-use states::*;
+use states::{Country, DisputeRuling, Industries, MilestoneParams, PauseGroup, PayoutPolicy, SkillGate, Social, SubmissionStatus};
 
 declare_id!("4a8Lgwhx7oQZUEUHq2m3B5yZJkZXrzLthYRjn3TCRCfc");
 
@@ -13,6 +15,39 @@ declare_id!("4a8Lgwhx7oQZUEUHq2m3B5yZJkZXrzLthYRjn3TCRCfc");
 pub mod solearn {
     use super::*;
 
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        fee_bps: u16,
+        treasury: Pubkey,
+        min_reward: u64,
+        max_deadline_duration: u64,
+    ) -> Result<()> {
+        instructions::config::initialize_config(ctx, fee_bps, treasury, min_reward, max_deadline_duration)
+    }
+
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        new_authority: Pubkey,
+        fee_bps: u16,
+        treasury: Pubkey,
+        min_reward: u64,
+        max_deadline_duration: u64,
+    ) -> Result<()> {
+        instructions::config::update_config(ctx, new_authority, fee_bps, treasury, min_reward, max_deadline_duration)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        instructions::config::set_guardian(ctx, guardian)
+    }
+
+    pub fn pause(ctx: Context<SetPause>, group: Option<PauseGroup>) -> Result<()> {
+        instructions::config::pause(ctx, group)
+    }
+
+    pub fn unpause(ctx: Context<SetPause>, group: Option<PauseGroup>) -> Result<()> {
+        instructions::config::unpause(ctx, group)
+    }
+
     pub fn create_user(ctx: Context<CreateUser>, name: String, email: String, skills: Vec<String>) -> Result<()> {
         instructions::user::create_user::create_user(ctx, name, email, skills)
     }
@@ -25,6 +60,14 @@ pub mod solearn {
         instructions::user::delete_user::delete_user(ctx)
     }
 
+    pub fn set_user_details(ctx: Context<SetUserDetails>, country: Country, socials: Social) -> Result<()> {
+        instructions::user::set_user_details::set_user_details(ctx, country, socials)
+    }
+
+    pub fn migrate_user(ctx: Context<MigrateUser>) -> Result<()> {
+        instructions::user::migrate_user::migrate_user(ctx)
+    }
+
     pub fn create_client(ctx: Context<CreateClient>, company_name: String, company_email: String, company_link: String) -> Result<()> {
         instructions::client::create_client(ctx,company_name,company_email,company_link)
     }
@@ -37,6 +80,15 @@ pub mod solearn {
         instructions::client::delete_client(ctx)
     }
 
+    pub fn set_client_details(ctx: Context<SetClientDetails>, industry: Industries, country: Country, socials: Social) -> Result<()> {
+        instructions::client::set_client_details(ctx, industry, country, socials)
+    }
+
+    pub fn migrate_client(ctx: Context<MigrateClient>) -> Result<()> {
+        instructions::client::migrate_client(ctx)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn create_bounty(
         ctx: Context<CreateBounty>,
         title: String,
@@ -44,8 +96,10 @@ pub mod solearn {
         reward: u64,
         deadline: u64,
         skills_needed: Vec<String>,
+        payout_policy: PayoutPolicy,
+        review_period: u64,
     ) -> Result<()> {
-        instructions::bounty::create_bounty::create_bounty(ctx, title, description, reward, deadline, skills_needed)
+        instructions::bounty::create_bounty::create_bounty(ctx, title, description, reward, deadline, skills_needed, payout_policy, review_period)
     }
 
     pub fn update_bounty(
@@ -57,8 +111,20 @@ pub mod solearn {
         instructions::bounty::update_bounty::update_bounty(ctx, new_title, new_description, new_deadline)
     }
 
-    pub fn delete_bounty(ctx: Context<DeleteBounty>,title: String) -> Result<()> {
-        instructions::bounty::delete_bounty::delete_bounty(ctx,title)
+    pub fn delete_bounty(ctx: Context<DeleteBounty>) -> Result<()> {
+        instructions::bounty::delete_bounty::delete_bounty(ctx)
+    }
+
+    pub fn migrate_bounty(ctx: Context<MigrateBounty>, title: String) -> Result<()> {
+        instructions::bounty::migrate_bounty::migrate_bounty(ctx, title)
+    }
+
+    pub fn migrate_bounty_layout(ctx: Context<MigrateBountyLayout>) -> Result<()> {
+        instructions::bounty::migrate_bounty_layout::migrate_bounty_layout(ctx)
+    }
+
+    pub fn migrate_submission(ctx: Context<MigrateSubmission>) -> Result<()> {
+        instructions::bounty::migrate_submission::migrate_submission(ctx)
     }
 
     pub fn create_submission(
@@ -69,9 +135,222 @@ pub mod solearn {
         instructions::bounty::create_submission::create_submission(ctx, submission_desc, submission_link)
     }
 
+    pub fn update_submission(
+        ctx: Context<UpdateSubmission>,
+        submission_desc: String,
+        submission_link: String,
+    ) -> Result<()> {
+        instructions::bounty::update_submission::update_submission(ctx, submission_desc, submission_link)
+    }
+
+    pub fn withdraw_submission(ctx: Context<WithdrawSubmission>) -> Result<()> {
+        instructions::bounty::withdraw_submission::withdraw_submission(ctx)
+    }
+
+    pub fn review_submission(
+        ctx: Context<ReviewSubmission>,
+        status: SubmissionStatus,
+        feedback: String,
+    ) -> Result<()> {
+        instructions::bounty::review_submission::review_submission(ctx, status, feedback)
+    }
+
     pub fn select_submission(ctx: Context<SelectSubmission>) -> Result<()> {
         instructions::bounty::select_submission::select_submission(ctx)
     }
 
+    pub fn create_token_bounty(
+        ctx: Context<CreateTokenBounty>,
+        title: String,
+        description: String,
+        reward: u64,
+        deadline: u64,
+        skills_needed: Vec<String>,
+    ) -> Result<()> {
+        instructions::bounty::create_token_bounty::create_token_bounty(ctx, title, description, reward, deadline, skills_needed)
+    }
+
+    pub fn delete_token_bounty(ctx: Context<DeleteTokenBounty>) -> Result<()> {
+        instructions::bounty::delete_token_bounty::delete_token_bounty(ctx)
+    }
+
+    pub fn select_token_submission(ctx: Context<SelectTokenSubmission>) -> Result<()> {
+        instructions::bounty::select_token_submission::select_token_submission(ctx)
+    }
+
+    pub fn create_tiered_bounty(
+        ctx: Context<CreateTieredBounty>,
+        title: String,
+        description: String,
+        prizes: Vec<u64>,
+        deadline: u64,
+        skills_needed: Vec<String>,
+    ) -> Result<()> {
+        instructions::bounty::create_tiered_bounty::create_tiered_bounty(ctx, title, description, prizes, deadline, skills_needed)
+    }
+
+    pub fn award_prize_tier(ctx: Context<AwardPrizeTier>, tier_index: u8) -> Result<()> {
+        instructions::bounty::award_prize_tier::award_prize_tier(ctx, tier_index)
+    }
+
+    pub fn forfeit_prize_tier(ctx: Context<ForfeitPrizeTier>, tier_index: u8) -> Result<()> {
+        instructions::bounty::forfeit_prize_tier::forfeit_prize_tier(ctx, tier_index)
+    }
+
+    pub fn expire_bounty(ctx: Context<ExpireBounty>) -> Result<()> {
+        instructions::bounty::expire_bounty::expire_bounty(ctx)
+    }
+
+    pub fn expire_token_bounty(ctx: Context<ExpireTokenBounty>) -> Result<()> {
+        instructions::bounty::expire_token_bounty::expire_token_bounty(ctx)
+    }
+
+    pub fn auto_release_bounty(ctx: Context<AutoReleaseBounty>) -> Result<()> {
+        instructions::bounty::auto_release_bounty::auto_release_bounty(ctx)
+    }
+
+    pub fn release_held_payout(ctx: Context<ReleaseHeldPayout>) -> Result<()> {
+        instructions::bounty::release_held_payout::release_held_payout(ctx)
+    }
+
+    pub fn set_bounty_arbiters(ctx: Context<SetBountyArbiters>, arbiters: Vec<Pubkey>, arbiter_fee_bps: u16) -> Result<()> {
+        instructions::bounty::set_bounty_arbiters::set_bounty_arbiters(ctx, arbiters, arbiter_fee_bps)
+    }
+
+    pub fn set_bounty_skill_gate(ctx: Context<SetBountySkillGate>, skill_gate: SkillGate, attested_skills: bool) -> Result<()> {
+        instructions::bounty::set_bounty_skill_gate::set_bounty_skill_gate(ctx, skill_gate, attested_skills)
+    }
+
+    pub fn set_bounty_min_reputation(ctx: Context<SetBountyMinReputation>, min_reputation: u64) -> Result<()> {
+        instructions::bounty::set_bounty_min_reputation::set_bounty_min_reputation(ctx, min_reputation)
+    }
+
+    pub fn create_milestone_bounty(
+        ctx: Context<CreateMilestoneBounty>,
+        title: String,
+        description: String,
+        milestones: Vec<MilestoneParams>,
+        deadline: u64,
+        skills_needed: Vec<String>,
+    ) -> Result<()> {
+        instructions::milestone::create_milestone_bounty(ctx, title, description, milestones, deadline, skills_needed)
+    }
+
+    pub fn engage_contributor(ctx: Context<EngageContributor>) -> Result<()> {
+        instructions::milestone::engage_contributor(ctx)
+    }
+
+    pub fn submit_milestone(ctx: Context<SubmitMilestone>, milestone_index: u8, work_url: String) -> Result<()> {
+        instructions::milestone::submit_milestone(ctx, milestone_index, work_url)
+    }
+
+    pub fn review_milestone(ctx: Context<ReviewMilestone>, milestone_index: u8, approved: bool) -> Result<()> {
+        instructions::milestone::review_milestone(ctx, milestone_index, approved)
+    }
+
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>, milestone_index: u8) -> Result<()> {
+        instructions::milestone::release_milestone(ctx, milestone_index)
+    }
+
+    pub fn end_milestone_engagement(ctx: Context<EndMilestoneEngagement>) -> Result<()> {
+        instructions::milestone::end_milestone_engagement(ctx)
+    }
+
+    pub fn open_dispute(ctx: Context<OpenDispute>, evidence_link: String) -> Result<()> {
+        instructions::dispute::open_dispute(ctx, evidence_link)
+    }
+
+    pub fn submit_evidence(ctx: Context<SubmitEvidence>, evidence_link: String) -> Result<()> {
+        instructions::dispute::submit_evidence(ctx, evidence_link)
+    }
+
+    pub fn cast_ruling<'info>(ctx: Context<'_, '_, '_, 'info, CastRuling<'info>>, ruling: DisputeRuling) -> Result<()> {
+        instructions::dispute::cast_ruling(ctx, ruling)
+    }
+
+    pub fn resolve_expired_dispute(ctx: Context<ResolveExpiredDispute>) -> Result<()> {
+        instructions::dispute::resolve_expired_dispute(ctx)
+    }
+
+    pub fn approve_issuer(ctx: Context<ApproveIssuer>) -> Result<()> {
+        instructions::attestation::approve_issuer(ctx)
+    }
+
+    pub fn remove_issuer(ctx: Context<RemoveIssuer>) -> Result<()> {
+        instructions::attestation::remove_issuer(ctx)
+    }
+
+    pub fn issue_attestation(ctx: Context<IssueAttestation>, skill: String, expires_at: u64) -> Result<()> {
+        instructions::attestation::issue_attestation(ctx, skill, expires_at)
+    }
+
+    pub fn attest_from_bounty(ctx: Context<AttestFromBounty>, skill: String) -> Result<()> {
+        instructions::attestation::attest_from_bounty(ctx, skill)
+    }
+
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        instructions::attestation::revoke_attestation(ctx)
+    }
+
+    pub fn review_contributor(ctx: Context<ReviewContributor>, score: u8, comment: String) -> Result<()> {
+        instructions::review::review_contributor(ctx, score, comment)
+    }
+
+    pub fn review_client(ctx: Context<ReviewClient>, score: u8, comment: String) -> Result<()> {
+        instructions::review::review_client(ctx, score, comment)
+    }
+
+    pub fn create_project(
+        ctx: Context<CreateProject>,
+        title: String,
+        description: String,
+        reward: u64,
+        deadline: u64,
+        skills_needed: Vec<String>,
+    ) -> Result<()> {
+        instructions::project::create_project(ctx, title, description, reward, deadline, skills_needed)
+    }
+
+    pub fn update_project(ctx: Context<UpdateProject>, description: String, deadline: u64) -> Result<()> {
+        instructions::project::update_project(ctx, description, deadline)
+    }
+
+    pub fn close_project(ctx: Context<CloseProject>) -> Result<()> {
+        instructions::project::close_project(ctx)
+    }
+
+    pub fn apply_to_project(ctx: Context<ApplyToProject>, description: String, link: String) -> Result<()> {
+        instructions::application::apply_to_project(ctx, description, link)
+    }
+
+    pub fn approve_application(ctx: Context<ReviewApplication>) -> Result<()> {
+        instructions::application::approve_application(ctx)
+    }
+
+    pub fn reject_application(ctx: Context<ReviewApplication>) -> Result<()> {
+        instructions::application::reject_application(ctx)
+    }
+
+    pub fn unapprove_application(ctx: Context<ReviewApplication>) -> Result<()> {
+        instructions::application::unapprove_application(ctx)
+    }
+
+    pub fn submit_work(
+        ctx: Context<SubmitWork>,
+        work_description: String,
+        work_link: String,
+        additional_files_link: String,
+    ) -> Result<()> {
+        instructions::application::submit_work(ctx, work_description, work_link, additional_files_link)
+    }
+
+    pub fn approve_work(ctx: Context<ApproveWork>) -> Result<()> {
+        instructions::application::approve_work(ctx)
+    }
+
+    pub fn reject_work(ctx: Context<RejectWork>) -> Result<()> {
+        instructions::application::reject_work(ctx)
+    }
+
     
 }
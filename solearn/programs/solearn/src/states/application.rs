@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::states::{ApplicationStatus, WorkStatus};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Application {
+    pub user_wallet_key: Pubkey,
+    pub user_key: Pubkey,
+    pub project_key: Pubkey,
+    #[max_len(500)]
+    pub description: String,
+    #[max_len(280)]
+    pub link: String,
+    pub status: ApplicationStatus,
+    pub applied_at: u64,
+    #[max_len(500)]
+    pub work_description: String,
+    #[max_len(280)]
+    pub work_link: String,
+    #[max_len(280)]
+    pub additional_files_link: String,
+    pub work_status: WorkStatus,
+    pub work_submitted_at: u64,
+    pub bump: u8,
+}
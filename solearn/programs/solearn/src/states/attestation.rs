@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum AttestationSource {
+    // Issued by the platform authority
+    Platform,
+    // Issued by a client the platform approved as an issuer
+    Client,
+    // Earned by winning a bounty that required the skill
+    Bounty,
+}
+
+// Statement that a contributor holds a skill. The address is derived from the
+// contributor, the hashed skill and the issuer, so each issuer attests a skill once.
+#[account]
+#[derive(InitSpace)]
+pub struct Attestation {
+    pub user_wallet: Pubkey,
+    // Issuing wallet, or the bounty for attestations earned from one
+    pub issuer: Pubkey,
+    pub source: AttestationSource,
+    #[max_len(32)]
+    pub skill: String,
+    pub issued_at: u64,
+    // 0 if the attestation never expires
+    pub expires_at: u64,
+    // 0 while the attestation stands
+    pub revoked_at: u64,
+    pub bump: u8,
+}
+
+impl Attestation {
+    pub fn is_active(&self, now: u64) -> bool {
+        self.revoked_at == 0 && (self.expires_at == 0 || now < self.expires_at)
+    }
+}
+
+// Client wallet the platform allows to issue attestations
+#[account]
+#[derive(InitSpace)]
+pub struct TrustedIssuer {
+    pub wallet: Pubkey,
+    pub approved_at: u64,
+    pub bump: u8,
+}
+
+// Skills compare trimmed and case-insensitively
+pub fn normalize_skill(skill: &str) -> String {
+    skill.trim().to_ascii_lowercase()
+}
+
+pub fn skill_hash(skill: &str) -> [u8; 32] {
+    hash(normalize_skill(skill).as_bytes()).to_bytes()
+}
@@ -1,4 +1,14 @@
 use anchor_lang::prelude::*;
+use crate::states::{BountyCloseReason, LegacyBountyV0, Milestone, MilestoneStatus, PayoutPolicy, PrizeTier, PrizeTierStatus, SkillGate, Versioned, normalize_skill, RESERVED_SPACE};
+
+pub const MAX_PRIZE_TIERS: usize = 5;
+pub const MAX_MILESTONES: usize = 5;
+pub const MAX_ARBITERS: usize = 3;
+pub const MAX_ARBITER_FEE_BPS: u16 = 1_000;
+// Time the client has after the deadline to pick a winner before anyone can expire the bounty
+pub const REVIEW_WINDOW_SECONDS: u64 = 7 * 24 * 60 * 60;
+// Time the client has to review delivered milestone work before the contributor can release it
+pub const MILESTONE_REVIEW_SECONDS: u64 = 7 * 24 * 60 * 60;
 
 #[account]
 #[derive(InitSpace)]
@@ -11,14 +21,176 @@ pub struct Bounty {
     pub description: String,
     pub reward: u64,
     pub live: bool,
+    pub close_reason: BountyCloseReason,
     pub created_at: u64,
     pub deadline: u64,
     #[max_len(10, 32)]
     pub required_skills: Vec<String>,
     pub no_of_submissions: u64,
+    pub first_submission: Pubkey,
     pub selected_submission: Pubkey,
     pub selected_user_wallet_key: Pubkey,
     pub escrow_account: Pubkey,
+    // Pubkey::default() for native SOL bounties
+    pub reward_mint: Pubkey,
     pub bounty_rewarded: bool,
+    pub payout_policy: PayoutPolicy,
+    // Seconds after the deadline before a guaranteed payout can be released
+    pub review_period: u64,
+    // Platform fee taken from the payout, fixed when the bounty is posted
+    pub platform_fee_bps: u16,
+    // Empty for single-winner bounties
+    #[max_len(MAX_PRIZE_TIERS)]
+    pub prize_tiers: Vec<PrizeTier>,
+    // Empty for bounties paid out in a single release
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>,
+    // Panel that rules on disputes, fixed before the first submission
+    #[max_len(MAX_ARBITERS)]
+    pub arbiters: Vec<Pubkey>,
+    pub arbiter_fee_bps: u16,
+    pub disputed: bool,
     pub bump: u8,
-}
\ No newline at end of file
+    // Per-client sequence number the bounty's address is derived from
+    pub bounty_id: u64,
+    // Bounties posted before ids existed stay addressed by their original title
+    #[max_len(32)]
+    pub legacy_title_seed: String,
+    // Which of `required_skills` a contributor needs before they can submit
+    pub skill_gate: SkillGate,
+    // Only attested skills count towards the gate, not the ones a profile lists
+    pub attested_skills: bool,
+    // Decayed reputation a contributor needs before they can submit
+    pub min_reputation: u64,
+    pub version: u8,
+    // Whether the client's `live_bounties` counts this bounty; bounties posted before
+    // the counter existed aren't
+    pub counted_live: bool,
+    // A paneled bounty's winner is paid once the dispute window after their
+    // selection closes, and held until then
+    pub payout_held: bool,
+    pub selected_at: u64,
+    pub reserved: [u8; RESERVED_SPACE - 10],
+}
+
+impl Versioned for Bounty {
+    const LAYOUT_VERSION: u8 = 2;
+
+    type Legacy = LegacyBountyV0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.version = Self::LAYOUT_VERSION;
+    }
+
+    // A SOL bounty the client selects for, without fee, tiers, milestones or panel.
+    // It keeps its title-derived address until `migrate_bounty` gives it an id.
+    fn from_legacy(legacy: LegacyBountyV0) -> Self {
+        Self {
+            creator_wallet_key: legacy.creator_wallet_key,
+            client_key: legacy.client_key,
+            title: legacy.title,
+            description: legacy.description,
+            reward: legacy.reward,
+            live: legacy.live,
+            close_reason: if legacy.bounty_rewarded {
+                BountyCloseReason::Rewarded
+            } else {
+                BountyCloseReason::None
+            },
+            created_at: legacy.created_at,
+            deadline: legacy.deadline,
+            required_skills: legacy.required_skills,
+            no_of_submissions: legacy.no_of_submissions,
+            first_submission: Pubkey::default(),
+            selected_submission: legacy.selected_submission,
+            selected_user_wallet_key: legacy.selected_user_wallet_key,
+            escrow_account: legacy.escrow_account,
+            reward_mint: Pubkey::default(),
+            bounty_rewarded: legacy.bounty_rewarded,
+            payout_policy: PayoutPolicy::ClientSelects,
+            review_period: 0,
+            platform_fee_bps: 0,
+            prize_tiers: Vec::new(),
+            milestones: Vec::new(),
+            arbiters: Vec::new(),
+            arbiter_fee_bps: 0,
+            disputed: false,
+            bump: legacy.bump,
+            bounty_id: 0,
+            legacy_title_seed: String::new(),
+            skill_gate: SkillGate::Open,
+            attested_skills: false,
+            min_reputation: 0,
+            version: 0,
+            counted_live: false,
+            payout_held: false,
+            selected_at: 0,
+            reserved: [0; RESERVED_SPACE - 10],
+        }
+    }
+}
+
+
+impl Bounty {
+    // Seed between b"bounty" and the creator's wallet in the bounty's PDA
+    pub fn address_seed(&self) -> Vec<u8> {
+        if self.legacy_title_seed.is_empty() {
+            self.bounty_id.to_le_bytes().to_vec()
+        } else {
+            self.legacy_title_seed.as_bytes().to_vec()
+        }
+    }
+
+    // Whether a contributor holding `skills` passes the skill gate. Skills are
+    // compared trimmed and case-insensitively.
+    pub fn accepts_skills(&self, skills: &[String]) -> bool {
+        let held: Vec<String> = skills.iter().map(|skill| normalize_skill(skill)).collect();
+        let matched = self
+            .required_skills
+            .iter()
+            .filter(|required| held.contains(&normalize_skill(required)))
+            .count();
+
+        match self.skill_gate {
+            SkillGate::Open => true,
+            SkillGate::Any => matched > 0,
+            SkillGate::All => matched == self.required_skills.len(),
+            SkillGate::AtLeast(count) => matched >= count as usize,
+        }
+    }
+
+    // Platform's cut of `amount`, at the rate fixed when the bounty was posted
+    pub fn platform_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.platform_fee_bps as u128 / 10_000) as u64
+    }
+
+    // Whether `submission` was paid out, as the single winner or for a prize tier
+    pub fn rewarded_submission(&self, submission: Pubkey) -> bool {
+        (self.bounty_rewarded && self.selected_submission == submission)
+            || self
+                .prize_tiers
+                .iter()
+                .any(|tier| tier.status == PrizeTierStatus::Awarded && tier.submission == submission)
+    }
+
+    // Whether `submission` was chosen, including an engaged milestone contributor not yet paid
+    pub fn picked_submission(&self, submission: Pubkey) -> bool {
+        self.selected_submission == submission || self.rewarded_submission(submission)
+    }
+
+    pub fn all_prize_tiers_settled(&self) -> bool {
+        self.prize_tiers
+            .iter()
+            .all(|tier| tier.status != PrizeTierStatus::Open)
+    }
+
+    pub fn all_milestones_settled(&self) -> bool {
+        self.milestones.iter().all(|milestone| {
+            matches!(milestone.status, MilestoneStatus::Approved | MilestoneStatus::Refunded)
+        })
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::states::{Bounty, Country, Industries, LegacyClientV0, Reputation, Social, Versioned, RESERVED_SPACE};
 
 
 // User account
@@ -20,4 +21,87 @@ pub struct Client {
     pub rewarded: u64,
     pub bounties_posted: u64,
     pub bump: u8,
+    // Id the client's next bounty is posted under
+    pub next_bounty_id: u64,
+    // Scores contributors gave the client, averaged as rating_sum / rating_count
+    pub rating_sum: u64,
+    pub rating_count: u64,
+    // Decaying score built from payouts, disputes, expirations and reviews
+    pub reputation: Reputation,
+    // Bounties can be browsed by the industry of the client posting them
+    pub industry: Industries,
+    pub country: Country,
+    pub socials: Social,
+    pub version: u8,
+    // Bounties and projects still holding the client's funds; the profile can't be
+    // deleted until both are zero. Ones opened before layout 2 aren't counted.
+    pub live_bounties: u64,
+    pub live_projects: u64,
+    // Set when the profile is deleted; the account stays behind as a tombstone
+    pub deleted_at: u64,
+    // Id the client's next project is posted under
+    pub next_project_id: u64,
+    pub reserved: [u8; RESERVED_SPACE - 32],
 }
+
+impl Versioned for Client {
+    const LAYOUT_VERSION: u8 = 2;
+
+    type Legacy = LegacyClientV0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.version = Self::LAYOUT_VERSION;
+    }
+
+    // Bounties from before ids are title-addressed, so ids start from zero, and
+    // they aren't counted as live
+    fn from_legacy(legacy: LegacyClientV0) -> Self {
+        Self {
+            authority: legacy.authority,
+            company_name: legacy.company_name,
+            company_email: legacy.company_email,
+            company_avatar: legacy.company_avatar,
+            company_link: legacy.company_link,
+            company_bio: legacy.company_bio,
+            joined_at: legacy.joined_at,
+            rewarded: legacy.rewarded,
+            bounties_posted: legacy.bounties_posted,
+            bump: legacy.bump,
+            next_bounty_id: 0,
+            rating_sum: 0,
+            rating_count: 0,
+            reputation: Reputation::default(),
+            industry: Industries::default(),
+            country: Country::default(),
+            socials: Social::default(),
+            version: 0,
+            live_bounties: 0,
+            live_projects: 0,
+            deleted_at: 0,
+            next_project_id: 0,
+            reserved: [0; RESERVED_SPACE - 32],
+        }
+    }
+}
+
+impl Client {
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at != 0
+    }
+
+    pub fn bounty_closed(&mut self, bounty: &Bounty) {
+        if bounty.counted_live {
+            self.live_bounties = self.live_bounties.checked_sub(1).unwrap();
+        }
+    }
+
+    // Saturates since projects opened before layout 2 were never counted
+    pub fn project_closed(&mut self) {
+        self.live_projects = self.live_projects.saturating_sub(1);
+    }
+}
+
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::states::Bounty;
+use crate::errors::{BountyError, ConfigError};
+
+pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum PauseGroup {
+    // Instructions that fund a new escrow
+    Deposits,
+    // Instructions that release escrow to contributors
+    Payouts,
+    // Instructions that return escrow to the creator
+    Refunds,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlatformConfig {
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    // Smallest reward, in whole SOL, a bounty can be posted with
+    pub min_reward: u64,
+    // Longest a bounty can run, in seconds from creation
+    pub max_deadline_duration: u64,
+    // Key allowed to pause and unpause fund-moving instructions
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub deposits_paused: bool,
+    pub payouts_paused: bool,
+    pub refunds_paused: bool,
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    pub fn group_paused(&self, group: PauseGroup) -> bool {
+        match group {
+            PauseGroup::Deposits => self.deposits_paused,
+            PauseGroup::Payouts => self.payouts_paused,
+            PauseGroup::Refunds => self.refunds_paused,
+        }
+    }
+
+    pub fn set_group_paused(&mut self, group: PauseGroup, paused: bool) {
+        match group {
+            PauseGroup::Deposits => self.deposits_paused = paused,
+            PauseGroup::Payouts => self.payouts_paused = paused,
+            PauseGroup::Refunds => self.refunds_paused = paused,
+        }
+    }
+
+    pub fn require_not_paused(&self, group: PauseGroup) -> Result<()> {
+        require!(!self.paused, ConfigError::ProgramPaused);
+        require!(!self.group_paused(group), ConfigError::InstructionGroupPaused);
+        Ok(())
+    }
+    // Deadlines must be in the future and no further out than the platform allows
+    pub fn check_deadline(&self, deadline: u64, now: u64) -> Result<()> {
+        require!(deadline > now, BountyError::InvalidDeadline);
+        require!(
+            deadline - now <= self.max_deadline_duration,
+            ConfigError::DeadlineBeyondMaximum
+        );
+        Ok(())
+    }
+
+    // The minimum is in whole SOL, so token rewards, counted in their own mint's
+    // units, can't be held to it
+    pub fn check_min_reward(&self, reward: u64) -> Result<()> {
+        require!(reward >= self.min_reward, ConfigError::RewardBelowMinimum);
+        Ok(())
+    }
+
+    // Every bounty creation path runs the same policy checks and fixes the fee rate
+    // in force at posting time, so later config changes never apply retroactively.
+    // SOL bounties are also held to `check_min_reward`
+    pub fn apply_bounty_policy(&self, bounty: &mut Bounty, deadline: u64, now: u64) -> Result<()> {
+        self.check_deadline(deadline, now)?;
+        bounty.platform_fee_bps = self.fee_bps;
+        Ok(())
+    }
+}
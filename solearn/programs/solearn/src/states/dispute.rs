@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::states::MAX_ARBITERS;
+
+pub const MAX_EVIDENCE: usize = 3;
+// Time the panel has to reach a majority before anyone can lift the freeze
+pub const DISPUTE_RULING_SECONDS: u64 = 14 * 24 * 60 * 60;
+// Time after a paneled bounty's winner is picked during which a passed-over
+// contributor can dispute the pick
+pub const SELECTION_DISPUTE_SECONDS: u64 = 3 * 24 * 60 * 60;
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    Resolved,
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum DisputeRuling {
+    PayContributor,
+    // Share of the escrow, after arbiter fees, that goes to the contributor
+    Split { contributor_bps: u16 },
+    Refund,
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct DisputeVote {
+    pub arbiter: Pubkey,
+    pub ruling: DisputeRuling,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Dispute {
+    pub bounty: Pubkey,
+    pub submission: Pubkey,
+    pub client_wallet: Pubkey,
+    pub contributor_wallet: Pubkey,
+    pub opened_by: Pubkey,
+    #[max_len(MAX_ARBITERS)]
+    pub arbiters: Vec<Pubkey>,
+    #[max_len(MAX_ARBITERS)]
+    pub votes: Vec<DisputeVote>,
+    pub arbiter_fee_bps: u16,
+    #[max_len(MAX_EVIDENCE, 200)]
+    pub client_evidence: Vec<String>,
+    #[max_len(MAX_EVIDENCE, 200)]
+    pub contributor_evidence: Vec<String>,
+    pub status: DisputeStatus,
+    // None while open, and after a panel lapses without reaching a majority
+    pub ruling: Option<DisputeRuling>,
+    pub opened_at: u64,
+    pub resolved_at: u64,
+    pub bump: u8,
+}
@@ -5,6 +5,73 @@ pub enum BountyStatus {
     Open,
     Closed,
 }
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum BountyCloseReason {
+    None,
+    Rewarded,
+    Cancelled,
+    Expired,
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum PayoutPolicy {
+    // The client must call select_submission
+    ClientSelects,
+    // The earliest submission is paid if the client misses the review period
+    GuaranteedToEarliest,
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum SkillGate {
+    // Anyone with a profile can submit
+    Open,
+    // The contributor lists at least one of the required skills
+    Any,
+    // The contributor lists every required skill
+    All,
+    // The contributor lists at least this many of the required skills
+    AtLeast(u8),
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum PrizeTierStatus {
+    Open,
+    Awarded,
+    Forfeited,
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PrizeTier {
+    pub reward: u64,
+    pub status: PrizeTierStatus,
+    pub submission: Pubkey,
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum MilestoneStatus {
+    Pending,
+    Submitted,
+    Approved,
+    Refunded,
+}
+
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct Milestone {
+    pub reward: u64,
+    #[max_len(100)]
+    pub deliverable: String,
+    #[max_len(200)]
+    pub work_url: String,
+    pub status: MilestoneStatus,
+    pub submitted_at: u64,
+}
+
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct MilestoneParams {
+    pub reward: u64,
+    pub deliverable: String,
+}
+
 #[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub enum ProjectStatus {
     Open,
@@ -18,8 +85,31 @@ pub enum ApplicationStatus {
     Rejected,
 }
 
+// Pending comes first, so submissions from before review states read as unreviewed
+#[derive(InitSpace, Default, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum SubmissionStatus {
+    #[default]
+    Pending,
+    Shortlisted,
+    // The contributor is asked to revise; revising puts it back to Pending
+    ChangesRequested,
+    // The contributor can withdraw it and reclaim its rent at any time
+    Rejected,
+    // Set by selection, tier awards and milestone engagement, never by review
+    Winner,
+}
 
-#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum WorkStatus {
+    NotSubmitted,
+    Submitted,
+    Approved,
+    Rejected,
+}
+
+
+// Variants are stored by position, so new ones go at the end
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub enum Country {
     India,
     USA,
@@ -30,6 +120,7 @@ pub enum Country {
     Other,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Country {
     fn default() -> Self {
         Country::Other
@@ -49,7 +140,19 @@ pub struct Social {
     pub website: String,
 }
 
-#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+impl Social {
+    // Handles and links fit their slots and contain no whitespace; the website,
+    // when set, is an https link
+    pub fn is_valid(&self) -> bool {
+        let fields = [&self.twitter, &self.facebook, &self.linkedin, &self.website];
+        fields
+            .iter()
+            .all(|field| field.len() <= 32 && !field.chars().any(char::is_whitespace))
+            && (self.website.is_empty() || self.website.starts_with("https://"))
+    }
+}
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
 pub enum Industries {
     DAO,
     Defi,
@@ -60,6 +163,7 @@ pub enum Industries {
     Other,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Industries {
     fn default() -> Self {
         Industries::Other
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hash;
+
+pub const USER_NAME_SEED: &[u8] = b"user_name";
+pub const USER_EMAIL_SEED: &[u8] = b"user_email";
+pub const COMPANY_NAME_SEED: &[u8] = b"company_name";
+
+// Registry entry reserving a handle for one profile. Its address is derived from the
+// hashed handle, so a handle resolves to its profile without scanning accounts.
+#[account]
+#[derive(InitSpace)]
+pub struct HandleRecord {
+    // Wallet that owns the profile holding the handle
+    pub authority: Pubkey,
+    // User or Client account the handle points at
+    pub profile: Pubkey,
+    pub bump: u8,
+}
+
+impl HandleRecord {
+    // Takes the handle for `profile` unless another wallet already holds it
+    pub fn claim(&mut self, authority: Pubkey, profile: Pubkey, bump: u8) -> bool {
+        if self.authority != Pubkey::default() && self.authority != authority {
+            return false;
+        }
+        self.authority = authority;
+        self.profile = profile;
+        self.bump = bump;
+        true
+    }
+}
+
+// Handles compare case-insensitively and ignore surrounding whitespace
+pub fn normalize_handle(handle: &str) -> String {
+    handle.trim().to_ascii_lowercase()
+}
+
+// Handles are only ever used as a seed through their hash, which fits the 32-byte
+// seed limit whatever the handle's length
+pub fn handle_hash(handle: &str) -> [u8; 32] {
+    hash(normalize_handle(handle).as_bytes()).to_bytes()
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+// Layouts accounts were written with before they were versioned. They share the
+// current accounts' discriminators and are only read by the `migrate_*` instructions,
+// which map them field by field into the current layout.
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyUserV0 {
+    pub authority: Pubkey,
+    #[max_len(32)]
+    pub name: String,
+    #[max_len(100)]
+    pub email: String,
+    #[max_len(32)]
+    pub avatar: String,
+    #[max_len(280)]
+    pub bio: String,
+    #[max_len(10, 32)]
+    pub skills: Vec<String>,
+    pub joined_at: u64,
+    pub earned: u64,
+    pub bounties_submitted: u64,
+    pub bounties_completed: u64,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyClientV0 {
+    pub authority: Pubkey,
+    #[max_len(32)]
+    pub company_name: String,
+    #[max_len(32)]
+    pub company_email: String,
+    #[max_len(32)]
+    pub company_avatar: String,
+    #[max_len(32)]
+    pub company_link: String,
+    #[max_len(280)]
+    pub company_bio: String,
+    pub joined_at: u64,
+    pub rewarded: u64,
+    pub bounties_posted: u64,
+    pub bump: u8,
+}
+
+// Addressed by [b"bounty", title, creator]
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyBountyV0 {
+    pub creator_wallet_key: Pubkey,
+    pub client_key: Pubkey,
+    #[max_len(32)]
+    pub title: String,
+    #[max_len(500)]
+    pub description: String,
+    pub reward: u64,
+    pub live: bool,
+    pub created_at: u64,
+    pub deadline: u64,
+    #[max_len(10, 32)]
+    pub required_skills: Vec<String>,
+    pub no_of_submissions: u64,
+    pub selected_submission: Pubkey,
+    pub selected_user_wallet_key: Pubkey,
+    pub escrow_account: Pubkey,
+    pub bounty_rewarded: bool,
+    pub bump: u8,
+}
+
+#[derive(InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct LegacySubmissionV0 {
+    pub user_wallet_key: Pubkey,
+    pub user_key: Pubkey,
+    pub bounty_key: Pubkey,
+    #[max_len(500)]
+    pub description: String,
+    #[max_len(280)]
+    pub work_url: String,
+    pub bump: u8,
+}
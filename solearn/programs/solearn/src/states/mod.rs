@@ -3,9 +3,29 @@ pub mod client;
 pub mod enums;
 pub mod bounty;
 pub mod submission;
+pub mod dispute;
+pub mod project;
+pub mod application;
+pub mod config;
+pub mod handle;
+pub mod attestation;
+pub mod review;
+pub mod reputation;
+pub mod version;
+pub mod legacy;
 
 pub use user::*;
 pub use client::*;
 pub use enums::*;
 pub use bounty::*;
-pub use submission::*;
\ No newline at end of file
+pub use submission::*;
+pub use dispute::*;
+pub use project::*;
+pub use application::*;
+pub use config::*;
+pub use handle::*;
+pub use attestation::*;
+pub use review::*;
+pub use reputation::*;
+pub use version::*;
+pub use legacy::*;
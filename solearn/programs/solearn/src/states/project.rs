@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::states::ProjectStatus;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Project {
+    pub creator_wallet_key: Pubkey,
+    pub client_key: Pubkey,
+    // Id the project was posted under, the client's next_project_id at the time
+    pub project_id: u64,
+    #[max_len(32)]
+    pub title: String,
+    #[max_len(500)]
+    pub description: String,
+    pub reward: u64,
+    pub status: ProjectStatus,
+    pub created_at: u64,
+    pub deadline: u64,
+    #[max_len(10, 32)]
+    pub required_skills: Vec<String>,
+    pub no_of_applications: u64,
+    pub approved_application: Pubkey,
+    pub approved_user_wallet_key: Pubkey,
+    pub escrow_account: Pubkey,
+    pub project_rewarded: bool,
+    // Platform fee rate in force when the project was posted
+    pub platform_fee_bps: u16,
+    pub bump: u8,
+}
+
+impl Project {
+    pub fn platform_fee(&self, amount: u64) -> u64 {
+        (amount as u128 * self.platform_fee_bps as u128 / 10_000) as u64
+    }
+}
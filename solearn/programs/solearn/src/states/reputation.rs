@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+// Reputation halves every half-life without new activity
+pub const REPUTATION_HALF_LIFE_SECONDS: u64 = 180 * 24 * 60 * 60;
+
+// Points added to or taken from a score, before decay
+pub const BOUNTY_WON_POINTS: i64 = 100;
+pub const BOUNTY_PAID_POINTS: i64 = 50;
+pub const DISPUTE_LOST_POINTS: i64 = -50;
+pub const BOUNTY_EXPIRED_POINTS: i64 = -25;
+// Per star above or below a three-star review
+pub const REVIEW_POINTS_PER_STAR: i64 = 10;
+
+#[derive(InitSpace, Clone, Copy, Default, AnchorSerialize, AnchorDeserialize)]
+pub struct Reputation {
+    // Score as of `updated_at`; read it through `current`
+    pub score: u64,
+    pub updated_at: u64,
+}
+
+impl Reputation {
+    // Score decayed to `now`: halved per elapsed half-life, and linearly in between
+    pub fn current(&self, now: u64) -> u64 {
+        let elapsed = now.saturating_sub(self.updated_at);
+        let halvings = elapsed / REPUTATION_HALF_LIFE_SECONDS;
+        if halvings >= 64 {
+            return 0;
+        }
+        let halved = self.score >> halvings;
+        let into_half_life = elapsed % REPUTATION_HALF_LIFE_SECONDS;
+        let decay = halved as u128 * into_half_life as u128 / (2 * REPUTATION_HALF_LIFE_SECONDS) as u128;
+        halved - decay as u64
+    }
+
+    // Applies `points` on top of the decayed score, never going below zero
+    pub fn adjust(&mut self, points: i64, now: u64) {
+        let current = self.current(now);
+        self.score = if points >= 0 {
+            current.saturating_add(points as u64)
+        } else {
+            current.saturating_sub(points.unsigned_abs())
+        };
+        self.updated_at = now;
+    }
+}
+
+pub fn review_points(score: u8) -> i64 {
+    (score as i64 - 3) * REVIEW_POINTS_PER_STAR
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::errors::ReviewError;
+
+pub const MAX_REVIEW_SCORE: u8 = 5;
+pub const MAX_REVIEW_COMMENT_LEN: usize = 200;
+
+#[derive(InitSpace, Clone, Copy, AnchorSerialize, AnchorDeserialize, PartialEq)]
+pub enum ReviewerRole {
+    // The client rating a winning contributor
+    Client,
+    // A winning contributor rating the client
+    Contributor,
+}
+
+// Feedback one side of a completed bounty leaves the other, once per pair
+#[account]
+#[derive(InitSpace)]
+pub struct Review {
+    pub bounty: Pubkey,
+    pub reviewer: Pubkey,
+    pub reviewee: Pubkey,
+    pub reviewer_role: ReviewerRole,
+    // 1 to MAX_REVIEW_SCORE
+    pub score: u8,
+    #[max_len(MAX_REVIEW_COMMENT_LEN)]
+    pub comment: String,
+    pub created_at: u64,
+    pub bump: u8,
+}
+
+impl Review {
+    pub fn validate(score: u8, comment: &str) -> Result<()> {
+        require!(
+            (1..=MAX_REVIEW_SCORE).contains(&score),
+            ReviewError::InvalidScore
+        );
+        require!(
+            comment.len() <= MAX_REVIEW_COMMENT_LEN,
+            ReviewError::CommentTooLong
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,16 @@
 use anchor_lang::prelude::*;
+use crate::states::{LegacySubmissionV0, SubmissionStatus, Versioned, RESERVED_SPACE};
+
+pub const MAX_SUBMISSION_REVISIONS: usize = 3;
+pub const MAX_SUBMISSION_FEEDBACK_LEN: usize = 200;
+
+// A work link the contributor replaced, and when they replaced it
+#[derive(InitSpace, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SubmissionRevision {
+    #[max_len(280)]
+    pub work_url: String,
+    pub revised_at: u64,
+}
 
 #[account]
 #[derive(InitSpace)]
@@ -10,5 +22,64 @@ pub struct Submission {
     pub description: String,
     #[max_len(280)]
     pub work_url: String,
+    pub submitted_at: u64,
     pub bump: u8,
-}
\ No newline at end of file
+    pub version: u8,
+    // Where the client's review stands, with when it was last set
+    pub status: SubmissionStatus,
+    pub reviewed_at: u64,
+    pub reserved: [u8; RESERVED_SPACE - 9],
+    // Earlier work links, oldest first; only the latest MAX_SUBMISSION_REVISIONS are kept
+    #[max_len(MAX_SUBMISSION_REVISIONS)]
+    pub revisions: Vec<SubmissionRevision>,
+    // The client's note with the latest review, empty if none was given
+    #[max_len(MAX_SUBMISSION_FEEDBACK_LEN)]
+    pub feedback: String,
+}
+
+impl Versioned for Submission {
+    const LAYOUT_VERSION: u8 = 3;
+
+    type Legacy = LegacySubmissionV0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.version = Self::LAYOUT_VERSION;
+    }
+
+    // When the work was submitted wasn't recorded
+    fn from_legacy(legacy: LegacySubmissionV0) -> Self {
+        Self {
+            user_wallet_key: legacy.user_wallet_key,
+            user_key: legacy.user_key,
+            bounty_key: legacy.bounty_key,
+            description: legacy.description,
+            work_url: legacy.work_url,
+            submitted_at: 0,
+            bump: legacy.bump,
+            version: 0,
+            status: SubmissionStatus::Pending,
+            reviewed_at: 0,
+            reserved: [0; RESERVED_SPACE - 9],
+            revisions: Vec::new(),
+            feedback: String::new(),
+        }
+    }
+}
+
+impl Submission {
+    // Replaces the work link, keeping the one it supersedes in the history
+    pub fn revise(&mut self, work_url: String, now: u64) {
+        if self.revisions.len() == MAX_SUBMISSION_REVISIONS {
+            self.revisions.remove(0);
+        }
+        let previous = std::mem::replace(&mut self.work_url, work_url);
+        self.revisions.push(SubmissionRevision {
+            work_url: previous,
+            revised_at: now,
+        });
+    }
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::states::{ Social, Country};
+use crate::states::{Country, LegacyUserV0, Reputation, Social, Versioned, RESERVED_SPACE};
 
 // User account
 #[account]
@@ -21,5 +21,62 @@ pub struct User {
     pub bounties_submitted: u64,
     pub bounties_completed: u64,
     pub bump: u8,
+    // Scores clients gave the user, averaged as rating_sum / rating_count
+    pub rating_sum: u64,
+    pub rating_count: u64,
+    // Decaying score built from payouts, disputes, expirations and reviews
+    pub reputation: Reputation,
+    pub country: Country,
+    pub socials: Social,
+    pub version: u8,
+    // Set when the profile is deleted; the account stays behind as a tombstone so
+    // pending submissions can still be selected and paid
+    pub deleted_at: u64,
+    pub reserved: [u8; RESERVED_SPACE - 8],
 }
 
+impl Versioned for User {
+    const LAYOUT_VERSION: u8 = 2;
+
+    type Legacy = LegacyUserV0;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.version = Self::LAYOUT_VERSION;
+    }
+
+    fn from_legacy(legacy: LegacyUserV0) -> Self {
+        Self {
+            authority: legacy.authority,
+            name: legacy.name,
+            email: legacy.email,
+            avatar: legacy.avatar,
+            bio: legacy.bio,
+            skills: legacy.skills,
+            joined_at: legacy.joined_at,
+            earned: legacy.earned,
+            bounties_submitted: legacy.bounties_submitted,
+            bounties_completed: legacy.bounties_completed,
+            bump: legacy.bump,
+            rating_sum: 0,
+            rating_count: 0,
+            reputation: Reputation::default(),
+            country: Country::default(),
+            socials: Social::default(),
+            version: 0,
+            deleted_at: 0,
+            reserved: [0; RESERVED_SPACE - 8],
+        }
+    }
+}
+
+impl User {
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at != 0
+    }
+}
+
+
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+// Spare bytes at the end of every versioned account. New fields are carved out of
+// them instead of appended, so accounts keep their size and deserialize unchanged.
+pub const RESERVED_SPACE: usize = 64;
+
+// Accounts that record the layout they were written with. Accounts allocated before
+// layouts were versioned are still in their `Legacy` layout, which doesn't line up
+// with the current one; `migrate_*` instructions read them through it and map them
+// over with `from_legacy`, as version 0.
+//
+// To add a field: take its bytes from `reserved`, bump LAYOUT_VERSION, and have
+// `upgrade` set the field for older versions unless zero is already its default.
+// A field too large for the reserved bytes goes after `reserved` instead; the
+// migration grows the account, so it reads as zero (an empty Vec) until set.
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator + Space {
+    const LAYOUT_VERSION: u8;
+
+    type Legacy: AnchorDeserialize + Space;
+
+    fn version(&self) -> u8;
+
+    fn upgrade(&mut self);
+
+    fn from_legacy(legacy: Self::Legacy) -> Self;
+}
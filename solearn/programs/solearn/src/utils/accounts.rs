@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::states::{Attestation, Client, HandleRecord, Versioned};
+use crate::errors::AttestationError;
+
+// Grows a program-owned account to `new_len`, topping its rent up from `payer`.
+// The added bytes are zeroed, so fields appended to a layout read as zero.
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let shortfall = rent.minimum_balance(new_len).saturating_sub(account.lamports());
+    if shortfall > 0 {
+        let cpi_accounts = Transfer {
+            from: payer.clone(),
+            to: account.clone(),
+        };
+        transfer(CpiContext::new(system_program.clone(), cpi_accounts), shortfall)?;
+    }
+    account.resize(new_len)?;
+
+    Ok(())
+}
+
+// Grows a versioned account to its current layout and upgrades it in place. An
+// account still sized for its legacy layout is read through that layout and mapped
+// over. Returns the upgraded state along with the version it was found at.
+pub fn migrate_layout<'info, T: Versioned>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(T, u8)> {
+    let legacy = if account.data_len() == 8 + T::Legacy::INIT_SPACE {
+        let data = account.try_borrow_data()?;
+        require!(
+            data.starts_with(T::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        Some(T::Legacy::deserialize(&mut &data[8..])?)
+    } else {
+        None
+    };
+
+    let new_len = 8 + T::INIT_SPACE;
+    if account.data_len() < new_len {
+        grow_account(account, payer, system_program, new_len)?;
+    }
+
+    let mut state = match legacy {
+        Some(legacy) => T::from_legacy(legacy),
+        None => T::try_deserialize(&mut &account.try_borrow_data()?[..])?,
+    };
+    let found = state.version();
+    state.upgrade();
+    // Clear what the old layout left behind, so later appended fields read as zero
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    state.try_serialize(&mut &mut data[..])?;
+
+    Ok((state, found))
+}
+
+// Applies `update` to the client profile at `client`. A closed profile, or one
+// still on a layout without reputation, is left alone.
+pub fn update_client_profile(client: &AccountInfo, update: impl FnOnce(&mut Client)) -> Result<()> {
+    if client.owner != &crate::ID || client.data_len() < 8 + Client::INIT_SPACE {
+        return Ok(());
+    }
+    let mut profile = Client::try_deserialize(&mut &client.try_borrow_data()?[..])?;
+    update(&mut profile);
+    profile.try_serialize(&mut &mut client.try_borrow_mut_data()?[..])?;
+
+    Ok(())
+}
+
+// Closes the handle record at `record` if `authority` holds it, refunding its rent.
+// Profiles created before the registry existed hold no record, so a missing or
+// foreign record is left alone.
+pub fn release_handle<'info>(record: &AccountInfo<'info>, authority: &AccountInfo<'info>) -> Result<()> {
+    if record.owner != &crate::ID || record.data_is_empty() {
+        return Ok(());
+    }
+    let held = HandleRecord::try_deserialize(&mut &record.try_borrow_data()?[..])?;
+    if held.authority != authority.key() {
+        return Ok(());
+    }
+
+    let lamports = record.lamports();
+    **record.try_borrow_mut_lamports()? = 0;
+    **authority.try_borrow_mut_lamports()? = authority.lamports()
+        .checked_add(lamports)
+        .unwrap();
+    record.assign(&System::id());
+    record.resize(0)?;
+
+    Ok(())
+}
+
+// Skills attested to `user_wallet` by `attestations`. Every account must be an
+// attestation of that contributor; revoked and expired ones are skipped.
+pub fn attested_skills(attestations: &[AccountInfo], user_wallet: &Pubkey, now: u64) -> Result<Vec<String>> {
+    let mut skills = Vec::with_capacity(attestations.len());
+    for info in attestations {
+        require!(info.owner == &crate::ID, AttestationError::InvalidAttestation);
+        let attestation = Attestation::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            attestation.user_wallet == *user_wallet,
+            AttestationError::InvalidAttestation
+        );
+        if attestation.is_active(now) {
+            skills.push(attestation.skill);
+        }
+    }
+
+    Ok(skills)
+}
@@ -3,6 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { Solearn } from "../target/types/solearn";
 import { Keypair, PublicKey, LAMPORTS_PER_SOL, SystemProgram } from "@solana/web3.js";
 import BN from "bn.js";
+import { createHash } from "crypto";
 import { assert, expect } from "chai";
 
 describe("solearn", () => {
@@ -19,6 +20,15 @@ describe("solearn", () => {
   let user1Pda: PublicKey;
   let user2Pda: PublicKey;
   let user3Pda: PublicKey;
+  let configPda: PublicKey;
+
+  // Usernames, emails and company names are claimed through a record keyed by the
+  // hash of the normalized handle
+  const handleRecord = (seed: string, handle: string) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from(seed), createHash("sha256").update(handle.trim().toLowerCase()).digest()],
+      program.programId
+    )[0];
 
   before(async () => {
     // Generate test keypairs
@@ -50,6 +60,23 @@ describe("solearn", () => {
       [Buffer.from("user"), user3.publicKey.toBuffer()],
       program.programId
     );
+
+    // The platform config is initialized once by the upgrade authority
+    [configPda] = PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId);
+    const [programDataPda] = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    );
+    await program.methods
+      .initializeConfig(0, provider.wallet.publicKey, new BN(0), new BN(30 * 24 * 60 * 60))
+      .accountsStrict({
+        authority: provider.wallet.publicKey,
+        config: configPda,
+        program: program.programId,
+        programData: programDataPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
   });
 
   describe("User Management", () => {
@@ -64,6 +91,8 @@ describe("solearn", () => {
           .accountsStrict({
             authority: user1.publicKey,
             user: user1Pda,
+            nameRecord: handleRecord("user_name", name),
+            emailRecord: handleRecord("user_email", email),
             systemProgram: SystemProgram.programId,
           })
           .signers([user1])
@@ -88,6 +117,8 @@ describe("solearn", () => {
           .accountsStrict({
             authority: user2.publicKey,
             user: user2Pda,
+            nameRecord: handleRecord("user_name", name),
+            emailRecord: handleRecord("user_email", email),
             systemProgram: SystemProgram.programId,
           })
           .signers([user2])
@@ -108,6 +139,8 @@ describe("solearn", () => {
           .accountsStrict({
             authority: user3.publicKey,
             user: user3Pda,
+            nameRecord: handleRecord("user_name", name),
+            emailRecord: handleRecord("user_email", email),
             systemProgram: SystemProgram.programId,
           })
           .signers([user3])
@@ -134,6 +167,8 @@ describe("solearn", () => {
             .accountsStrict({
               authority: user.publicKey,
               user: userPda,
+              nameRecord: handleRecord("user_name", name),
+              emailRecord: handleRecord("user_email", email),
               systemProgram: SystemProgram.programId,
             })
             .signers([user])
@@ -158,6 +193,10 @@ describe("solearn", () => {
           .accountsStrict({
             authority: user1.publicKey,
             user: user1Pda,
+            currentNameRecord: handleRecord("user_name", "John Doe"),
+            currentEmailRecord: handleRecord("user_email", "john.doe@example.com"),
+            nameRecord: handleRecord("user_name", newName),
+            emailRecord: handleRecord("user_email", newEmail),
             systemProgram: SystemProgram.programId,
           })
           .signers([user1])
@@ -178,6 +217,10 @@ describe("solearn", () => {
             .accountsStrict({
               authority: user1.publicKey,
               user: user1Pda,
+              currentNameRecord: handleRecord("user_name", "John Updated"),
+              currentEmailRecord: handleRecord("user_email", "john.updated@example.com"),
+              nameRecord: handleRecord("user_name", ""),
+              emailRecord: handleRecord("user_email", "a@a.com"),
               systemProgram: SystemProgram.programId,
             })
             .signers([user1])
@@ -195,6 +238,10 @@ describe("solearn", () => {
             .accountsStrict({
               authority: user2.publicKey, // user2 trying to update user1's profile
               user: user1Pda,
+              currentNameRecord: handleRecord("user_name", "John Updated"),
+              currentEmailRecord: handleRecord("user_email", "john.updated@example.com"),
+              nameRecord: handleRecord("user_name", "new name"),
+              emailRecord: handleRecord("user_email", "new@email.com"),
               systemProgram: SystemProgram.programId,
             })
             .signers([user2])
@@ -213,17 +260,17 @@ describe("solearn", () => {
           .accountsStrict({
             authority: user1.publicKey,
             user: user1Pda,
+            nameRecord: handleRecord("user_name", "John Updated"),
+            emailRecord: handleRecord("user_email", "john.updated@example.com"),
             systemProgram: SystemProgram.programId,
           })
           .signers([user1])
           .rpc();
 
-        try {
-          await program.account.user.fetch(user1Pda);
-          assert.fail("User account should be closed");
-        } catch (error) {
-          expect(error.toString()).to.include("Account does not exist");
-        }
+        // The profile stays behind as a tombstone
+        const userAccount = await program.account.user.fetch(user1Pda);
+        expect(userAccount.deletedAt.toNumber()).to.not.equal(0);
+        expect(userAccount.email).to.equal("");
       });
 
       it("Should fail to delete user with unauthorized account", async () => {
@@ -233,6 +280,8 @@ describe("solearn", () => {
             .accountsStrict({
               authority: user3.publicKey, // user3 trying to delete user2's profile
               user: user2Pda,
+              nameRecord: handleRecord("user_name", "Alice"),
+              emailRecord: handleRecord("user_email", "alice@example.com"),
               systemProgram: SystemProgram.programId,
             })
             .signers([user3])
@@ -277,6 +326,7 @@ describe("solearn", () => {
         .accountsStrict({
           authority: client1.publicKey,
           client: client1Pda,
+          nameRecord: handleRecord("company_name", companyName),
           systemProgram: SystemProgram.programId,
         })
         .signers([client1])
@@ -299,6 +349,8 @@ describe("solearn", () => {
         .accountsStrict({
           authority: client1.publicKey,
           client: client1Pda,
+          currentNameRecord: handleRecord("company_name", "Test Corp"),
+          nameRecord: handleRecord("company_name", newCompanyName),
           systemProgram: SystemProgram.programId,
         })
         .signers([client1])
@@ -317,17 +369,16 @@ describe("solearn", () => {
         .accountsStrict({
           authority: client1.publicKey,
           client: client1Pda,
+          nameRecord: handleRecord("company_name", "Updated Corp"),
           systemProgram: SystemProgram.programId,
         })
         .signers([client1])
         .rpc();
 
-      try {
-        await program.account.client.fetch(client1Pda);
-        assert.fail("Client account should be closed");
-      } catch (error) {
-        expect(error.toString()).to.include("Account does not exist");
-      }
+      // The profile stays behind as a tombstone
+      const clientAccount = await program.account.client.fetch(client1Pda);
+      expect(clientAccount.deletedAt.toNumber()).to.not.equal(0);
+      expect(clientAccount.companyEmail).to.equal("");
     });
   });
 
@@ -339,6 +390,15 @@ describe("solearn", () => {
     let submissionPda: PublicKey;
     const bountyTitle = "Test Bounty";
 
+    // Bounties are addressed by the client's next bounty id
+    const nextBountyPda = async () => {
+      const client = await program.account.client.fetch(bountyCreatorPda);
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("bounty"), client.nextBountyId.toArrayLike(Buffer, "le", 8), bountyCreator.publicKey.toBuffer()],
+        program.programId
+      )[0];
+    };
+
     before(async () => {
       bountyCreator = Keypair.generate();
       await provider.connection.confirmTransaction(
@@ -355,15 +415,13 @@ describe("solearn", () => {
         .accountsStrict({
           authority: bountyCreator.publicKey,
           client: bountyCreatorPda,
+          nameRecord: handleRecord("company_name", "Bounty Corp"),
           systemProgram: SystemProgram.programId,
         })
         .signers([bountyCreator])
         .rpc();
 
-      [bountyPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("bounty"), Buffer.from(bountyTitle), bountyCreator.publicKey.toBuffer()],
-        program.programId
-      );
+      bountyPda = await nextBountyPda();
 
       [escrowPda] = PublicKey.findProgramAddressSync(
         [Buffer.from("escrow"), bountyPda.toBuffer()],
@@ -385,6 +443,8 @@ describe("solearn", () => {
             .accountsStrict({
                 authority: user2.publicKey,
                 user: user2Pda,
+                nameRecord: handleRecord("user_name", "Submitter"),
+                emailRecord: handleRecord("user_email", "submitter@test.com"),
                 systemProgram: SystemProgram.programId,
             })
             .signers([user2])
@@ -405,10 +465,11 @@ describe("solearn", () => {
       const skills = ["Rust", "Solana"];
 
       await program.methods
-        .createBounty(bountyTitle, description, reward, deadline, skills)
+        .createBounty(bountyTitle, description, reward, deadline, skills, { clientSelects: {} }, new BN(0))
         .accountsStrict({
           authority: bountyCreator.publicKey,
           client: bountyCreatorPda,
+          config: configPda,
           bounty: bountyPda,
           escrowAccount: escrowPda,
           systemProgram: SystemProgram.programId,
@@ -458,6 +519,8 @@ describe("solearn", () => {
           submission: submissionPda,
           selectedUser: submissionAccount.userKey,
           selectedUserWallet: user2.publicKey,
+          config: configPda,
+          treasury: provider.wallet.publicKey,
           escrowAccount: escrowPda,
           systemProgram: SystemProgram.programId,
         })
@@ -472,10 +535,7 @@ describe("solearn", () => {
     it("Should fail to delete a bounty with submissions", async () => {
         // Re-create a bounty to test deletion failure
         const newBountyTitle = "Delete Fail Bounty";
-        const newBountyPda = PublicKey.findProgramAddressSync(
-            [Buffer.from("bounty"), Buffer.from(newBountyTitle), bountyCreator.publicKey.toBuffer()],
-            program.programId
-        )[0];
+        const newBountyPda = await nextBountyPda();
         const newEscrowPda = PublicKey.findProgramAddressSync(
             [Buffer.from("escrow"), newBountyPda.toBuffer()],
             program.programId
@@ -486,10 +546,11 @@ describe("solearn", () => {
         )[0];
 
         await program.methods
-            .createBounty(newBountyTitle, "desc", new BN(1), new BN(Date.now() / 1000 + 3600), [])
+            .createBounty(newBountyTitle, "desc", new BN(1), new BN(Date.now() / 1000 + 3600), [], { clientSelects: {} }, new BN(0))
             .accountsStrict({
                 authority: bountyCreator.publicKey,
                 client: bountyCreatorPda,
+                config: configPda,
                 bounty: newBountyPda,
                 escrowAccount: newEscrowPda,
                 systemProgram: SystemProgram.programId,
@@ -511,12 +572,13 @@ describe("solearn", () => {
 
         try {
             await program.methods
-                .deleteBounty(newBountyTitle)
+                .deleteBounty()
                 .accountsStrict({
                     authority: bountyCreator.publicKey,
                     client: bountyCreatorPda,
                     bounty: newBountyPda,
                     escrowAccount: newEscrowPda,
+                    config: configPda,
                     systemProgram: SystemProgram.programId,
                 })
                 .signers([bountyCreator])
@@ -527,22 +589,57 @@ describe("solearn", () => {
         }
     });
 
-    it("Should delete a bounty successfully when there are no submissions", async () => {
-        const newBountyTitle = "Delete Success Bounty";
-        const newBountyPda = PublicKey.findProgramAddressSync(
-            [Buffer.from("bounty"), Buffer.from(newBountyTitle), bountyCreator.publicKey.toBuffer()],
+    it("Should block bounty creation while deposits are paused", async () => {
+        const newBountyTitle = "Paused Bounty";
+        const newBountyPda = await nextBountyPda();
+        const newEscrowPda = PublicKey.findProgramAddressSync(
+            [Buffer.from("escrow"), newBountyPda.toBuffer()],
             program.programId
         )[0];
+
+        await program.methods
+            .pause({ deposits: {} })
+            .accountsStrict({ guardian: provider.wallet.publicKey, config: configPda })
+            .rpc();
+
+        try {
+            await program.methods
+                .createBounty(newBountyTitle, "desc", new BN(1), new BN(Date.now() / 1000 + 3600), [], { clientSelects: {} }, new BN(0))
+                .accountsStrict({
+                    authority: bountyCreator.publicKey,
+                    client: bountyCreatorPda,
+                    config: configPda,
+                    bounty: newBountyPda,
+                    escrowAccount: newEscrowPda,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([bountyCreator])
+                .rpc();
+            assert.fail("Expected an error but none was thrown.");
+        } catch (error) {
+            expect(error.toString()).to.include("InstructionGroupPaused");
+        } finally {
+            await program.methods
+                .unpause({ deposits: {} })
+                .accountsStrict({ guardian: provider.wallet.publicKey, config: configPda })
+                .rpc();
+        }
+    });
+
+    it("Should delete a bounty successfully when there are no submissions", async () => {
+        const newBountyTitle = "Delete Success Bounty";
+        const newBountyPda = await nextBountyPda();
         const newEscrowPda = PublicKey.findProgramAddressSync(
             [Buffer.from("escrow"), newBountyPda.toBuffer()],
             program.programId
         )[0];
 
         await program.methods
-            .createBounty(newBountyTitle, "desc", new BN(1), new BN(Date.now() / 1000 + 3600), [])
+            .createBounty(newBountyTitle, "desc", new BN(1), new BN(Date.now() / 1000 + 3600), [], { clientSelects: {} }, new BN(0))
             .accountsStrict({
                 authority: bountyCreator.publicKey,
                 client: bountyCreatorPda,
+                config: configPda,
                 bounty: newBountyPda,
                 escrowAccount: newEscrowPda,
                 systemProgram: SystemProgram.programId,
@@ -551,12 +648,13 @@ describe("solearn", () => {
             .rpc();
 
         await program.methods
-            .deleteBounty(newBountyTitle)
+            .deleteBounty()
             .accountsStrict({
                 authority: bountyCreator.publicKey,
                 client: bountyCreatorPda,
                 bounty: newBountyPda,
                 escrowAccount: newEscrowPda,
+                config: configPda,
                 systemProgram: SystemProgram.programId,
             })
             .signers([bountyCreator])